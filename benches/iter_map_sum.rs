@@ -0,0 +1,25 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+fn run_iterator(data: &Vec<i64>) {
+    let result: i64 = data.iter().map(|x| x + 1).sum();
+    black_box(result);
+}
+
+pub fn make_data(amount: usize) -> Vec<i64> {
+    let mut retval = Vec::new();
+    retval.reserve(amount);
+    for x in 0..amount {
+        retval.push(x as i64);
+    }
+    retval
+}
+
+pub fn benchmarks(c: &mut Criterion) {
+    let data = make_data(1000_000);
+    c.bench_function("iter_map_sum", |b| {
+        b.iter(|| run_iterator(black_box(&data)))
+    });
+}
+
+criterion_group!(benches, benchmarks);
+criterion_main!(benches);
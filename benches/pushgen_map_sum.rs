@@ -0,0 +1,26 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use pushgen::{GeneratorExt, SliceGenerator};
+
+fn run_generator(data: &Vec<i64>) {
+    let result: i64 = SliceGenerator::new(data.as_slice()).map(|x| x + 1).sum();
+    black_box(result);
+}
+
+pub fn make_data(amount: usize) -> Vec<i64> {
+    let mut retval = Vec::new();
+    retval.reserve(amount);
+    for x in 0..amount {
+        retval.push(x as i64);
+    }
+    retval
+}
+
+pub fn benchmarks(c: &mut Criterion) {
+    let data = make_data(1000_000);
+    c.bench_function("pushgen_map_sum", |b| {
+        b.iter(|| run_generator(black_box(&data)))
+    });
+}
+
+criterion_group!(benches, benchmarks);
+criterion_main!(benches);
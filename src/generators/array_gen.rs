@@ -1,6 +1,10 @@
+#[cfg(feature = "safe")]
+use crate::structs::utility::unwrap_some;
 use crate::{Generator, GeneratorResult, ReverseGenerator, ValueResult};
+#[cfg(not(feature = "safe"))]
 use core::mem::MaybeUninit;
 use core::num::NonZeroUsize;
+#[cfg(not(feature = "safe"))]
 use core::ops::Range;
 
 /// A generator that generates values from an array.
@@ -38,7 +42,10 @@ use core::ops::Range;
 /// [`into_gen()`]: crate::IntoGenerator::into_gen
 ///
 pub struct ArrayGenerator<T, const N: usize> {
+    #[cfg(not(feature = "safe"))]
     data: [MaybeUninit<T>; N],
+    #[cfg(feature = "safe")]
+    data: [Option<T>; N],
     // Slice can be indexed with indices in the range [begin, end). That is end is 1 greater than
     // the last index that can be used at all times.
     begin: usize,
@@ -60,20 +67,42 @@ impl<T, const N: usize> ArrayGenerator<T, N> {
     #[inline]
     pub fn new(data: [T; N]) -> Self {
         Self {
-            // Safety: this is a safe usage of transmute
-            data: unsafe { core::mem::transmute_copy(&data) },
+            data: Self::init_data(data),
             begin: 0,
             end: N,
         }
     }
 
+    #[cfg(not(feature = "safe"))]
+    #[inline(always)]
+    fn init_data(data: [T; N]) -> [MaybeUninit<T>; N] {
+        // The values are owned by the returned array, so they must not be dropped here as well.
+        let data = core::mem::ManuallyDrop::new(data);
+        // Safety: this is a safe usage of transmute
+        unsafe { core::mem::transmute_copy(&*data) }
+    }
+
+    #[cfg(feature = "safe")]
+    #[inline(always)]
+    fn init_data(data: [T; N]) -> [Option<T>; N] {
+        data.map(Some)
+    }
+
     // Safety requirements:
-    // self.begin <= index < self.end
+    // self.begin <= index < self.end, and index is removed from that range afterwards
+    #[cfg(not(feature = "safe"))]
     #[inline]
-    unsafe fn value_at(&self, index: usize) -> T {
+    unsafe fn take(&mut self, index: usize) -> T {
         self.data.get_unchecked(index).as_ptr().read()
     }
 
+    #[cfg(feature = "safe")]
+    #[inline(always)]
+    fn take(&mut self, index: usize) -> T {
+        unwrap_some(self.data[index].take())
+    }
+
+    #[cfg(not(feature = "safe"))]
     fn as_slice(&self) -> &[T] {
         // Safety: self.begin and self.end are always kept up-to-date
         unsafe {
@@ -85,12 +114,14 @@ impl<T, const N: usize> ArrayGenerator<T, N> {
         }
     }
 
+    #[cfg(not(feature = "safe"))]
     fn uninit_data_array() -> [MaybeUninit<T>; N] {
         // Safety: This is the exact implementation of MaybeUninit::uninit_array()
         unsafe { MaybeUninit::<[MaybeUninit<T>; N]>::uninit().assume_init() }
     }
 }
 
+#[cfg(not(feature = "safe"))]
 impl<T: Clone, const N: usize> Clone for ArrayGenerator<T, N> {
     #[inline]
     fn clone(&self) -> Self {
@@ -110,6 +141,18 @@ impl<T: Clone, const N: usize> Clone for ArrayGenerator<T, N> {
     }
 }
 
+#[cfg(feature = "safe")]
+impl<T: Clone, const N: usize> Clone for ArrayGenerator<T, N> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            data: self.data.clone(),
+            begin: self.begin,
+            end: self.end,
+        }
+    }
+}
+
 impl<T, const N: usize> Generator for ArrayGenerator<T, N> {
     type Output = T;
 
@@ -118,7 +161,11 @@ impl<T, const N: usize> Generator for ArrayGenerator<T, N> {
         let end = self.end;
         while self.begin < end {
             // Safety: self.begin < self.end always true.
-            if output(unsafe { self.value_at(self.begin) }) == ValueResult::Stop {
+            #[cfg(not(feature = "safe"))]
+            let value = unsafe { self.take(self.begin) };
+            #[cfg(feature = "safe")]
+            let value = self.take(self.begin);
+            if output(value) == ValueResult::Stop {
                 self.begin += 1;
                 return GeneratorResult::Stopped;
             }
@@ -148,7 +195,11 @@ impl<T, const N: usize> ReverseGenerator for ArrayGenerator<T, N> {
         while self.end > end_back {
             // self.end > end_back -> self.end > 0, so self.end-1 is safe
             // Safety: self.end-1 always in range [0, self.slice.len())
-            if output(unsafe { self.value_at(self.end - 1) }) == ValueResult::Stop {
+            #[cfg(not(feature = "safe"))]
+            let value = unsafe { self.take(self.end - 1) };
+            #[cfg(feature = "safe")]
+            let value = self.take(self.end - 1);
+            if output(value) == ValueResult::Stop {
                 self.end -= 1;
                 return GeneratorResult::Stopped;
             }
@@ -172,6 +223,7 @@ impl<T, const N: usize> ReverseGenerator for ArrayGenerator<T, N> {
     }
 }
 
+#[cfg(not(feature = "safe"))]
 impl<T, const N: usize> Drop for ArrayGenerator<T, N> {
     #[inline]
     fn drop(&mut self) {
@@ -194,6 +246,7 @@ mod tests {
     use super::*;
     use crate::{Generator, GeneratorExt};
     use core::num::NonZeroUsize;
+    use std::rc::Rc;
     use std::sync::atomic::{AtomicU32, Ordering};
 
     #[test]
@@ -361,4 +414,16 @@ mod tests {
         drop(gen);
         assert_eq!(COUNTER.load(Ordering::Acquire), 2);
     }
+
+    #[test]
+    fn new_does_not_drop() {
+        let value = Rc::new(1);
+        let gen = ArrayGenerator::new([value.clone(), value.clone()]);
+        assert_eq!(Rc::strong_count(&value), 3);
+        let cloned = gen.clone();
+        assert_eq!(Rc::strong_count(&value), 5);
+        drop(gen);
+        drop(cloned);
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
 }
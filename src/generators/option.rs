@@ -1,4 +1,4 @@
-use crate::{Generator, GeneratorResult, ReverseGenerator, ValueResult};
+use crate::{FusedGenerator, Generator, GeneratorResult, ReverseGenerator, ValueResult};
 
 /// A generator over the value in [`Some`] variant of an [`Option`].
 ///
@@ -44,3 +44,5 @@ impl<T> ReverseGenerator for OptionGen<T> {
         GeneratorResult::Complete
     }
 }
+
+impl<T> FusedGenerator for OptionGen<T> {}
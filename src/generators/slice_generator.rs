@@ -1,4 +1,4 @@
-use crate::{Generator, GeneratorResult, ReverseGenerator, ValueResult};
+use crate::{FusedGenerator, Generator, GeneratorResult, ReverseGenerator, ValueResult};
 use core::num::NonZeroUsize;
 
 /// A generator that generates values from a slice.
@@ -41,6 +41,66 @@ impl<'a, T> SliceGenerator<'a, T> {
             end: slice.len(),
         }
     }
+
+    /// Returns the values that haven't been generated yet.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use pushgen::{GeneratorExt, SliceGenerator};
+    /// let data = [1, 2, 3];
+    /// let mut gen = SliceGenerator::new(&data);
+    /// assert_eq!(gen.next(), Ok(&1));
+    /// assert_eq!(gen.as_slice(), [2, 3]);
+    /// ```
+    #[inline]
+    pub fn as_slice(&self) -> &'a [T] {
+        &self.slice[self.begin..self.end]
+    }
+
+    /// Splits the values that haven't been generated yet into two independent generators.
+    ///
+    /// The first generator generates the first `n` remaining values and the second generator
+    /// generates the rest.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `n` is greater than the number of remaining values.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use pushgen::{GeneratorExt, SliceGenerator};
+    /// let data = [2, 10, 20, 30];
+    /// let (header, body) = SliceGenerator::new(&data).split_at(1);
+    /// let header: Vec<_> = header.collect();
+    /// let body: i32 = body.copied().sum();
+    /// assert_eq!(header, [&2]);
+    /// assert_eq!(body, 60);
+    /// ```
+    #[inline]
+    pub fn split_at(self, n: usize) -> (Self, Self) {
+        let (head, tail) = self.as_slice().split_at(n);
+        (Self::new(head), Self::new(tail))
+    }
+}
+
+impl<'a, T> SliceGenerator<'a, T> {
+    /// Get the value at `index`.
+    ///
+    /// Callers must make sure that `index` is in the range `[self.begin, self.end)`.
+    #[cfg(not(feature = "safe"))]
+    #[inline(always)]
+    fn get(&self, index: usize) -> &'a T {
+        // Safety: index in range [self.begin, self.end) which is always within the slice
+        unsafe { self.slice.get_unchecked(index) }
+    }
+
+    #[cfg(feature = "safe")]
+    #[inline(always)]
+    fn get(&self, index: usize) -> &'a T {
+        &self.slice[index]
+    }
 }
 
 impl<'a, T> Generator for SliceGenerator<'a, T> {
@@ -50,8 +110,7 @@ impl<'a, T> Generator for SliceGenerator<'a, T> {
     fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
         let end = self.end;
         while self.begin < end {
-            // Safety: index < self.end always true.
-            if output(unsafe { self.slice.get_unchecked(self.begin) }) == ValueResult::Stop {
+            if output(self.get(self.begin)) == ValueResult::Stop {
                 self.begin += 1;
                 return GeneratorResult::Stopped;
             }
@@ -79,9 +138,8 @@ impl<'a, T> ReverseGenerator for SliceGenerator<'a, T> {
     fn run_back(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
         let end_back = self.begin;
         while self.end > end_back {
-            // self.end > end_back -> self.end > 0, so self.end-1 is safe
-            // Safety: self.end-1 always in range [0, self.slice.len())
-            if output(unsafe { self.slice.get_unchecked(self.end - 1) }) == ValueResult::Stop {
+            // self.end > end_back -> self.end > 0, so self.end-1 is in range [self.begin, self.end)
+            if output(self.get(self.end - 1)) == ValueResult::Stop {
                 self.end -= 1;
                 return GeneratorResult::Stopped;
             }
@@ -105,12 +163,52 @@ impl<'a, T> ReverseGenerator for SliceGenerator<'a, T> {
     }
 }
 
+impl<'a, T> FusedGenerator for SliceGenerator<'a, T> {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{Generator, GeneratorExt};
     use core::num::NonZeroUsize;
 
+    #[test]
+    fn same_as_iter() {
+        let data = [1, 2, 3, 4, 5];
+        let forward: Vec<_> = SliceGenerator::new(&data).collect();
+        assert_eq!(forward, data.iter().collect::<Vec<_>>());
+        let backward: Vec<_> = SliceGenerator::new(&data).rev().collect();
+        assert_eq!(backward, data.iter().rev().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn split_at() {
+        let data = [1, 2, 3, 4, 5];
+        let mut gen = SliceGenerator::new(&data);
+        assert_eq!(gen.next(), Ok(&1));
+        assert_eq!(gen.next_back(), Ok(&5));
+
+        let (head, tail) = gen.clone().split_at(1);
+        assert_eq!(head.collect::<Vec<_>>(), [&2]);
+        assert_eq!(tail.collect::<Vec<_>>(), [&3, &4]);
+
+        let (head, tail) = gen.clone().split_at(0);
+        assert!(head.as_slice().is_empty());
+        assert_eq!(tail.as_slice(), [2, 3, 4]);
+
+        let (head, tail) = gen.split_at(3);
+        assert_eq!(head.as_slice(), [2, 3, 4]);
+        assert!(tail.as_slice().is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn split_at_out_of_range() {
+        let data = [1, 2, 3];
+        let mut gen = SliceGenerator::new(&data);
+        assert_eq!(gen.next(), Ok(&1));
+        let _ = gen.split_at(3);
+    }
+
     #[test]
     fn try_advance() {
         let data = [1, 2, 3, 4, 5];
@@ -29,6 +29,17 @@
 //! `std`: Enable boxing and trait implementations for types that requires `std`. If this feature
 //! is disabled, `pushgen` is `no_std`. This is *enabled* by default.
 //!
+//! `safe`: Use bounds-checked indexing in [`SliceGenerator`] instead of unchecked indexing, and
+//! replace all other `unsafe` code with checked alternatives, so that `pushgen` contains no
+//! `unsafe` code at all. Adaptors that build arrays, such as
+//! [`array_chunks()`](GeneratorExt::array_chunks), then keep their values in a heap allocated
+//! buffer, so an allocator is required even without `std`. This is *disabled* by default.
+//!
+//! `rand`: Enable adaptors that use the [`rand`](https://docs.rs/rand) crate, such as
+#![cfg_attr(feature = "rand", doc = "[`GeneratorExt::sample`].")]
+#![cfg_attr(not(feature = "rand"), doc = "`GeneratorExt::sample`.")]
+//! This is *disabled* by default.
+//!
 //! `test`: Enable test tools that can be used to test generators and adaptors. This is *disabled* by default.
 //!
 //! ## Performance
@@ -38,12 +49,17 @@
 
 #![cfg_attr(not(any(test, feature = "std")), no_std)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
+#![cfg_attr(feature = "safe", forbid(unsafe_code))]
+
+#[cfg(feature = "safe")]
+extern crate alloc;
 
 pub use either::Either;
 
 pub use result::*;
 
 pub use traits::FromGenerator;
+pub use traits::FusedGenerator;
 pub use traits::Generator;
 pub use traits::GeneratorExt;
 pub use traits::IntoGenerator;
@@ -52,7 +68,12 @@ pub use traits::ReverseGenerator;
 pub use generators::from_fn;
 pub use generators::from_iter;
 pub use generators::SliceGenerator;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use structs::{kmerge, kmerge_by, put_back_n, round_robin};
+pub use structs::{multizip, put_back, repeat_n};
 
+mod macros;
 mod result;
 
 pub mod generators;
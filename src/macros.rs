@@ -0,0 +1,115 @@
+/// Zips an arbitrary number of generators into a single generator of flat tuples.
+///
+/// Each argument may be anything implementing [`IntoGenerator`](crate::IntoGenerator). The
+/// macro expands to nested [`zip()`](crate::GeneratorExt::zip) calls followed by a
+/// [`map()`](crate::GeneratorExt::map) that flattens the nested tuples, so
+/// `izip!(a, b, c)` generates `(a_value, b_value, c_value)` rather than
+/// `((a_value, b_value), c_value)`. With a single argument the generator of that argument is
+/// returned as is.
+///
+/// The generator will complete when any of the generators completes.
+///
+/// ## Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use pushgen::{izip, GeneratorExt};
+/// let a = [1, 2, 3];
+/// let b = [4, 5, 6];
+/// let c = [7, 8, 9];
+/// let output: Vec<_> = izip!(&a, &b, &c).map(|(a, b, c)| a + b + c).collect();
+/// assert_eq!(output, [12, 15, 18]);
+/// ```
+#[macro_export]
+macro_rules! izip {
+    (@closure $p:pat => $tup:expr) => {
+        |$p| $tup
+    };
+    (@closure $p:pat => ($($tup:tt)*), $_gen:expr $(, $tail:expr)*) => {
+        $crate::izip!(@closure ($p, b) => ($($tup)*, b) $(, $tail)*)
+    };
+    ($first:expr $(,)?) => {
+        $crate::IntoGenerator::into_gen($first)
+    };
+    ($first:expr, $second:expr $(,)?) => {
+        $crate::GeneratorExt::zip($crate::izip!($first), $crate::izip!($second))
+    };
+    ($first:expr $(, $rest:expr)* $(,)?) => {{
+        let gen = $crate::izip!($first);
+        $(let gen = $crate::GeneratorExt::zip(gen, $crate::izip!($rest));)*
+        $crate::GeneratorExt::map(gen, $crate::izip!(@closure a => (a) $(, $rest)*))
+    }};
+}
+
+/// Chains an arbitrary number of generators into a single generator.
+///
+/// Each argument may be anything implementing [`IntoGenerator`](crate::IntoGenerator), and all
+/// of them must generate the same type of values. The macro expands to nested
+/// [`chain()`](crate::GeneratorExt::chain) calls, so `chain!(a, b, c)` is the same as
+/// `a.into_gen().chain(b.into_gen()).chain(c.into_gen())`.
+///
+/// ## Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use pushgen::{chain, GeneratorExt};
+/// let a = [1, 2];
+/// let b = [3];
+/// let c = [4, 5];
+/// let output: Vec<_> = chain!(a, b, c).collect();
+/// assert_eq!(output, [1, 2, 3, 4, 5]);
+/// ```
+#[macro_export]
+macro_rules! chain {
+    ($first:expr $(, $rest:expr)* $(,)?) => {{
+        let gen = $crate::IntoGenerator::into_gen($first);
+        $(let gen = $crate::GeneratorExt::chain(gen, $crate::IntoGenerator::into_gen($rest));)*
+        gen
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::StoppingGen;
+    use crate::{GeneratorExt, GeneratorResult};
+
+    #[test]
+    fn izip() {
+        let a = [1, 2, 3];
+        let b = ['a', 'b', 'c', 'd'];
+        let c = [true, false, true];
+        let d = [1.0, 2.0];
+
+        let output: Vec<_> = izip!(a).collect();
+        assert_eq!(output, a);
+
+        let output: Vec<_> = izip!(a, b).collect();
+        assert_eq!(output, [(1, 'a'), (2, 'b'), (3, 'c')]);
+
+        let output: Vec<_> = izip!(a, b, c, d,).collect();
+        assert_eq!(output, [(1, 'a', true, 1.0), (2, 'b', false, 2.0)]);
+    }
+
+    #[test]
+    fn izip_spuriously_stopping() {
+        let data = [1, 2, 3];
+        for x in 0..3 {
+            let mut gen = izip!(StoppingGen::new(x, &data), &data, &data);
+            let mut output = Vec::new();
+            while gen.for_each(|(a, b, c)| output.push(a + b + c)) == GeneratorResult::Stopped {}
+            assert_eq!(output, [3, 6, 9]);
+        }
+    }
+
+    #[test]
+    fn chain() {
+        let a = [1, 2];
+        let output: Vec<_> = chain!(a).collect();
+        assert_eq!(output, a);
+
+        let output: Vec<_> = chain!(a, [], [3], a,).collect();
+        assert_eq!(output, [1, 2, 3, 1, 2]);
+    }
+}
@@ -119,3 +119,106 @@ impl<T> TryReduction<T> {
         }
     }
 }
+
+/// The result of one step of a `try_*` reduction, either continuing with a new accumulator or
+/// breaking out of the reduction early.
+///
+/// This is implemented for [`Result`], where `Err` breaks, and for
+/// [`ControlFlow`](core::ops::ControlFlow), where `Break` breaks. The trait is sealed and can't be
+/// implemented outside of this crate.
+pub trait TryStep<B>: sealed::Sealed {
+    /// The value the reduction breaks with.
+    type Break;
+
+    /// Converts the step into `Ok` to continue with the accumulator, or `Err` to break.
+    fn into_result(self) -> Result<B, Self::Break>;
+}
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl<B, E> Sealed for Result<B, E> {}
+
+    impl<B, C> Sealed for core::ops::ControlFlow<C, B> {}
+}
+
+impl<B, E> TryStep<B> for Result<B, E> {
+    type Break = E;
+
+    #[inline]
+    fn into_result(self) -> Result<B, E> {
+        self
+    }
+}
+
+impl<B, C> TryStep<B> for core::ops::ControlFlow<C, B> {
+    type Break = C;
+
+    #[inline]
+    fn into_result(self) -> Result<B, C> {
+        match self {
+            core::ops::ControlFlow::Continue(acc) => Ok(acc),
+            core::ops::ControlFlow::Break(value) => Err(value),
+        }
+    }
+}
+
+/// The error returned by [`GeneratorExt::exactly_one`](crate::GeneratorExt::exactly_one) when a
+/// generator doesn't generate exactly one value.
+#[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq)]
+pub enum ExactlyOneError<T> {
+    /// The generator didn't generate any values.
+    Empty,
+    /// The generator generated more than one value. The first two values are kept.
+    MoreThanOne(T, T),
+    /// The generator stopped before it completed, so it is not yet known whether it generates
+    /// exactly one value. Holds the value generated before the stop, if any.
+    ///
+    /// This is a separate variant since neither `Empty` nor `Ok(value)` would be correct: the
+    /// generator may still generate values when it is resumed. The value generated before the stop
+    /// has already been taken from the generator, so it is kept here to not be lost.
+    Stopped(Option<T>),
+}
+
+impl<T> core::fmt::Display for ExactlyOneError<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ExactlyOneError::Empty => {
+                f.write_str("generator was empty, expected exactly one value")
+            }
+            ExactlyOneError::MoreThanOne(_, _) => {
+                f.write_str("generator generated more than one value, expected exactly one")
+            }
+            ExactlyOneError::Stopped(_) => {
+                f.write_str("generator stopped before completing, expected exactly one value")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<T: core::fmt::Debug> std::error::Error for ExactlyOneError<T> {}
+
+/// The error generated by [`GeneratorExt::try_zip_eq`](crate::GeneratorExt::try_zip_eq) when the
+/// zipped generators have different lengths.
+#[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq)]
+pub enum ZipEqError {
+    /// The left generator generated more values than the right one.
+    LeftLonger,
+    /// The right generator generated more values than the left one.
+    RightLonger,
+}
+
+impl core::fmt::Display for ZipEqError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ZipEqError::LeftLonger => f.write_str("left generator is longer than right generator"),
+            ZipEqError::RightLonger => f.write_str("right generator is longer than left generator"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for ZipEqError {}
@@ -0,0 +1,119 @@
+use crate::{structs::utility::ArrayBuilder, Generator, GeneratorResult, ValueResult};
+
+/// Groups values into arrays of `N` values. See [`.array_chunks()`](crate::GeneratorExt::array_chunks) for details.
+#[derive(Clone)]
+pub struct ArrayChunks<Src, const N: usize>
+where
+    Src: Generator,
+{
+    source: Src,
+    chunk: ArrayBuilder<Src::Output, N>,
+}
+
+impl<Src, const N: usize> ArrayChunks<Src, N>
+where
+    Src: Generator,
+{
+    #[inline]
+    pub(crate) fn new(source: Src) -> Self {
+        if N == 0 {
+            panic!("Chunk size must not be 0");
+        }
+        Self {
+            source,
+            chunk: ArrayBuilder::new(),
+        }
+    }
+
+    /// The values of the incomplete chunk.
+    ///
+    /// Once the generator has completed, these are the values that were left over because there
+    /// weren't enough of them to fill a whole chunk.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt, GeneratorResult};
+    /// let a = [1, 2, 3, 4, 5];
+    /// let mut gen = a.into_gen().array_chunks::<2>();
+    /// let mut output = Vec::new();
+    /// assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Complete);
+    /// assert_eq!(output, [[1, 2], [3, 4]]);
+    /// assert_eq!(gen.remainder(), [5]);
+    /// ```
+    #[inline]
+    pub fn remainder(&self) -> &[Src::Output] {
+        self.chunk.as_slice()
+    }
+}
+
+impl<Src, const N: usize> Generator for ArrayChunks<Src, N>
+where
+    Src: Generator,
+{
+    type Output = [Src::Output; N];
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        let chunk = &mut self.chunk;
+        self.source.run(|x| {
+            chunk.push(x);
+            match chunk.take_array() {
+                Some(array) => output(array),
+                None => ValueResult::MoreValues,
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::StoppingGen;
+    use crate::{GeneratorExt, GeneratorResult, IntoGenerator};
+    use std::rc::Rc;
+
+    #[test]
+    fn array_chunks() {
+        let data = [1, 2, 3, 4, 5, 6];
+        let output: Vec<_> = data.into_gen().array_chunks::<3>().collect();
+        assert_eq!(output, [[1, 2, 3], [4, 5, 6]]);
+
+        let mut gen = data.into_gen().array_chunks::<4>();
+        assert_eq!(gen.next(), Ok([1, 2, 3, 4]));
+        assert_eq!(gen.next(), Err(GeneratorResult::Complete));
+        assert_eq!(gen.remainder(), [5, 6]);
+    }
+
+    #[test]
+    fn remainder_is_dropped() {
+        let value = Rc::new(1);
+        let data = [value.clone(), value.clone(), value.clone()];
+        let mut gen = (&data).into_gen().cloned().array_chunks::<2>();
+        assert_eq!(gen.next().map(|x| x.len()), Ok(2));
+        assert_eq!(gen.next().map(|x| x.len()), Err(GeneratorResult::Complete));
+        assert_eq!(gen.remainder().len(), 1);
+        assert_eq!(Rc::strong_count(&value), 5);
+        drop(gen);
+        assert_eq!(Rc::strong_count(&value), 4);
+    }
+
+    #[test]
+    fn spuriously_stopping() {
+        let data = [1, 2, 3, 4, 5];
+        for x in 0..5 {
+            let mut gen = StoppingGen::new(x, &data).copied().array_chunks::<2>();
+            let mut output = Vec::new();
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Stopped);
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Complete);
+            assert_eq!(output, [[1, 2], [3, 4]]);
+            assert_eq!(gen.remainder(), [5]);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_size() {
+        let data = [1, 2, 3];
+        let _gen = data.into_gen().array_chunks::<0>();
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{Generator, GeneratorResult, ValueResult};
+
+/// Pull-style batching of values. See [`.batching()`](crate::GeneratorExt::batching) for details.
+#[derive(Clone)]
+pub struct Batching<Src, F> {
+    source: Src,
+    f: F,
+}
+
+impl<Src, F> Batching<Src, F> {
+    #[inline]
+    pub(crate) fn new(source: Src, f: F) -> Self {
+        Self { source, f }
+    }
+}
+
+impl<Src, F, B> Generator for Batching<Src, F>
+where
+    Src: Generator,
+    F: FnMut(&mut Src) -> Result<B, GeneratorResult>,
+{
+    type Output = B;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        loop {
+            match (self.f)(&mut self.source) {
+                Ok(value) => {
+                    if output(value) == ValueResult::Stop {
+                        return GeneratorResult::Stopped;
+                    }
+                }
+                Err(result) => return result,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::{collect_one_at_a_time, StoppingGen};
+    use crate::{GeneratorExt, GeneratorResult, IntoGenerator};
+
+    #[test]
+    fn batching() {
+        let data = [1, 2, 3, 4, 5];
+        let output: Vec<_> = data
+            .into_gen()
+            .batching(|gen| {
+                let a = gen.next()?;
+                match gen.next() {
+                    Ok(b) => Ok(a + b),
+                    Err(GeneratorResult::Complete) => Ok(a),
+                    Err(err) => Err(err),
+                }
+            })
+            .collect();
+        assert_eq!(output, [3, 7, 5]);
+    }
+
+    #[test]
+    fn consumer_stop() {
+        let mut gen = [1, 2, 3].into_gen().batching(|gen| gen.next());
+        let output = collect_one_at_a_time(&mut gen);
+        assert_eq!(output, [1, 2, 3]);
+    }
+
+    #[test]
+    fn spuriously_stopping() {
+        let data = [1, 2, 3];
+        for x in 0..3 {
+            let mut gen = StoppingGen::new(x, &data).batching(|gen| gen.next());
+            let mut output = Vec::new();
+            assert_eq!(gen.for_each(|x| output.push(*x)), GeneratorResult::Stopped);
+            assert_eq!(gen.for_each(|x| output.push(*x)), GeneratorResult::Complete);
+            assert_eq!(output, data);
+        }
+    }
+}
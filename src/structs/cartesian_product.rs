@@ -0,0 +1,101 @@
+use crate::{Generator, GeneratorExt, GeneratorResult, ValueResult};
+
+/// Cartesian product of two generators. See
+/// [`.cartesian_product()`](crate::GeneratorExt::cartesian_product) for details.
+#[derive(Clone)]
+pub struct CartesianProduct<Left, Right>
+where
+    Left: Generator,
+{
+    left: Left,
+    right: Right,
+    current: Option<(Left::Output, Right)>,
+}
+
+impl<Left, Right> CartesianProduct<Left, Right>
+where
+    Left: Generator,
+{
+    #[inline]
+    pub(crate) fn new(left: Left, right: Right) -> Self {
+        Self {
+            left,
+            right,
+            current: None,
+        }
+    }
+}
+
+impl<Left, Right> Generator for CartesianProduct<Left, Right>
+where
+    Left: Generator,
+    Left::Output: Clone,
+    Right: Generator + Clone,
+{
+    type Output = (Left::Output, Right::Output);
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        loop {
+            if let Some((left_value, right)) = &mut self.current {
+                if right.run(|right_value| output((left_value.clone(), right_value)))
+                    == GeneratorResult::Stopped
+                {
+                    return GeneratorResult::Stopped;
+                }
+                self.current = None;
+            }
+
+            match self.left.next() {
+                Ok(left_value) => self.current = Some((left_value, self.right.clone())),
+                Err(result) => return result,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::{collect_one_at_a_time, StoppingGen};
+    use crate::{GeneratorExt, GeneratorResult, IntoGenerator, SliceGenerator};
+
+    #[test]
+    fn cartesian_product() {
+        let output: Vec<_> = [1, 2]
+            .into_gen()
+            .cartesian_product(['a', 'b', 'c'].into_gen())
+            .collect();
+        assert_eq!(
+            output,
+            [(1, 'a'), (1, 'b'), (1, 'c'), (2, 'a'), (2, 'b'), (2, 'c')]
+        );
+
+        let output: Vec<_> = [1, 2]
+            .into_gen()
+            .cartesian_product(SliceGenerator::<i32>::new(&[]))
+            .collect();
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn consumer_stop() {
+        let mut gen = [1, 2].into_gen().cartesian_product([3, 4].into_gen());
+        let output = collect_one_at_a_time(&mut gen);
+        assert_eq!(output, [(1, 3), (1, 4), (2, 3), (2, 4)]);
+    }
+
+    #[test]
+    fn spuriously_stopping() {
+        let data = [1, 2, 3];
+        for x in 0..3 {
+            let mut gen = StoppingGen::new(x, &data).cartesian_product([4, 5].into_gen());
+            let mut output = Vec::new();
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Stopped);
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Complete);
+            assert_eq!(
+                output,
+                [(&1, 4), (&1, 5), (&2, 4), (&2, 5), (&3, 4), (&3, 5)]
+            );
+        }
+    }
+}
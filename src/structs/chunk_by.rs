@@ -0,0 +1,195 @@
+use crate::{Generator, GeneratorResult, ValueResult};
+
+/// Groups consecutive values with equal keys, generating `(key, group)` pairs.
+/// See [`.chunk_by_with_key()`](crate::GeneratorExt::chunk_by_with_key) for details.
+#[derive(Clone)]
+pub struct ChunkByWithKey<Src, F, K>
+where
+    Src: Generator,
+{
+    source: Src,
+    key_fn: F,
+    current: Option<(K, Vec<Src::Output>)>,
+}
+
+impl<Src, F, K> ChunkByWithKey<Src, F, K>
+where
+    Src: Generator,
+    F: FnMut(&Src::Output) -> K,
+    K: PartialEq,
+{
+    #[inline]
+    pub(crate) fn new(source: Src, key_fn: F) -> Self {
+        Self {
+            source,
+            key_fn,
+            current: None,
+        }
+    }
+}
+
+impl<Src, F, K> Generator for ChunkByWithKey<Src, F, K>
+where
+    Src: Generator,
+    F: FnMut(&Src::Output) -> K,
+    K: PartialEq,
+{
+    type Output = (K, Vec<Src::Output>);
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        let key_fn = &mut self.key_fn;
+        let current = &mut self.current;
+        let result = self.source.run(|x| {
+            let key = key_fn(&x);
+            match current {
+                Some((current_key, group)) if *current_key == key => {
+                    group.push(x);
+                    ValueResult::MoreValues
+                }
+                _ => match current.replace((key, vec![x])) {
+                    Some(finished) => output(finished),
+                    None => ValueResult::MoreValues,
+                },
+            }
+        });
+
+        if result == GeneratorResult::Complete {
+            if let Some(last) = self.current.take() {
+                if output(last) == ValueResult::Stop {
+                    return GeneratorResult::Stopped;
+                }
+            }
+        }
+
+        result
+    }
+}
+
+/// Groups consecutive values with equal keys, generating each group as a `Vec`.
+/// See [`.chunk_by()`](crate::GeneratorExt::chunk_by) for details.
+pub struct ChunkBy<Src, F, K>
+where
+    Src: Generator,
+{
+    chunks: ChunkByWithKey<Src, F, K>,
+}
+
+impl<Src, F, K> ChunkBy<Src, F, K>
+where
+    Src: Generator,
+    F: FnMut(&Src::Output) -> K,
+    K: PartialEq,
+{
+    #[inline]
+    pub(crate) fn new(source: Src, key_fn: F) -> Self {
+        Self {
+            chunks: ChunkByWithKey::new(source, key_fn),
+        }
+    }
+}
+
+impl<Src, F, K> Clone for ChunkBy<Src, F, K>
+where
+    Src: Generator + Clone,
+    Src::Output: Clone,
+    F: Clone,
+    K: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            chunks: self.chunks.clone(),
+        }
+    }
+}
+
+impl<Src, F, K> Generator for ChunkBy<Src, F, K>
+where
+    Src: Generator,
+    F: FnMut(&Src::Output) -> K,
+    K: PartialEq,
+{
+    type Output = Vec<Src::Output>;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        self.chunks.run(|(_, group)| output(group))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::{MultiStoppingGen, StoppingGen};
+    use crate::{GeneratorExt, GeneratorResult, IntoGenerator};
+
+    #[test]
+    fn chunk_by_with_key() {
+        let data = [1, 1, 2, 3, 3, 3];
+        let output: Vec<_> = data.into_gen().chunk_by_with_key(|x| *x).collect();
+        assert_eq!(output, [(1, vec![1, 1]), (2, vec![2]), (3, vec![3, 3, 3])]);
+
+        let empty: [i32; 0] = [];
+        assert_eq!(empty.into_gen().chunk_by_with_key(|x| *x).count(), 0);
+    }
+
+    #[test]
+    fn chunk_by() {
+        let data = [1, 3, 2, 4, 6, 5];
+        let output: Vec<_> = data.into_gen().chunk_by(|x| x % 2).collect();
+        assert_eq!(output, [vec![1, 3], vec![2, 4, 6], vec![5]]);
+
+        let empty: [i32; 0] = [];
+        assert_eq!(empty.into_gen().chunk_by(|x| *x).count(), 0);
+    }
+
+    #[test]
+    fn spuriously_stopping_chunk_by() {
+        let data = [1, 3, 2, 4, 6, 5];
+        for x in 0..6 {
+            let mut gen = StoppingGen::new(x, &data).chunk_by(|x| **x % 2);
+            let mut output = Vec::new();
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Stopped);
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Complete);
+            assert_eq!(output, [vec![&1, &3], vec![&2, &4, &6], vec![&5]]);
+        }
+    }
+
+    #[test]
+    fn stop_mid_group() {
+        let data = [1, 1, 2, 3, 3, 3];
+        for x in 0..6 {
+            let mut gen = StoppingGen::new(x, &data).chunk_by_with_key(|x| **x);
+            let mut output = Vec::new();
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Stopped);
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Complete);
+            assert_eq!(
+                output,
+                [(1, vec![&1, &1]), (2, vec![&2]), (3, vec![&3, &3, &3])]
+            );
+        }
+    }
+
+    #[test]
+    fn consumer_stop() {
+        let data = [1, 1, 2, 3, 3, 3];
+        let mut gen = data.into_gen().chunk_by_with_key(|x| x % 2);
+        assert_eq!(gen.next(), Ok((1, vec![1, 1])));
+        assert_eq!(gen.next(), Ok((0, vec![2])));
+        assert_eq!(gen.next(), Ok((1, vec![3, 3, 3])));
+        assert_eq!(gen.next(), Err(GeneratorResult::Complete));
+    }
+
+    #[test]
+    fn clone_mid_group() {
+        let data = [Some(1), Some(1), None, Some(1), Some(2)];
+        let mut gen = MultiStoppingGen::new(&data).chunk_by_with_key(|x| **x);
+        assert_eq!(gen.for_each(|_| ()), GeneratorResult::Stopped);
+
+        let cloned = gen.clone();
+        let output: Vec<_> = gen.collect();
+        assert_eq!(output, [(1, vec![&1, &1, &1]), (2, vec![&2])]);
+        let output: Vec<_> = cloned.collect();
+        assert_eq!(output, [(1, vec![&1, &1, &1]), (2, vec![&2])]);
+    }
+}
@@ -0,0 +1,107 @@
+use crate::{Generator, GeneratorResult, ValueResult};
+
+/// Groups values into `Vec` chunks of a fixed size. See [`.chunks()`](crate::GeneratorExt::chunks) for details.
+#[derive(Clone)]
+pub struct Chunks<Src>
+where
+    Src: Generator,
+{
+    source: Src,
+    size: usize,
+    chunk: Vec<Src::Output>,
+}
+
+impl<Src> Chunks<Src>
+where
+    Src: Generator,
+{
+    #[inline]
+    pub(crate) fn new(source: Src, size: usize) -> Self {
+        if size == 0 {
+            panic!("Chunk size must not be 0");
+        }
+        Self {
+            source,
+            size,
+            chunk: Vec::with_capacity(size),
+        }
+    }
+}
+
+impl<Src> Generator for Chunks<Src>
+where
+    Src: Generator,
+{
+    type Output = Vec<Src::Output>;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        let size = self.size;
+        let chunk = &mut self.chunk;
+        let result = self.source.run(|x| {
+            chunk.push(x);
+            if chunk.len() == size {
+                output(core::mem::replace(chunk, Vec::with_capacity(size)))
+            } else {
+                ValueResult::MoreValues
+            }
+        });
+
+        // Flush the partial final chunk.
+        if result == GeneratorResult::Complete
+            && !self.chunk.is_empty()
+            && output(core::mem::take(&mut self.chunk)) == ValueResult::Stop
+        {
+            return GeneratorResult::Stopped;
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::StoppingGen;
+    use crate::{GeneratorExt, GeneratorResult, IntoGenerator};
+
+    #[test]
+    fn chunks() {
+        let data = [1, 2, 3, 4, 5];
+        let output: Vec<_> = data.into_gen().chunks(2).collect();
+        assert_eq!(output, [vec![1, 2], vec![3, 4], vec![5]]);
+
+        let output: Vec<_> = data.into_gen().chunks(5).collect();
+        assert_eq!(output, [vec![1, 2, 3, 4, 5]]);
+
+        let empty: [i32; 0] = [];
+        assert_eq!(empty.into_gen().chunks(2).count(), 0);
+    }
+
+    #[test]
+    fn stop_at_partial_chunk() {
+        let data = [1, 2, 3];
+        let mut gen = data.into_gen().chunks(2);
+        assert_eq!(gen.next(), Ok(vec![1, 2]));
+        assert_eq!(gen.next(), Ok(vec![3]));
+        assert_eq!(gen.next(), Err(GeneratorResult::Complete));
+    }
+
+    #[test]
+    fn spuriously_stopping() {
+        let data = [1, 2, 3, 4, 5];
+        for x in 0..5 {
+            let mut gen = StoppingGen::new(x, &data).copied().chunks(2);
+            let mut output = Vec::new();
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Stopped);
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Complete);
+            assert_eq!(output, [vec![1, 2], vec![3, 4], vec![5]]);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_size() {
+        let data = [1, 2, 3];
+        let _gen = data.into_gen().chunks(0);
+    }
+}
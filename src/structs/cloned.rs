@@ -1,4 +1,4 @@
-use crate::{Generator, GeneratorResult, ReverseGenerator, ValueResult};
+use crate::{FusedGenerator, Generator, GeneratorResult, ReverseGenerator, ValueResult};
 use core::num::NonZeroUsize;
 
 /// A generator that clones the elements of an underlying generator. See `[.cloned()](crate::GeneratorExt::cloned)
@@ -49,6 +49,13 @@ where
     }
 }
 
+impl<'a, Src, T> FusedGenerator for Cloned<Src>
+where
+    T: 'a + Clone,
+    Src: FusedGenerator<Output = &'a T>,
+{
+}
+
 #[cfg(test)]
 mod tests {
     use crate::test::StoppingGen;
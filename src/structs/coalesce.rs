@@ -0,0 +1,123 @@
+use crate::structs::utility::InplaceUpdatable;
+use crate::{Generator, GeneratorExt, GeneratorResult, ValueResult};
+
+/// Merging of adjacent values. See [`.coalesce()`](crate::GeneratorExt::coalesce) for details.
+#[derive(Clone)]
+pub struct Coalesce<Src, F>
+where
+    Src: Generator,
+{
+    source: Src,
+    f: F,
+    next: Option<Src::Output>,
+}
+
+impl<Src, F> Coalesce<Src, F>
+where
+    Src: Generator,
+{
+    #[inline]
+    pub(crate) fn new(source: Src, f: F) -> Self {
+        Self {
+            source,
+            f,
+            next: None,
+        }
+    }
+}
+
+impl<Src, F> Generator for Coalesce<Src, F>
+where
+    Src: Generator,
+    F: FnMut(Src::Output, Src::Output) -> Result<Src::Output, (Src::Output, Src::Output)>,
+{
+    type Output = Src::Output;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        let prev = match self.next.take() {
+            Some(value) => value,
+            None => match self.source.next() {
+                Ok(x) => x,
+                Err(err) => return err,
+            },
+        };
+
+        let mut prev = InplaceUpdatable::new(prev);
+        let f = &mut self.f;
+        let mut result = self.source.run(|x| {
+            let done = prev.update_with_result(|prev| match f(prev, x) {
+                Ok(merged) => (merged, None),
+                Err((done, next)) => (next, Some(done)),
+            });
+            match done {
+                Some(done) => output(done),
+                None => ValueResult::MoreValues,
+            }
+        });
+
+        let prev = prev.get_inner();
+        if result == GeneratorResult::Complete {
+            if output(prev) == ValueResult::Stop {
+                result = GeneratorResult::Stopped;
+            }
+        } else {
+            // More values might be merged into `prev` on later runs.
+            self.next = Some(prev);
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::{collect_one_at_a_time, StoppingGen};
+    use crate::{GeneratorExt, GeneratorResult, IntoGenerator};
+
+    #[test]
+    fn coalesce() {
+        let data = [1, 2, -3, -4, 5, -6];
+        let output: Vec<_> = data
+            .into_gen()
+            .coalesce(|a, b| {
+                if (a < 0) == (b < 0) {
+                    Ok(a + b)
+                } else {
+                    Err((a, b))
+                }
+            })
+            .collect();
+        assert_eq!(output, [3, -7, 5, -6]);
+
+        let output: Vec<i32> = [0; 0].into_gen().coalesce(|a, b| Ok(a + b)).collect();
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn consumer_stop() {
+        let mut gen = [1, 1, 2, 3, 3]
+            .into_gen()
+            .coalesce(|a, b| if a == b { Ok(a) } else { Err((a, b)) });
+        let output = collect_one_at_a_time(&mut gen);
+        assert_eq!(output, [1, 2, 3]);
+    }
+
+    #[test]
+    fn spuriously_stopping() {
+        let data = [1, 2, 4, 5, 6, 8];
+        for x in 0..6 {
+            let mut gen = StoppingGen::new(x, &data).copied().coalesce(|a, b| {
+                if a + 1 == b {
+                    Ok(b)
+                } else {
+                    Err((a, b))
+                }
+            });
+            let mut output = Vec::new();
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Stopped);
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Complete);
+            assert_eq!(output, [2, 6, 8]);
+        }
+    }
+}
@@ -0,0 +1,368 @@
+use crate::{Generator, GeneratorResult, ValueResult};
+
+/// Collects all values of `source` into `pool`.
+///
+/// Returns `true` once the source has completed.
+#[inline]
+fn fill_pool<Src: Generator>(source: &mut Src, pool: &mut Vec<Src::Output>) -> bool {
+    source.run(|x| {
+        pool.push(x);
+        ValueResult::MoreValues
+    }) == GeneratorResult::Complete
+}
+
+/// Advances `indices` to the next combination of `indices.len()` out of `n` positions, in
+/// lexicographic order.
+///
+/// Returns `false` if `indices` already was the last combination.
+#[inline]
+fn next_combination(indices: &mut [usize], n: usize) -> bool {
+    let k = indices.len();
+    match (0..k).rev().find(|&i| indices[i] != i + n - k) {
+        Some(i) => {
+            indices[i] += 1;
+            for j in i + 1..k {
+                indices[j] = indices[j - 1] + 1;
+            }
+            true
+        }
+        None => false,
+    }
+}
+
+/// A generator of the `k`-combinations of the values of a generator. See
+/// [`.combinations()`](crate::GeneratorExt::combinations) for details.
+#[derive(Clone)]
+pub struct Combinations<Src>
+where
+    Src: Generator,
+{
+    source: Src,
+    k: usize,
+    pool: Vec<Src::Output>,
+    // `None` until the whole source has been collected into `pool`.
+    indices: Option<Vec<usize>>,
+    done: bool,
+}
+
+impl<Src> Combinations<Src>
+where
+    Src: Generator,
+{
+    #[inline]
+    pub(crate) fn new(source: Src, k: usize) -> Self {
+        Self {
+            source,
+            k,
+            pool: Vec::new(),
+            indices: None,
+            done: false,
+        }
+    }
+}
+
+impl<Src> Generator for Combinations<Src>
+where
+    Src: Generator,
+    Src::Output: Clone,
+{
+    type Output = Vec<Src::Output>;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        let k = self.k;
+        if self.indices.is_none() {
+            if !fill_pool(&mut self.source, &mut self.pool) {
+                return GeneratorResult::Stopped;
+            }
+            self.done = k > self.pool.len();
+            self.indices = Some((0..k).collect());
+        }
+
+        let (pool, done) = (&self.pool, &mut self.done);
+        let n = pool.len();
+        let indices = match &mut self.indices {
+            Some(indices) => indices,
+            None => unreachable!(),
+        };
+
+        while !*done {
+            let combination = indices.iter().map(|&i| pool[i].clone()).collect();
+
+            *done = !next_combination(indices, n);
+
+            if output(combination) == ValueResult::Stop {
+                return GeneratorResult::Stopped;
+            }
+        }
+        GeneratorResult::Complete
+    }
+}
+
+/// A generator of the `k`-permutations of the values of a generator. See
+/// [`.permutations()`](crate::GeneratorExt::permutations) for details.
+#[derive(Clone)]
+pub struct Permutations<Src>
+where
+    Src: Generator,
+{
+    source: Src,
+    k: usize,
+    pool: Vec<Src::Output>,
+    // `None` until the whole source has been collected into `pool`.
+    state: Option<PermutationState>,
+    done: bool,
+}
+
+#[derive(Clone)]
+struct PermutationState {
+    indices: Vec<usize>,
+    cycles: Vec<usize>,
+}
+
+impl<Src> Permutations<Src>
+where
+    Src: Generator,
+{
+    #[inline]
+    pub(crate) fn new(source: Src, k: usize) -> Self {
+        Self {
+            source,
+            k,
+            pool: Vec::new(),
+            state: None,
+            done: false,
+        }
+    }
+}
+
+impl<Src> Generator for Permutations<Src>
+where
+    Src: Generator,
+    Src::Output: Clone,
+{
+    type Output = Vec<Src::Output>;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        let k = self.k;
+        if self.state.is_none() {
+            if !fill_pool(&mut self.source, &mut self.pool) {
+                return GeneratorResult::Stopped;
+            }
+            let n = self.pool.len();
+            self.done = k > n;
+            self.state = Some(PermutationState {
+                indices: (0..n).collect(),
+                cycles: (0..k.min(n)).map(|i| n - i).collect(),
+            });
+        }
+
+        let (pool, done) = (&self.pool, &mut self.done);
+        let n = pool.len();
+        let state = match &mut self.state {
+            Some(state) => state,
+            None => unreachable!(),
+        };
+
+        while !*done {
+            let permutation = state.indices[..k]
+                .iter()
+                .map(|&i| pool[i].clone())
+                .collect();
+
+            // Advance to the next permutation in lexicographic order of the indices.
+            *done = true;
+            for i in (0..k).rev() {
+                state.cycles[i] -= 1;
+                if state.cycles[i] == 0 {
+                    state.indices[i..].rotate_left(1);
+                    state.cycles[i] = n - i;
+                } else {
+                    let j = state.cycles[i];
+                    state.indices.swap(i, n - j);
+                    *done = false;
+                    break;
+                }
+            }
+
+            if output(permutation) == ValueResult::Stop {
+                return GeneratorResult::Stopped;
+            }
+        }
+        GeneratorResult::Complete
+    }
+}
+
+/// A generator of all subsets of the values of a generator. See
+/// [`.powerset()`](crate::GeneratorExt::powerset) for details.
+#[derive(Clone)]
+pub struct Powerset<Src>
+where
+    Src: Generator,
+{
+    source: Src,
+    pool: Vec<Src::Output>,
+    // `None` until the whole source has been collected into `pool`.
+    indices: Option<Vec<usize>>,
+    done: bool,
+}
+
+impl<Src> Powerset<Src>
+where
+    Src: Generator,
+{
+    #[inline]
+    pub(crate) fn new(source: Src) -> Self {
+        Self {
+            source,
+            pool: Vec::new(),
+            indices: None,
+            done: false,
+        }
+    }
+}
+
+impl<Src> Generator for Powerset<Src>
+where
+    Src: Generator,
+    Src::Output: Clone,
+{
+    type Output = Vec<Src::Output>;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        if self.indices.is_none() {
+            if !fill_pool(&mut self.source, &mut self.pool) {
+                return GeneratorResult::Stopped;
+            }
+            self.indices = Some(Vec::new());
+        }
+
+        let (pool, done) = (&self.pool, &mut self.done);
+        let n = pool.len();
+        let indices = match &mut self.indices {
+            Some(indices) => indices,
+            None => unreachable!(),
+        };
+
+        while !*done {
+            let subset = indices.iter().map(|&i| pool[i].clone()).collect();
+
+            // Once all subsets of one size have been generated, continue with the next size.
+            if !next_combination(indices, n) {
+                let k = indices.len() + 1;
+                if k > n {
+                    *done = true;
+                } else {
+                    indices.clear();
+                    indices.extend(0..k);
+                }
+            }
+
+            if output(subset) == ValueResult::Stop {
+                return GeneratorResult::Stopped;
+            }
+        }
+        GeneratorResult::Complete
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::{collect_one_at_a_time, StoppingGen};
+    use crate::{Generator, GeneratorExt, GeneratorResult, IntoGenerator, ValueResult};
+    use itertools::Itertools;
+
+    #[test]
+    fn combinations() {
+        let data = [1, 2, 3, 4];
+        for k in 0..=5 {
+            let output: Vec<_> = data.into_gen().combinations(k).collect();
+            let expected: Vec<_> = data.iter().copied().combinations(k).collect();
+            assert_eq!(output, expected, "k = {}", k);
+        }
+
+        let empty: [i32; 0] = [];
+        assert_eq!(
+            empty.into_gen().combinations(0).collect::<Vec<_>>(),
+            [Vec::<i32>::new()]
+        );
+        assert_eq!(empty.into_gen().combinations(1).count(), 0);
+    }
+
+    #[test]
+    fn permutations() {
+        let data = [1, 2, 3, 4];
+        for k in 0..=5 {
+            let output: Vec<_> = data.into_gen().permutations(k).collect();
+            let expected: Vec<_> = data.iter().copied().permutations(k).collect();
+            assert_eq!(output, expected, "k = {}", k);
+        }
+    }
+
+    #[test]
+    fn powerset() {
+        let data = [1, 2, 3, 4];
+        let output: Vec<_> = data.into_gen().powerset().collect();
+        let expected: Vec<_> = data.iter().copied().powerset().collect();
+        assert_eq!(output, expected);
+
+        let empty: [i32; 0] = [];
+        assert_eq!(
+            empty.into_gen().powerset().collect::<Vec<_>>(),
+            [Vec::<i32>::new()]
+        );
+    }
+
+    #[test]
+    fn powerset_spuriously_stopping() {
+        let data = [1, 2, 3];
+        for x in 0..data.len() {
+            let mut gen = StoppingGen::new(x as i32, &data).copied().powerset();
+            let mut output = Vec::new();
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Stopped);
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Complete);
+            assert_eq!(output.len(), 8);
+        }
+    }
+
+    #[test]
+    fn consumer_stop() {
+        let data = [1, 2, 3];
+        let mut gen = data.into_gen().combinations(2);
+        let output = collect_one_at_a_time(&mut gen);
+        assert_eq!(output, [vec![1, 2], vec![1, 3], vec![2, 3]]);
+        assert_eq!(
+            gen.run(|_| ValueResult::MoreValues),
+            GeneratorResult::Complete
+        );
+
+        let mut gen = data.into_gen().permutations(2);
+        let output = collect_one_at_a_time(&mut gen);
+        assert_eq!(output.len(), 6);
+        assert_eq!(
+            gen.run(|_| ValueResult::MoreValues),
+            GeneratorResult::Complete
+        );
+    }
+
+    #[test]
+    fn spuriously_stopping() {
+        let data = [1, 2, 3];
+        for x in 0..data.len() {
+            let mut gen = StoppingGen::new(x as i32, &data).copied().combinations(2);
+            let mut output = Vec::new();
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Stopped);
+            assert!(output.is_empty());
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Complete);
+            assert_eq!(output, [vec![1, 2], vec![1, 3], vec![2, 3]]);
+
+            let mut gen = StoppingGen::new(x as i32, &data).copied().permutations(3);
+            let mut output = Vec::new();
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Stopped);
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Complete);
+            assert_eq!(output.len(), 6);
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Generator, GeneratorResult, ReverseGenerator, ValueResult};
+use crate::{FusedGenerator, Generator, GeneratorResult, ReverseGenerator, ValueResult};
 use core::num::NonZeroUsize;
 
 /// A generator that copies the elements of an underlying generator. See [`.copied()`](crate::GeneratorExt::copied) for details.
@@ -26,7 +26,7 @@ where
     }
 
     #[inline]
-    fn try_advance(&mut self, n: core::num::NonZeroUsize) -> (usize, GeneratorResult) {
+    fn try_advance(&mut self, n: NonZeroUsize) -> (usize, GeneratorResult) {
         self.source.try_advance(n)
     }
 }
@@ -47,10 +47,17 @@ where
     }
 }
 
+impl<'a, Src, T> FusedGenerator for Copied<Src>
+where
+    T: 'a + Copy,
+    Src: FusedGenerator<Output = &'a T>,
+{
+}
+
 #[cfg(test)]
 mod tests {
     use crate::test::StoppingGen;
-    use crate::{GeneratorExt, GeneratorResult, ReverseGenerator, SliceGenerator};
+    use crate::{Generator, GeneratorExt, GeneratorResult, ReverseGenerator, SliceGenerator};
     use std::num::NonZeroUsize;
 
     #[test]
@@ -67,6 +74,18 @@ mod tests {
         }
     }
 
+    #[test]
+    fn try_advance() {
+        let data = [1, 2, 3];
+        let mut gen = SliceGenerator::new(&data).copied();
+        assert_eq!(
+            gen.try_advance(NonZeroUsize::new(2).unwrap()),
+            (2, GeneratorResult::Stopped)
+        );
+        assert_eq!(gen.next(), Ok(3));
+        assert_eq!(gen.next(), Err(GeneratorResult::Complete));
+    }
+
     #[test]
     fn reverse() {
         let data = [1, 2, 3];
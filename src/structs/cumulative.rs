@@ -0,0 +1,150 @@
+use crate::{structs::utility::set_some, FusedGenerator, Generator, GeneratorResult, ValueResult};
+use core::ops::{Add, Mul};
+
+/// Generates the running totals of the source, combining each value into the total with `combine`.
+#[inline]
+fn run_cumulative<Src>(
+    source: &mut Src,
+    total: &mut Option<Src::Output>,
+    mut combine: impl FnMut(Src::Output, Src::Output) -> Src::Output,
+    mut output: impl FnMut(Src::Output) -> ValueResult,
+) -> GeneratorResult
+where
+    Src: Generator,
+    Src::Output: Clone,
+{
+    source.run(|x| {
+        let new_total = match total.take() {
+            Some(prev) => combine(prev, x),
+            None => x,
+        };
+        output(set_some(total, new_total).clone())
+    })
+}
+
+/// A generator of running sums. See [`.cumsum()`](crate::GeneratorExt::cumsum) for details.
+#[derive(Clone)]
+pub struct CumSum<Src>
+where
+    Src: Generator,
+{
+    source: Src,
+    total: Option<Src::Output>,
+}
+
+impl<Src> CumSum<Src>
+where
+    Src: Generator,
+{
+    #[inline]
+    pub(crate) fn new(source: Src) -> Self {
+        Self {
+            source,
+            total: None,
+        }
+    }
+}
+
+impl<Src> Generator for CumSum<Src>
+where
+    Src: Generator,
+    Src::Output: Add<Output = Src::Output> + Clone,
+{
+    type Output = Src::Output;
+
+    #[inline]
+    fn run(&mut self, output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        run_cumulative(&mut self.source, &mut self.total, Add::add, output)
+    }
+}
+
+impl<Src> FusedGenerator for CumSum<Src>
+where
+    Src: FusedGenerator,
+    Src::Output: Add<Output = Src::Output> + Clone,
+{
+}
+
+/// A generator of running products. See [`.cumprod()`](crate::GeneratorExt::cumprod) for details.
+#[derive(Clone)]
+pub struct CumProd<Src>
+where
+    Src: Generator,
+{
+    source: Src,
+    total: Option<Src::Output>,
+}
+
+impl<Src> CumProd<Src>
+where
+    Src: Generator,
+{
+    #[inline]
+    pub(crate) fn new(source: Src) -> Self {
+        Self {
+            source,
+            total: None,
+        }
+    }
+}
+
+impl<Src> Generator for CumProd<Src>
+where
+    Src: Generator,
+    Src::Output: Mul<Output = Src::Output> + Clone,
+{
+    type Output = Src::Output;
+
+    #[inline]
+    fn run(&mut self, output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        run_cumulative(&mut self.source, &mut self.total, Mul::mul, output)
+    }
+}
+
+impl<Src> FusedGenerator for CumProd<Src>
+where
+    Src: FusedGenerator,
+    Src::Output: Mul<Output = Src::Output> + Clone,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::StoppingGen;
+    use crate::{GeneratorExt, GeneratorResult, IntoGenerator};
+
+    #[test]
+    fn cumsum() {
+        let data = [1, 2, 3, 4];
+        let output: Vec<_> = data.into_gen().cumsum().collect();
+        assert_eq!(output, [1, 3, 6, 10]);
+
+        let empty: [i32; 0] = [];
+        assert_eq!(empty.into_gen().cumsum().count(), 0);
+    }
+
+    #[test]
+    fn cumprod() {
+        let data = [1.0, 2.0, 3.0, 0.5];
+        let output: Vec<_> = data.into_gen().cumprod().collect();
+        assert_eq!(output, [1.0, 2.0, 6.0, 3.0]);
+    }
+
+    #[test]
+    fn spuriously_stopping() {
+        let data = [1, 2, 3, 4];
+        for x in 0..data.len() {
+            let mut gen = StoppingGen::new(x as i32, &data).copied().cumsum();
+            let mut output = Vec::new();
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Stopped);
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Complete);
+            assert_eq!(output, [1, 3, 6, 10]);
+
+            let mut gen = StoppingGen::new(x as i32, &data).copied().cumprod();
+            let mut output = Vec::new();
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Stopped);
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Complete);
+            assert_eq!(output, [1, 2, 6, 24]);
+        }
+    }
+}
@@ -1,9 +1,12 @@
 use crate::{Generator, GeneratorResult, ValueResult};
 
 /// Repeates a generator endlessly. See [`cycle()`](crate::GeneratorExt::cycle) for details.
+#[derive(Clone)]
 pub struct Cycle<Src> {
     source: Src,
     current: Src,
+    // No value has been generated since `current` was last restarted.
+    empty_cycle: bool,
 }
 
 impl<Src: Clone> Cycle<Src> {
@@ -11,6 +14,7 @@ impl<Src: Clone> Cycle<Src> {
         Self {
             source: source.clone(),
             current: source,
+            empty_cycle: true,
         }
     }
 }
@@ -21,11 +25,60 @@ impl<Src: Clone + Generator> Generator for Cycle<Src> {
     #[inline]
     fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
         loop {
+            let empty_cycle = &mut self.empty_cycle;
+            let result = self.current.run(|x| {
+                *empty_cycle = false;
+                output(x)
+            });
+            match result {
+                GeneratorResult::Stopped => return GeneratorResult::Stopped,
+                // An empty source would otherwise be restarted forever.
+                GeneratorResult::Complete if self.empty_cycle => return GeneratorResult::Complete,
+                GeneratorResult::Complete => {
+                    self.current = self.source.clone();
+                    self.empty_cycle = true;
+                }
+            }
+        }
+    }
+}
+
+/// Repeats a generator a fixed number of times. See [`cycle_n()`](crate::GeneratorExt::cycle_n) for details.
+#[derive(Clone)]
+pub struct CycleN<Src> {
+    source: Src,
+    current: Src,
+    // Number of passes left, including the one in `current`.
+    remaining: usize,
+}
+
+impl<Src: Clone> CycleN<Src> {
+    pub(crate) fn new(source: Src, times: usize) -> Self {
+        Self {
+            source: source.clone(),
+            current: source,
+            remaining: times,
+        }
+    }
+}
+
+impl<Src: Clone + Generator> Generator for CycleN<Src> {
+    type Output = Src::Output;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        while self.remaining > 0 {
             match self.current.run(&mut output) {
                 GeneratorResult::Stopped => return GeneratorResult::Stopped,
-                GeneratorResult::Complete => self.current = self.source.clone(),
+                GeneratorResult::Complete => {
+                    self.remaining -= 1;
+                    if self.remaining > 0 {
+                        self.current = self.source.clone();
+                    }
+                }
             }
         }
+        GeneratorResult::Complete
     }
 }
 
@@ -33,7 +86,7 @@ impl<Src: Clone + Generator> Generator for Cycle<Src> {
 mod tests {
     use super::*;
     use crate::test::MultiStoppingGen;
-    use crate::{GeneratorExt, IntoGenerator};
+    use crate::{GeneratorExt, GeneratorResult, IntoGenerator};
 
     #[test]
     fn cycle() {
@@ -53,6 +106,47 @@ mod tests {
         assert_eq!(gen.next(), Ok(&3));
     }
 
+    #[test]
+    fn empty_source() {
+        let data: [i32; 0] = [];
+        let mut gen = data.into_gen().cycle();
+        assert_eq!(gen.next(), Err(GeneratorResult::Complete));
+        assert_eq!(gen.next(), Err(GeneratorResult::Complete));
+    }
+
+    #[test]
+    fn cycle_take() {
+        let data = [1, 2, 3];
+        let output: Vec<_> = data.into_gen().cycle().take(7).collect();
+        assert_eq!(output, [1, 2, 3, 1, 2, 3, 1]);
+    }
+
+    #[test]
+    fn cycle_n() {
+        let data = [1, 2];
+        let output: Vec<_> = data.into_gen().cycle_n(3).collect();
+        assert_eq!(output, [1, 2, 1, 2, 1, 2]);
+
+        assert_eq!(data.into_gen().cycle_n(0).count(), 0);
+
+        let empty: [i32; 0] = [];
+        assert_eq!(empty.into_gen().cycle_n(3).count(), 0);
+    }
+
+    #[test]
+    fn cycle_n_spuriously_stopping() {
+        let data = [None, Some(1), None, Some(2)];
+        let mut gen = MultiStoppingGen::new(&data).cycle_n(2);
+        let mut output = Vec::new();
+        let mut num_stops = 0;
+        while gen.for_each(|x| output.push(*x)) == GeneratorResult::Stopped {
+            num_stops += 1;
+        }
+        assert_eq!(num_stops, 4);
+        assert_eq!(output, [1, 2, 1, 2]);
+        assert_eq!(gen.next(), Err(GeneratorResult::Complete));
+    }
+
     #[test]
     fn spuriously_stopping() {
         use crate::GeneratorResult::Stopped;
@@ -1,4 +1,4 @@
-use crate::{Generator, GeneratorExt, GeneratorResult, ValueResult};
+use crate::{Generator, GeneratorExt, GeneratorResult, ReverseGenerator, ValueResult};
 use core::mem;
 
 /// Deduplication of duplicate consecutive values. See [`.dedup()`](crate::GeneratorExt::dedup) for details.
@@ -10,6 +10,7 @@ where
 {
     source: Src,
     next: Option<Src::Output>,
+    next_back: Option<Src::Output>,
 }
 
 impl<Src> Dedup<Src>
@@ -19,7 +20,11 @@ where
 {
     #[inline]
     pub(crate) fn new(source: Src) -> Self {
-        Self { source, next: None }
+        Self {
+            source,
+            next: None,
+            next_back: None,
+        }
     }
 }
 
@@ -67,6 +72,235 @@ where
     }
 }
 
+impl<Src> ReverseGenerator for Dedup<Src>
+where
+    Src: ReverseGenerator,
+    Src::Output: PartialEq,
+{
+    #[inline]
+    fn run_back(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        let mut prev = match self.next_back.take() {
+            Some(value) => value,
+            None => match self.source.next_back() {
+                Ok(x) => x,
+                Err(err) => return err,
+            },
+        };
+
+        let mut result = self.source.run_back(|x| {
+            if x == prev {
+                prev = x;
+                ValueResult::MoreValues
+            } else {
+                output(mem::replace(&mut prev, x))
+            }
+        });
+
+        if result == GeneratorResult::Complete {
+            if output(prev) == ValueResult::Stop {
+                result = GeneratorResult::Stopped;
+            }
+        } else {
+            self.next_back = Some(prev);
+        }
+
+        result
+    }
+}
+
+/// Deduplication of consecutive values using a custom equality. See [`.dedup_by()`](crate::GeneratorExt::dedup_by) for details.
+#[derive(Clone)]
+pub struct DedupBy<Src, F>
+where
+    Src: Generator,
+{
+    source: Src,
+    same: F,
+    next: Option<Src::Output>,
+}
+
+impl<Src, F> DedupBy<Src, F>
+where
+    Src: Generator,
+    F: FnMut(&Src::Output, &Src::Output) -> bool,
+{
+    #[inline]
+    pub(crate) fn new(source: Src, same: F) -> Self {
+        Self {
+            source,
+            same,
+            next: None,
+        }
+    }
+}
+
+impl<Src, F> Generator for DedupBy<Src, F>
+where
+    Src: Generator,
+    F: FnMut(&Src::Output, &Src::Output) -> bool,
+{
+    type Output = Src::Output;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        let mut prev = match self.next.take() {
+            Some(value) => value,
+            None => match self.source.next() {
+                Ok(x) => x,
+                Err(err) => return err,
+            },
+        };
+
+        let same = &mut self.same;
+        let mut result = self.source.run(|x| {
+            if same(&prev, &x) {
+                ValueResult::MoreValues
+            } else {
+                output(mem::replace(&mut prev, x))
+            }
+        });
+
+        if result == GeneratorResult::Complete {
+            if output(prev) == ValueResult::Stop {
+                result = GeneratorResult::Stopped;
+            }
+        } else {
+            self.next = Some(prev);
+        }
+
+        result
+    }
+}
+
+/// Deduplication of consecutive values with equal keys. See [`.dedup_by_key()`](crate::GeneratorExt::dedup_by_key) for details.
+#[derive(Clone)]
+pub struct DedupByKey<Src, F, K>
+where
+    Src: Generator,
+{
+    source: Src,
+    key_fn: F,
+    // The held value together with its key, so the key is only computed once per value.
+    next: Option<(K, Src::Output)>,
+}
+
+impl<Src, F, K> DedupByKey<Src, F, K>
+where
+    Src: Generator,
+    F: FnMut(&Src::Output) -> K,
+    K: PartialEq,
+{
+    #[inline]
+    pub(crate) fn new(source: Src, key_fn: F) -> Self {
+        Self {
+            source,
+            key_fn,
+            next: None,
+        }
+    }
+}
+
+impl<Src, F, K> Generator for DedupByKey<Src, F, K>
+where
+    Src: Generator,
+    F: FnMut(&Src::Output) -> K,
+    K: PartialEq,
+{
+    type Output = Src::Output;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        let key_fn = &mut self.key_fn;
+        let (mut prev_key, mut prev) = match self.next.take() {
+            Some(held) => held,
+            None => match self.source.next() {
+                Ok(x) => (key_fn(&x), x),
+                Err(err) => return err,
+            },
+        };
+
+        let mut result = self.source.run(|x| {
+            let key = key_fn(&x);
+            if key == prev_key {
+                ValueResult::MoreValues
+            } else {
+                prev_key = key;
+                output(mem::replace(&mut prev, x))
+            }
+        });
+
+        if result == GeneratorResult::Complete {
+            if output(prev) == ValueResult::Stop {
+                result = GeneratorResult::Stopped;
+            }
+        } else {
+            self.next = Some((prev_key, prev));
+        }
+
+        result
+    }
+}
+
+/// Collapses runs of equal consecutive values into `(count, value)` pairs. See [`.dedup_with_count()`](crate::GeneratorExt::dedup_with_count) for details.
+#[derive(Clone)]
+pub struct DedupWithCount<Src>
+where
+    Src: Generator,
+{
+    source: Src,
+    next: Option<(usize, Src::Output)>,
+}
+
+impl<Src> DedupWithCount<Src>
+where
+    Src: Generator,
+    Src::Output: PartialEq,
+{
+    #[inline]
+    pub(crate) fn new(source: Src) -> Self {
+        Self { source, next: None }
+    }
+}
+
+impl<Src> Generator for DedupWithCount<Src>
+where
+    Src: Generator,
+    Src::Output: PartialEq,
+{
+    type Output = (usize, Src::Output);
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        let (mut count, mut prev) = match self.next.take() {
+            Some(held) => held,
+            None => match self.source.next() {
+                Ok(x) => (1, x),
+                Err(err) => return err,
+            },
+        };
+
+        let mut result = self.source.run(|x| {
+            if x == prev {
+                count += 1;
+                ValueResult::MoreValues
+            } else {
+                let run_length = mem::replace(&mut count, 1);
+                output((run_length, mem::replace(&mut prev, x)))
+            }
+        });
+
+        if result == GeneratorResult::Complete {
+            if output((count, prev)) == ValueResult::Stop {
+                result = GeneratorResult::Stopped;
+            }
+        } else {
+            self.next = Some((count, prev));
+        }
+
+        result
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -99,6 +333,101 @@ mod tests {
         assert_eq!(out, [1, 2, 3, 4]);
     }
 
+    #[test]
+    fn dedup_reverse() {
+        let data = [1, 1, 2, 3, 3];
+        let mut gen = Dedup::new(SliceGenerator::new(&data).copied());
+        let mut output = Vec::new();
+        let result = gen.run_back(|x| {
+            output.push(x);
+            ValueResult::MoreValues
+        });
+        assert_eq!(result, GeneratorResult::Complete);
+        assert_eq!(output, [3, 2, 1]);
+    }
+
+    #[test]
+    fn dedup_next_back() {
+        let data = [1, 1, 2, 3, 3];
+        let mut gen = SliceGenerator::new(&data).dedup();
+        assert_eq!(gen.next_back(), Ok(&3));
+        assert_eq!(gen.next_back(), Ok(&2));
+        assert_eq!(gen.next_back(), Ok(&1));
+        assert_eq!(gen.next_back(), Err(GeneratorResult::Complete));
+    }
+
+    #[test]
+    fn dedup_consumer_stop() {
+        let data = [1, 1, 2, 2, 2, 3, 3];
+        let mut gen = SliceGenerator::new(&data).copied().dedup();
+        let mut output = Vec::new();
+        loop {
+            // Stop after every value to make sure the last seen value is kept between runs.
+            let result = gen.run(|x| {
+                output.push(x);
+                ValueResult::Stop
+            });
+            if result == GeneratorResult::Complete {
+                break;
+            }
+        }
+        assert_eq!(output, [1, 2, 3]);
+    }
+
+    #[test]
+    fn dedup_by() {
+        let data = ["a", "A", "b", "B", "b", "c", "a"];
+        let out = run(SliceGenerator::new(&data).dedup_by(|a, b| a.eq_ignore_ascii_case(b)));
+        assert_eq!(out, [&"a", &"b", &"c", &"a"]);
+    }
+
+    #[test]
+    fn dedup_by_stopping_source() {
+        let data = [1, 3, 2, 4, 4, 5];
+        for x in 0..10 {
+            let gen = crate::test::StoppingGen::new(x, &data);
+            let out = run(gen.dedup_by(|a, b| *a % 2 == *b % 2).copied());
+            assert_eq!(out, [1, 2, 5]);
+        }
+    }
+
+    #[test]
+    fn dedup_by_key() {
+        let data = [(1, "a"), (1, "b"), (2, "c"), (1, "d"), (1, "e")];
+        let out = run(SliceGenerator::new(&data).dedup_by_key(|x| x.0));
+        assert_eq!(out, [&(1, "a"), &(2, "c"), &(1, "d")]);
+    }
+
+    #[test]
+    fn dedup_by_key_stopping_source() {
+        let data = [1, 3, 2, 4, 4, 5];
+        for x in 0..10 {
+            let gen = crate::test::StoppingGen::new(x, &data);
+            let out = run(gen.dedup_by_key(|x| *x % 2).copied());
+            assert_eq!(out, [1, 2, 5]);
+        }
+    }
+
+    #[test]
+    fn dedup_with_count() {
+        let data = [1, 1, 2, 3, 3, 3, 1];
+        let out = run(SliceGenerator::new(&data).copied().dedup_with_count());
+        assert_eq!(out, [(2, 1), (1, 2), (3, 3), (1, 1)]);
+
+        let empty: [i32; 0] = [];
+        assert!(run(SliceGenerator::new(&empty).dedup_with_count()).is_empty());
+    }
+
+    #[test]
+    fn dedup_with_count_stopping_source() {
+        let data = [1, 1, 2, 3, 3, 3, 1];
+        for x in 0..10 {
+            let gen = crate::test::StoppingGen::new(x, &data);
+            let out = run(gen.copied().dedup_with_count());
+            assert_eq!(out, [(2, 1), (1, 2), (3, 3), (1, 1)]);
+        }
+    }
+
     #[test]
     fn dedup_stopping_source() {
         let data = [1, 2, 2, 3, 3, 4];
@@ -0,0 +1,145 @@
+use crate::{FusedGenerator, Generator, GeneratorResult, ValueResult};
+use core::ops::Sub;
+
+/// A generator of the differences between consecutive values. See
+/// [`.diff()`](crate::GeneratorExt::diff) for details.
+#[derive(Clone)]
+pub struct Diff<Src>
+where
+    Src: Generator,
+{
+    source: Src,
+    prev: Option<Src::Output>,
+}
+
+impl<Src> Diff<Src>
+where
+    Src: Generator,
+{
+    #[inline]
+    pub(crate) fn new(source: Src) -> Self {
+        Self { source, prev: None }
+    }
+}
+
+impl<Src> Generator for Diff<Src>
+where
+    Src: Generator,
+    Src::Output: Sub<Output = Src::Output> + Clone,
+{
+    type Output = Src::Output;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        let prev = &mut self.prev;
+        self.source.run(|x| match prev.replace(x.clone()) {
+            Some(p) => output(x - p),
+            None => ValueResult::MoreValues,
+        })
+    }
+}
+
+impl<Src> FusedGenerator for Diff<Src>
+where
+    Src: FusedGenerator,
+    Src::Output: Sub<Output = Src::Output> + Clone,
+{
+}
+
+/// A generator combining each value with its predecessor. See
+/// [`.diff_by()`](crate::GeneratorExt::diff_by) for details.
+#[derive(Clone)]
+pub struct DiffBy<Src, F>
+where
+    Src: Generator,
+{
+    source: Src,
+    combine: F,
+    prev: Option<Src::Output>,
+}
+
+impl<Src, F> DiffBy<Src, F>
+where
+    Src: Generator,
+{
+    #[inline]
+    pub(crate) fn new(source: Src, combine: F) -> Self {
+        Self {
+            source,
+            combine,
+            prev: None,
+        }
+    }
+}
+
+impl<Src, F, U> Generator for DiffBy<Src, F>
+where
+    Src: Generator,
+    F: FnMut(&Src::Output, &Src::Output) -> U,
+{
+    type Output = U;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        let prev = &mut self.prev;
+        let combine = &mut self.combine;
+        self.source.run(|x| {
+            let value = prev.as_ref().map(|p| combine(p, &x));
+            *prev = Some(x);
+            match value {
+                Some(value) => output(value),
+                None => ValueResult::MoreValues,
+            }
+        })
+    }
+}
+
+impl<Src, F, U> FusedGenerator for DiffBy<Src, F>
+where
+    Src: FusedGenerator,
+    F: FnMut(&Src::Output, &Src::Output) -> U,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::StoppingGen;
+    use crate::{GeneratorExt, GeneratorResult, IntoGenerator};
+
+    #[test]
+    fn diff() {
+        let data = [1, 4, 9, 16, 10];
+        let output: Vec<_> = data.into_gen().diff().collect();
+        assert_eq!(output, [3, 5, 7, -6]);
+
+        assert_eq!([1].into_gen().diff().count(), 0);
+    }
+
+    #[test]
+    fn diff_by() {
+        let data = ["a", "bb", "ccc"];
+        let output: Vec<_> = data
+            .into_gen()
+            .diff_by(|prev, x| format!("{}{}", prev, x))
+            .collect();
+        assert_eq!(output, ["abb", "bbccc"]);
+    }
+
+    #[test]
+    fn spuriously_stopping() {
+        let data = [1, 4, 9, 16];
+        for x in 0..data.len() {
+            let mut gen = StoppingGen::new(x as i32, &data).copied().diff();
+            let mut output = Vec::new();
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Stopped);
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Complete);
+            assert_eq!(output, [3, 5, 7]);
+
+            let mut gen = StoppingGen::new(x as i32, &data).diff_by(|a, b| *b - *a);
+            let mut output = Vec::new();
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Stopped);
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Complete);
+            assert_eq!(output, [3, 5, 7]);
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use crate::{FusedGenerator, Generator, GeneratorResult, ValueResult};
+use std::collections::VecDeque;
+
+/// A generator that holds back the last values of its source. See
+/// [`.dropping_back()`](crate::GeneratorExt::dropping_back) for details.
+pub struct DroppingBack<Src>
+where
+    Src: Generator,
+{
+    source: Src,
+    buffer: VecDeque<Src::Output>,
+    amount: usize,
+}
+
+impl<Src> DroppingBack<Src>
+where
+    Src: Generator,
+{
+    #[inline]
+    pub(crate) fn new(source: Src, amount: usize) -> Self {
+        Self {
+            source,
+            buffer: VecDeque::new(),
+            amount,
+        }
+    }
+}
+
+impl<Src> Clone for DroppingBack<Src>
+where
+    Src: Generator + Clone,
+    Src::Output: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            source: self.source.clone(),
+            buffer: self.buffer.clone(),
+            amount: self.amount,
+        }
+    }
+}
+
+impl<Src> Generator for DroppingBack<Src>
+where
+    Src: Generator,
+{
+    type Output = Src::Output;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        if self.amount == 0 {
+            return self.source.run(output);
+        }
+
+        let amount = self.amount;
+        let buffer = &mut self.buffer;
+        self.source.run(|x| {
+            let oldest = if buffer.len() == amount {
+                buffer.pop_front()
+            } else {
+                None
+            };
+            buffer.push_back(x);
+            if let Some(oldest) = oldest {
+                output(oldest)
+            } else {
+                ValueResult::MoreValues
+            }
+        })
+    }
+}
+
+impl<Src> FusedGenerator for DroppingBack<Src> where Src: FusedGenerator {}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::{collect_one_at_a_time, StoppingGen};
+    use crate::{Generator, GeneratorExt, GeneratorResult, IntoGenerator, ValueResult};
+
+    #[test]
+    fn dropping_back() {
+        let data = [1, 2, 3, 4, 5];
+        let output: Vec<_> = data.into_gen().dropping_back(2).collect();
+        assert_eq!(output, [1, 2, 3]);
+
+        let output: Vec<_> = data.into_gen().dropping_back(0).collect();
+        assert_eq!(output, data);
+
+        let output: Vec<_> = data.into_gen().dropping_back(5).collect();
+        assert!(output.is_empty());
+
+        let output: Vec<_> = data.into_gen().dropping_back(10).collect();
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn huge_amount() {
+        let data = [1, 2, 3];
+        let output: Vec<_> = data.into_gen().dropping_back(usize::MAX).collect();
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn consumer_stop() {
+        let data = [1, 2, 3, 4, 5];
+        let mut gen = data.into_gen().dropping_back(2);
+        let output = collect_one_at_a_time(&mut gen);
+        assert_eq!(output, [1, 2, 3]);
+        assert_eq!(
+            gen.run(|_| ValueResult::MoreValues),
+            GeneratorResult::Complete
+        );
+    }
+
+    #[test]
+    fn spuriously_stopping() {
+        let data = [1, 2, 3, 4, 5];
+        for x in 0..data.len() {
+            let mut gen = StoppingGen::new(x as i32, &data).copied().dropping_back(2);
+            let mut output = Vec::new();
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Stopped);
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Complete);
+            assert_eq!(output, [1, 2, 3]);
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Generator, GeneratorResult, ValueResult};
+use crate::{FusedGenerator, Generator, GeneratorResult, ValueResult};
 use core::num::NonZeroUsize;
 
 /// A generator that yields the current count and the value when run. See [`enumerate()`](crate::GeneratorExt::enumerate) for details.
@@ -9,8 +9,11 @@ pub struct Enumerate<Src> {
 
 impl<Src> Enumerate<Src> {
     #[inline]
-    pub(crate) fn new(source: Src) -> Self {
-        Self { source, index: 0 }
+    pub(crate) fn new(source: Src, start: usize) -> Self {
+        Self {
+            source,
+            index: start,
+        }
     }
 }
 
@@ -38,6 +41,8 @@ where
     }
 }
 
+impl<Src> FusedGenerator for Enumerate<Src> where Src: FusedGenerator {}
+
 #[cfg(test)]
 mod tests {
     use crate::test::StoppingGen;
@@ -55,6 +60,17 @@ mod tests {
         assert_eq!(gen.next(), Err(GeneratorResult::Complete));
     }
 
+    #[test]
+    fn enumerate_from() {
+        let data = ['a', 'b', 'c'];
+
+        let mut gen = SliceGenerator::new(&data).enumerate_from(100);
+        assert_eq!(gen.next(), Ok((100, &'a')));
+        assert_eq!(gen.next(), Ok((101, &'b')));
+        assert_eq!(gen.next(), Ok((102, &'c')));
+        assert_eq!(gen.next(), Err(GeneratorResult::Complete));
+    }
+
     #[test]
     fn advance() {
         let data = ['a', 'b', 'c'];
@@ -1,4 +1,4 @@
-use crate::{Generator, GeneratorResult, ReverseGenerator, ValueResult};
+use crate::{FusedGenerator, Generator, GeneratorResult, ReverseGenerator, ValueResult};
 
 /// Implements a filtered generator. See [`.filter()`](crate::GeneratorExt::filter) for more details.
 #[derive(Clone)]
@@ -59,6 +59,13 @@ where
     }
 }
 
+impl<Gen, Pred> FusedGenerator for Filter<Gen, Pred>
+where
+    Gen: FusedGenerator,
+    Pred: FnMut(&Gen::Output) -> bool,
+{
+}
+
 #[cfg(test)]
 mod tests {
     use crate::test::StoppingGen;
@@ -1,4 +1,4 @@
-use crate::{Generator, GeneratorResult, ReverseGenerator, ValueResult};
+use crate::{FusedGenerator, Generator, GeneratorResult, ReverseGenerator, ValueResult};
 
 /// Implements a mapped generator. See [`.map()`](crate::GeneratorExt::map) for details.
 #[derive(Clone)]
@@ -56,6 +56,13 @@ where
     }
 }
 
+impl<Gen, Func, Out> FusedGenerator for FilterMap<Gen, Func>
+where
+    Gen: FusedGenerator,
+    Func: FnMut(Gen::Output) -> Option<Out>,
+{
+}
+
 #[cfg(test)]
 mod tests {
     use crate::test::StoppingGen;
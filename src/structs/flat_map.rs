@@ -0,0 +1,174 @@
+use crate::{
+    structs::utility::set_some, Generator, GeneratorResult, IntoGenerator, ReverseGenerator,
+    ValueResult,
+};
+
+/// Map each value to a generator and flatten the result. See [`.flat_map()`](crate::GeneratorExt::flat_map) for details.
+pub struct FlatMap<Src, F, U>
+where
+    U: IntoGenerator,
+{
+    source: Src,
+    func: F,
+    current_generator: Option<U::IntoGen>,
+    current_back_generator: Option<U::IntoGen>,
+}
+
+impl<Src, F, U> FlatMap<Src, F, U>
+where
+    Src: Generator,
+    F: FnMut(Src::Output) -> U,
+    U: IntoGenerator,
+{
+    #[inline]
+    pub(crate) fn new(source: Src, func: F) -> Self {
+        Self {
+            source,
+            func,
+            current_generator: None,
+            current_back_generator: None,
+        }
+    }
+}
+
+impl<Src, F, U> Clone for FlatMap<Src, F, U>
+where
+    Src: Clone,
+    F: Clone,
+    U: IntoGenerator,
+    U::IntoGen: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            source: self.source.clone(),
+            func: self.func.clone(),
+            current_generator: self.current_generator.clone(),
+            current_back_generator: self.current_back_generator.clone(),
+        }
+    }
+}
+
+impl<Src, F, U> Generator for FlatMap<Src, F, U>
+where
+    Src: Generator,
+    F: FnMut(Src::Output) -> U,
+    U: IntoGenerator,
+{
+    type Output = U::Output;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        if let Some(current) = self.current_generator.as_mut() {
+            if current.run(&mut output) == GeneratorResult::Stopped {
+                return GeneratorResult::Stopped;
+            }
+        }
+
+        let func = &mut self.func;
+        let current_generator = &mut self.current_generator;
+        let result = self.source.run(|x| {
+            match set_some(current_generator, func(x).into_gen()).run(&mut output) {
+                GeneratorResult::Stopped => ValueResult::Stop,
+                GeneratorResult::Complete => ValueResult::MoreValues,
+            }
+        });
+
+        if result == GeneratorResult::Complete {
+            if let Some(mut last) = self.current_back_generator.take() {
+                return if last.run(output) == GeneratorResult::Stopped {
+                    self.current_back_generator = Some(last);
+                    GeneratorResult::Stopped
+                } else {
+                    GeneratorResult::Complete
+                };
+            }
+        }
+
+        result
+    }
+}
+
+impl<Src, F, U> ReverseGenerator for FlatMap<Src, F, U>
+where
+    Src: ReverseGenerator,
+    F: FnMut(Src::Output) -> U,
+    U: IntoGenerator,
+    U::IntoGen: ReverseGenerator,
+{
+    #[inline]
+    fn run_back(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        if let Some(mut current) = self.current_back_generator.take() {
+            if current.run_back(&mut output) == GeneratorResult::Stopped {
+                self.current_back_generator = Some(current);
+                return GeneratorResult::Stopped;
+            }
+        }
+
+        let func = &mut self.func;
+        let current = &mut self.current_back_generator;
+        let result = self.source.run_back(|x| {
+            match set_some(current, func(x).into_gen()).run_back(&mut output) {
+                GeneratorResult::Stopped => ValueResult::Stop,
+                GeneratorResult::Complete => ValueResult::MoreValues,
+            }
+        });
+
+        if result == GeneratorResult::Complete {
+            if let Some(mut last) = self.current_generator.take() {
+                return if last.run_back(output) == GeneratorResult::Stopped {
+                    self.current_generator = Some(last);
+                    GeneratorResult::Stopped
+                } else {
+                    GeneratorResult::Complete
+                };
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::StoppingGen;
+    use crate::{GeneratorExt, GeneratorResult, SliceGenerator};
+
+    #[test]
+    fn flat_map() {
+        let data = [1, 2, 3];
+        let output: Vec<_> = SliceGenerator::new(&data)
+            .flat_map(|x| crate::repeat_n(*x, *x as usize))
+            .collect();
+        assert_eq!(output, [1, 2, 2, 3, 3, 3]);
+    }
+
+    #[test]
+    fn reverse() {
+        let data = [[1, 2], [3, 4]];
+        let output: Vec<_> = SliceGenerator::new(&data)
+            .flat_map(|x| SliceGenerator::new(x))
+            .rev()
+            .collect();
+        assert_eq!(output, [&4, &3, &2, &1]);
+    }
+
+    #[test]
+    fn stopping_generators() {
+        let data = [[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]];
+        let expected = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+        for outer in 0..3 {
+            for i in 0..4 {
+                let mut gen = StoppingGen::new(outer, &data).flat_map(|x| StoppingGen::new(i, x));
+
+                let mut output = Vec::new();
+                let mut num_stops = 0;
+                while gen.for_each(|x| output.push(*x)) == GeneratorResult::Stopped {
+                    num_stops += 1;
+                }
+                assert_eq!(num_stops, 4);
+                assert_eq!(output, expected);
+            }
+        }
+    }
+}
@@ -123,6 +123,162 @@ where
     }
 }
 
+/// Flatten generator that places a separator between sub-generators. See
+/// [`.flatten_with_sep()`](crate::GeneratorExt::flatten_with_sep) for details.
+pub struct FlattenWithSep<Src>
+where
+    Src: Generator,
+    Src::Output: IntoGenerator,
+{
+    source: Src,
+    separator: <Src::Output as IntoGenerator>::Output,
+    current_generator: Option<<Src::Output as IntoGenerator>::IntoGen>,
+    // A sub-generator has been completed, a separator must be generated before the next one.
+    separator_pending: bool,
+}
+
+impl<Src> FlattenWithSep<Src>
+where
+    Src: Generator,
+    Src::Output: IntoGenerator,
+{
+    #[inline]
+    pub(crate) fn new(source: Src, separator: <Src::Output as IntoGenerator>::Output) -> Self {
+        Self {
+            source,
+            separator,
+            current_generator: None,
+            separator_pending: false,
+        }
+    }
+}
+
+impl<Src> Clone for FlattenWithSep<Src>
+where
+    Src: Generator + Clone,
+    Src::Output: IntoGenerator,
+    <Src::Output as IntoGenerator>::Output: Clone,
+    <Src::Output as IntoGenerator>::IntoGen: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            source: self.source.clone(),
+            separator: self.separator.clone(),
+            current_generator: self.current_generator.clone(),
+            separator_pending: self.separator_pending,
+        }
+    }
+}
+
+impl<Src> Generator for FlattenWithSep<Src>
+where
+    Src: Generator,
+    Src::Output: IntoGenerator,
+    <Src::Output as IntoGenerator>::Output: Clone,
+{
+    type Output = <<Src as Generator>::Output as IntoGenerator>::Output;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        if let Some(mut current) = self.current_generator.take() {
+            if current.run(&mut output) == GeneratorResult::Stopped {
+                self.current_generator = Some(current);
+                return GeneratorResult::Stopped;
+            }
+            self.separator_pending = true;
+        }
+
+        let separator = &self.separator;
+        let current_generator = &mut self.current_generator;
+        let separator_pending = &mut self.separator_pending;
+        self.source.run(|x| {
+            let mut gen = x.into_gen();
+            if *separator_pending && output(separator.clone()) == ValueResult::Stop {
+                *separator_pending = false;
+                *current_generator = Some(gen);
+                return ValueResult::Stop;
+            }
+            *separator_pending = true;
+            match gen.run(&mut output) {
+                GeneratorResult::Stopped => {
+                    *current_generator = Some(gen);
+                    ValueResult::Stop
+                }
+                GeneratorResult::Complete => ValueResult::MoreValues,
+            }
+        })
+    }
+}
+
+/// Flattens the `Ok` values of a generator of `Result`s. See
+/// [`.flatten_ok()`](crate::GeneratorExt::flatten_ok) for details.
+pub struct FlattenOk<Src, T>
+where
+    T: IntoGenerator,
+{
+    source: Src,
+    current: Option<T::IntoGen>,
+}
+
+impl<Src, T> FlattenOk<Src, T>
+where
+    T: IntoGenerator,
+{
+    #[inline]
+    pub(crate) fn new(source: Src) -> Self {
+        Self {
+            source,
+            current: None,
+        }
+    }
+}
+
+impl<Src, T> Clone for FlattenOk<Src, T>
+where
+    Src: Clone,
+    T: IntoGenerator,
+    T::IntoGen: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            source: self.source.clone(),
+            current: self.current.clone(),
+        }
+    }
+}
+
+impl<Src, T, E> Generator for FlattenOk<Src, T>
+where
+    Src: Generator<Output = Result<T, E>>,
+    T: IntoGenerator,
+{
+    type Output = Result<T::Output, E>;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        if let Some(current) = self.current.as_mut() {
+            if current.run(|x| output(Ok(x))) == GeneratorResult::Stopped {
+                return GeneratorResult::Stopped;
+            }
+            self.current = None;
+        }
+
+        let current = &mut self.current;
+        self.source.run(|value| match value {
+            Ok(inner) => match set_some(current, inner.into_gen()).run(|x| output(Ok(x))) {
+                GeneratorResult::Stopped => ValueResult::Stop,
+                GeneratorResult::Complete => {
+                    *current = None;
+                    ValueResult::MoreValues
+                }
+            },
+            Err(err) => output(Err(err)),
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -214,6 +370,49 @@ mod tests {
         }
     }
 
+    #[test]
+    fn flatten_with_sep() {
+        let data = vec![vec![1, 2], vec![3], vec![4, 5]];
+        let output: Vec<_> = SliceGenerator::new(&data).flatten_with_sep(&0).collect();
+        assert_eq!(output, [&1, &2, &0, &3, &0, &4, &5]);
+
+        let data = vec![vec![1, 2]];
+        let output: Vec<_> = SliceGenerator::new(&data).flatten_with_sep(&0).collect();
+        assert_eq!(output, [&1, &2]);
+    }
+
+    #[test]
+    fn flatten_with_sep_stop_at_separator() {
+        let data = [[1, 2], [3, 4]];
+        let mut gen = SliceGenerator::new(&data).flatten_with_sep(&0);
+        let mut output = Vec::new();
+        let result = gen.run(|x| {
+            output.push(*x);
+            (*x != 0).into()
+        });
+        assert_eq!(result, GeneratorResult::Stopped);
+        assert_eq!(output, [1, 2, 0]);
+        assert_eq!(gen.for_each(|x| output.push(*x)), GeneratorResult::Complete);
+        assert_eq!(output, [1, 2, 0, 3, 4]);
+    }
+
+    #[test]
+    fn flatten_with_sep_stopping() {
+        let data = [[1, 2], [3, 4], [5, 6]];
+        let expected = [1, 2, 0, 3, 4, 0, 5, 6];
+        for outer in 0..3 {
+            for i in 0..2 {
+                let mut gen = StoppingGen::new(outer, &data)
+                    .map(|x| StoppingGen::new(i, x))
+                    .flatten_with_sep(&0);
+
+                let mut output = Vec::new();
+                while gen.for_each(|x| output.push(*x)) == GeneratorResult::Stopped {}
+                assert_eq!(output, expected);
+            }
+        }
+    }
+
     #[test]
     fn reverse() {
         let data = [[1, 2], [3, 4], [5, 6]];
@@ -310,4 +509,26 @@ mod tests {
         assert_eq!(gen.next(), Err(GeneratorResult::Complete));
         assert_eq!(gen.next_back(), Err(GeneratorResult::Complete));
     }
+
+    #[test]
+    fn flatten_ok() {
+        let data: Vec<Result<Vec<i32>, &str>> =
+            vec![Ok(vec![1, 2]), Err("bad"), Ok(vec![]), Ok(vec![3])];
+        let output: Vec<_> = data.into_gen().flatten_ok().collect();
+        assert_eq!(output, [Ok(1), Ok(2), Err("bad"), Ok(3)]);
+    }
+
+    #[test]
+    fn flatten_ok_spuriously_stopping() {
+        let inner = [1, 2, 3];
+        let data: [Result<_, i32>; 3] = [
+            Ok(StoppingGen::new(1, &inner)),
+            Err(0),
+            Ok(StoppingGen::new(0, &inner)),
+        ];
+        let mut gen = data.into_gen().flatten_ok();
+        let mut output = Vec::new();
+        while gen.for_each(|x| output.push(x.copied())) == GeneratorResult::Stopped {}
+        assert_eq!(output, [Ok(1), Ok(2), Ok(3), Err(0), Ok(1), Ok(2), Ok(3)]);
+    }
 }
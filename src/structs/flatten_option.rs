@@ -0,0 +1,82 @@
+use crate::{FusedGenerator, Generator, GeneratorResult, ReverseGenerator, ValueResult};
+
+/// A generator of the values inside `Some`, skipping `None` values. See
+/// [`.flatten_option()`](crate::GeneratorExt::flatten_option) for details.
+#[derive(Clone)]
+pub struct FlattenOption<Src> {
+    source: Src,
+}
+
+impl<Src> FlattenOption<Src> {
+    #[inline]
+    pub(crate) fn new(source: Src) -> Self {
+        Self { source }
+    }
+}
+
+impl<Src, T> Generator for FlattenOption<Src>
+where
+    Src: Generator<Output = Option<T>>,
+{
+    type Output = T;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        self.source.run(|x| match x {
+            Some(value) => output(value),
+            None => ValueResult::MoreValues,
+        })
+    }
+}
+
+impl<Src, T> ReverseGenerator for FlattenOption<Src>
+where
+    Src: ReverseGenerator<Output = Option<T>>,
+{
+    #[inline]
+    fn run_back(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        self.source.run_back(|x| match x {
+            Some(value) => output(value),
+            None => ValueResult::MoreValues,
+        })
+    }
+}
+
+impl<Src, T> FusedGenerator for FlattenOption<Src> where Src: FusedGenerator<Output = Option<T>> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::StoppingGen;
+    use crate::{GeneratorExt, GeneratorResult, IntoGenerator};
+
+    #[test]
+    fn flatten_option() {
+        let data = [Some(1), None, Some(2), None, None, Some(3)];
+        let output: Vec<_> = data.into_gen().flatten_option().collect();
+        assert_eq!(output, [1, 2, 3]);
+
+        let output: Vec<i32> = [None, None].into_gen().flatten_option().collect();
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn spuriously_stopping() {
+        let data = [Some(1), None, Some(2), Some(3)];
+        for x in 0..data.len() {
+            let mut gen = StoppingGen::new(x as i32, &data).copied().flatten_option();
+            let mut output = Vec::new();
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Stopped);
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Complete);
+            assert_eq!(output, [1, 2, 3]);
+        }
+    }
+
+    #[test]
+    fn reverse() {
+        let data = [Some(1), None, Some(2), None];
+        let mut gen = data.into_gen().flatten_option();
+        assert_eq!(gen.next_back(), Ok(2));
+        assert_eq!(gen.next_back(), Ok(1));
+        assert_eq!(gen.next_back(), Err(GeneratorResult::Complete));
+    }
+}
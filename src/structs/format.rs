@@ -0,0 +1,94 @@
+use crate::{Generator, ValueResult};
+use core::cell::Cell;
+use core::fmt;
+
+/// Formats the values of a generator lazily. See
+/// [`.format_with()`](crate::GeneratorExt::format_with) for details.
+pub struct FormatWith<'a, Src, F> {
+    sep: &'a str,
+    // Formatting consumes the generator, so it can only be done once.
+    inner: Cell<Option<(Src, F)>>,
+}
+
+impl<'a, Src, F> FormatWith<'a, Src, F> {
+    #[inline]
+    pub(crate) fn new(source: Src, sep: &'a str, format: F) -> Self {
+        Self {
+            sep,
+            inner: Cell::new(Some((source, format))),
+        }
+    }
+}
+
+impl<'a, Src, F> fmt::Display for FormatWith<'a, Src, F>
+where
+    Src: Generator,
+    F: FnMut(Src::Output, &mut dyn FnMut(&dyn fmt::Display) -> fmt::Result) -> fmt::Result,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (mut source, mut format) = match self.inner.take() {
+            Some(inner) => inner,
+            None => panic!("FormatWith: was already formatted once"),
+        };
+
+        let sep = self.sep;
+        let mut first = true;
+        let mut result = Ok(());
+        source.run(|x| {
+            if !first {
+                result = f.write_str(sep);
+            }
+            first = false;
+            if result.is_ok() {
+                result = format(x, &mut |value: &dyn fmt::Display| value.fmt(f));
+            }
+            if result.is_ok() {
+                ValueResult::MoreValues
+            } else {
+                ValueResult::Stop
+            }
+        });
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::StoppingGen;
+    use crate::{GeneratorExt, IntoGenerator};
+
+    #[test]
+    fn format_with() {
+        let data = [1, 2, 3];
+        let s = format!(
+            "[{}]",
+            data.into_gen()
+                .format_with(", ", |x, f| f(&format_args!("<{}>", x)))
+        );
+        assert_eq!(s, "[<1>, <2>, <3>]");
+
+        let empty: [i32; 0] = [];
+        assert_eq!(
+            empty.into_gen().format_with(", ", |x, f| f(&x)).to_string(),
+            ""
+        );
+    }
+
+    #[test]
+    fn stopped_generator() {
+        let data = [1, 2, 3];
+        let formatted = StoppingGen::new(2, &data)
+            .format_with("-", |x, f| f(x))
+            .to_string();
+        assert_eq!(formatted, "1-2");
+    }
+
+    #[test]
+    #[should_panic]
+    fn format_twice() {
+        let data = [1, 2, 3];
+        let formatted = data.into_gen().format_with(", ", |x, f| f(&x));
+        let _ = formatted.to_string();
+        let _ = formatted.to_string();
+    }
+}
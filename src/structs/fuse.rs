@@ -0,0 +1,116 @@
+use crate::{FusedGenerator, Generator, GeneratorResult, ReverseGenerator, ValueResult};
+use core::num::NonZeroUsize;
+
+/// A generator that never generates values after it has completed. See [`.fuse()`](crate::GeneratorExt::fuse) for details.
+#[derive(Clone)]
+pub struct Fuse<Src> {
+    source: Src,
+    is_complete: bool,
+}
+
+impl<Src> Fuse<Src> {
+    #[inline]
+    pub(crate) fn new(source: Src) -> Self {
+        Self {
+            source,
+            is_complete: false,
+        }
+    }
+
+    #[inline]
+    fn fuse_result(&mut self, result: GeneratorResult) -> GeneratorResult {
+        if result == GeneratorResult::Complete {
+            self.is_complete = true;
+        }
+        result
+    }
+}
+
+impl<Src: Generator> Generator for Fuse<Src> {
+    type Output = Src::Output;
+
+    #[inline]
+    fn run(&mut self, output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        if self.is_complete {
+            return GeneratorResult::Complete;
+        }
+        let result = self.source.run(output);
+        self.fuse_result(result)
+    }
+
+    #[inline]
+    fn try_advance(&mut self, n: NonZeroUsize) -> (usize, GeneratorResult) {
+        if self.is_complete {
+            return (0, GeneratorResult::Complete);
+        }
+        let (advanced, result) = self.source.try_advance(n);
+        (advanced, self.fuse_result(result))
+    }
+}
+
+impl<Src: ReverseGenerator> ReverseGenerator for Fuse<Src> {
+    #[inline]
+    fn run_back(&mut self, output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        if self.is_complete {
+            return GeneratorResult::Complete;
+        }
+        let result = self.source.run_back(output);
+        self.fuse_result(result)
+    }
+
+    #[inline]
+    fn try_advance_back(&mut self, n: NonZeroUsize) -> (usize, GeneratorResult) {
+        if self.is_complete {
+            return (0, GeneratorResult::Complete);
+        }
+        let (advanced, result) = self.source.try_advance_back(n);
+        (advanced, self.fuse_result(result))
+    }
+}
+
+impl<Src: Generator> FusedGenerator for Fuse<Src> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Generator, GeneratorExt, GeneratorResult, ValueResult};
+
+    // Generates a single value every time it is run, and completes after each value.
+    struct Restarting(i32);
+
+    impl Generator for Restarting {
+        type Output = i32;
+
+        fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+            self.0 += 1;
+            if output(self.0) == ValueResult::Stop {
+                return GeneratorResult::Stopped;
+            }
+            GeneratorResult::Complete
+        }
+    }
+
+    #[test]
+    fn fuse() {
+        let mut gen = Restarting(0);
+        assert_eq!(gen.next(), Ok(1));
+        assert_eq!(gen.for_each(|_| ()), GeneratorResult::Complete);
+        assert_eq!(gen.next(), Ok(3));
+
+        let mut gen = Restarting(0).fuse();
+        assert_eq!(gen.next(), Ok(1));
+        assert_eq!(gen.for_each(|_| ()), GeneratorResult::Complete);
+        assert_eq!(gen.next(), Err(GeneratorResult::Complete));
+        assert_eq!(gen.next(), Err(GeneratorResult::Complete));
+    }
+
+    #[test]
+    fn stopped_is_not_complete() {
+        let data = [Some(1), None, Some(2)];
+        let mut gen = crate::test::MultiStoppingGen::new(&data).fuse();
+        let mut output = Vec::new();
+        assert_eq!(gen.for_each(|x| output.push(*x)), GeneratorResult::Stopped);
+        assert_eq!(gen.for_each(|x| output.push(*x)), GeneratorResult::Complete);
+        assert_eq!(gen.for_each(|x| output.push(*x)), GeneratorResult::Complete);
+        assert_eq!(output, [1, 2]);
+    }
+}
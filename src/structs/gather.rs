@@ -0,0 +1,98 @@
+use crate::{FusedGenerator, Generator, GeneratorResult, ReverseGenerator, ValueResult};
+use core::num::NonZeroUsize;
+
+/// Looks up each generated index in a slice. See [`.gather()`](crate::GeneratorExt::gather) for
+/// details.
+pub struct Gather<'a, Src, T> {
+    indices: Src,
+    source: &'a [T],
+}
+
+impl<'a, Src, T> Gather<'a, Src, T> {
+    #[inline]
+    pub(crate) fn new(indices: Src, source: &'a [T]) -> Self {
+        Self { indices, source }
+    }
+}
+
+impl<'a, Src: Clone, T> Clone for Gather<'a, Src, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(self.indices.clone(), self.source)
+    }
+}
+
+impl<'a, Src, T> Generator for Gather<'a, Src, T>
+where
+    Src: Generator<Output = usize>,
+{
+    type Output = &'a T;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        let source = self.source;
+        self.indices.run(move |index| output(&source[index]))
+    }
+
+    #[inline]
+    fn try_advance(&mut self, n: NonZeroUsize) -> (usize, GeneratorResult) {
+        self.indices.try_advance(n)
+    }
+}
+
+impl<'a, Src, T> ReverseGenerator for Gather<'a, Src, T>
+where
+    Src: ReverseGenerator<Output = usize>,
+{
+    #[inline]
+    fn run_back(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        let source = self.source;
+        self.indices.run_back(move |index| output(&source[index]))
+    }
+
+    #[inline]
+    fn try_advance_back(&mut self, n: NonZeroUsize) -> (usize, GeneratorResult) {
+        self.indices.try_advance_back(n)
+    }
+}
+
+impl<'a, Src, T> FusedGenerator for Gather<'a, Src, T> where Src: FusedGenerator<Output = usize> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::StoppingGen;
+    use crate::{GeneratorExt, GeneratorResult, IntoGenerator};
+
+    #[test]
+    fn gather() {
+        let data = ['a', 'b', 'c', 'd'];
+        let output: Vec<_> = [3, 0, 0, 2].into_gen().gather(&data).collect();
+        assert_eq!(output, [&'d', &'a', &'a', &'c']);
+
+        let mut gen = [1, 2, 3].into_gen().gather(&data);
+        assert_eq!(gen.next_back(), Ok(&'d'));
+        assert_eq!(gen.next(), Ok(&'b'));
+        assert_eq!(gen.next(), Ok(&'c'));
+        assert_eq!(gen.next(), Err(GeneratorResult::Complete));
+    }
+
+    #[test]
+    #[should_panic]
+    fn out_of_range() {
+        let data = [1, 2];
+        let _: Vec<_> = [0, 2].into_gen().gather(&data).collect();
+    }
+
+    #[test]
+    fn spuriously_stopping() {
+        let data = ['a', 'b', 'c'];
+        let indices = [2, 1, 0];
+        for x in 0..indices.len() {
+            let mut gen = StoppingGen::new(x as i32, &indices).copied().gather(&data);
+            let mut output = Vec::new();
+            assert_eq!(gen.for_each(|x| output.push(*x)), GeneratorResult::Stopped);
+            assert_eq!(gen.for_each(|x| output.push(*x)), GeneratorResult::Complete);
+            assert_eq!(output, ['c', 'b', 'a']);
+        }
+    }
+}
@@ -0,0 +1,265 @@
+use crate::{Generator, GeneratorExt, GeneratorResult, ValueResult};
+
+/// Groups consecutive values with equal keys, handing each group to a closure as a generator.
+/// See [`.group_by()`](crate::GeneratorExt::group_by) for details.
+#[derive(Clone)]
+pub struct GroupBy<Src, F, K>
+where
+    Src: Generator,
+{
+    source: Src,
+    key_fn: F,
+    // Key of the group that is currently being generated.
+    current_key: Option<K>,
+    // First value of the current group, if it hasn't been generated yet.
+    head: Option<Src::Output>,
+    // First value of the next group, found while generating the current group.
+    next: Option<(K, Src::Output)>,
+    // Set when the current group was stopped by the source rather than by the consumer.
+    interrupted: bool,
+    complete: bool,
+}
+
+impl<Src, F, K> GroupBy<Src, F, K>
+where
+    Src: Generator,
+    F: FnMut(&Src::Output) -> K,
+    K: PartialEq + Clone,
+{
+    #[inline]
+    pub(crate) fn new(source: Src, key_fn: F) -> Self {
+        Self {
+            source,
+            key_fn,
+            current_key: None,
+            head: None,
+            next: None,
+            interrupted: false,
+            complete: false,
+        }
+    }
+
+    /// Runs the groups, calling `f` with the key and a [`Group`] generator for each group.
+    ///
+    /// Values of a group that `f` doesn't generate are skipped. Returns
+    /// [`GeneratorResult::Stopped`] if `f` returns [`ValueResult::Stop`] or the source generator
+    /// stopped, and [`GeneratorResult::Complete`] once all groups have been handed to `f`.
+    #[inline]
+    pub fn run_groups(
+        &mut self,
+        mut f: impl FnMut(K, Group<'_, Src, F, K>) -> ValueResult,
+    ) -> GeneratorResult {
+        loop {
+            let key = match &self.current_key {
+                // The source stopped in the middle of the group, hand the rest of it to `f`. Any
+                // values `f` doesn't generate are skipped like for any other group.
+                Some(key) if self.interrupted => {
+                    self.interrupted = false;
+                    key.clone()
+                }
+                Some(_) => {
+                    let result = Group { parent: self }.run(|_| ValueResult::MoreValues);
+                    if result == GeneratorResult::Stopped {
+                        // Keep skipping the group on the next run.
+                        self.interrupted = false;
+                        return result;
+                    }
+                    continue;
+                }
+                None => {
+                    let (key, value) = match self.next.take() {
+                        Some(next) => next,
+                        None if self.complete => return GeneratorResult::Complete,
+                        None => match self.source.next() {
+                            Ok(value) => ((self.key_fn)(&value), value),
+                            Err(result) => {
+                                self.complete = result == GeneratorResult::Complete;
+                                return result;
+                            }
+                        },
+                    };
+                    self.current_key = Some(key.clone());
+                    self.head = Some(value);
+                    key
+                }
+            };
+
+            if f(key, Group { parent: self }) == ValueResult::Stop || self.interrupted {
+                return GeneratorResult::Stopped;
+            }
+        }
+    }
+
+    /// Calls `f` with the key and a [`Group`] generator for each group.
+    ///
+    /// This is [`run_groups()`](GroupBy::run_groups) for closures that never stop.
+    #[inline]
+    pub fn for_each_group(
+        &mut self,
+        mut f: impl FnMut(K, Group<'_, Src, F, K>),
+    ) -> GeneratorResult {
+        self.run_groups(|key, group| {
+            f(key, group);
+            ValueResult::MoreValues
+        })
+    }
+}
+
+/// A generator of the values in one group of a [`GroupBy`].
+///
+/// Completes when a value with a different key is found or the source generator completes.
+pub struct Group<'a, Src, F, K>
+where
+    Src: Generator,
+{
+    parent: &'a mut GroupBy<Src, F, K>,
+}
+
+impl<'a, Src, F, K> Generator for Group<'a, Src, F, K>
+where
+    Src: Generator,
+    F: FnMut(&Src::Output) -> K,
+    K: PartialEq,
+{
+    type Output = Src::Output;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        let GroupBy {
+            source,
+            key_fn,
+            current_key,
+            head,
+            next,
+            interrupted,
+            complete,
+        } = &mut *self.parent;
+
+        let key = match current_key.as_ref() {
+            Some(key) => key,
+            None => return GeneratorResult::Complete,
+        };
+
+        *interrupted = false;
+        if let Some(value) = head.take() {
+            if output(value) == ValueResult::Stop {
+                return GeneratorResult::Stopped;
+            }
+        }
+
+        let mut consumer_stopped = false;
+        let result = source.run(|x| {
+            let next_key = key_fn(&x);
+            if next_key == *key {
+                let result = output(x);
+                consumer_stopped = result == ValueResult::Stop;
+                result
+            } else {
+                *next = Some((next_key, x));
+                ValueResult::Stop
+            }
+        });
+
+        if result == GeneratorResult::Complete || next.is_some() {
+            *complete = result == GeneratorResult::Complete;
+            *current_key = None;
+            GeneratorResult::Complete
+        } else {
+            *interrupted = !consumer_stopped;
+            GeneratorResult::Stopped
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::StoppingGen;
+    use crate::{GeneratorExt, GeneratorResult, IntoGenerator, ValueResult};
+
+    #[test]
+    fn group_by() {
+        let data = [1, 3, 2, 4, 6, 5, 8];
+        let mut output = Vec::new();
+        let result = data
+            .into_gen()
+            .group_by(|x| x % 2)
+            .for_each_group(|key, group| output.push((key, group.sum::<i32>())));
+        assert_eq!(result, GeneratorResult::Complete);
+        assert_eq!(output, [(1, 4), (0, 12), (1, 5), (0, 8)]);
+
+        let empty: [i32; 0] = [];
+        let mut count = 0;
+        empty
+            .into_gen()
+            .group_by(|x| *x)
+            .for_each_group(|_, _| count += 1);
+        assert_eq!(count, 0);
+    }
+
+    #[test]
+    fn ignored_group_after_stop() {
+        let data = [1, 1, 1, 2, 2, 3];
+        let mut gen = StoppingGen::new(2, &data).group_by(|x| **x);
+        let mut counts = Vec::new();
+        let result = gen.run_groups(|key, group| {
+            counts.push((key, group.count()));
+            ValueResult::MoreValues
+        });
+        assert_eq!(result, GeneratorResult::Stopped);
+        assert_eq!(counts, [(1, 2)]);
+
+        let mut keys = Vec::new();
+        assert_eq!(
+            gen.for_each_group(|key, _| keys.push(key)),
+            GeneratorResult::Complete
+        );
+        assert_eq!(keys, [1, 2, 3]);
+    }
+
+    #[test]
+    fn partially_consumed_groups() {
+        let data = [1, 1, 1, 2, 2, 3];
+        let mut output = Vec::new();
+        data.into_gen()
+            .group_by(|x| *x)
+            .for_each_group(|key, mut group| {
+                if key != 2 {
+                    output.push(group.next().unwrap());
+                }
+            });
+        assert_eq!(output, [1, 3]);
+    }
+
+    #[test]
+    fn consumer_stop() {
+        let data = [1, 1, 2, 3, 3];
+        let mut gen = data.into_gen().group_by(|x| *x);
+        let mut output = Vec::new();
+        while gen.run_groups(|key, group| {
+            output.push((key, group.count()));
+            ValueResult::Stop
+        }) == GeneratorResult::Stopped
+        {}
+        assert_eq!(output, [(1, 2), (2, 1), (3, 2)]);
+    }
+
+    #[test]
+    fn spuriously_stopping() {
+        let data = [1, 1, 2, 3, 3, 3];
+        for x in 0..6 {
+            let mut gen = StoppingGen::new(x, &data).group_by(|x| **x);
+            let mut output: Vec<(i32, Vec<i32>)> = Vec::new();
+            let mut push = |key, mut group: super::Group<'_, _, _, _>| {
+                match output.last_mut() {
+                    Some((last_key, values)) if *last_key == key => {
+                        group.for_each(|x: &i32| values.push(*x));
+                    }
+                    _ => output.push((key, group.copied().collect())),
+                };
+            };
+            assert_eq!(gen.for_each_group(&mut push), GeneratorResult::Stopped);
+            assert_eq!(gen.for_each_group(&mut push), GeneratorResult::Complete);
+            assert_eq!(output, [(1, vec![1, 1]), (2, vec![2]), (3, vec![3, 3, 3])]);
+        }
+    }
+}
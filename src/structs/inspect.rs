@@ -1,7 +1,8 @@
-use crate::{Generator, GeneratorResult, ReverseGenerator, ValueResult};
+use crate::{FusedGenerator, Generator, GeneratorResult, ReverseGenerator, ValueResult};
 use core::num::NonZeroUsize;
 
 /// Inspect each value and then pass it on. See [`inspect()`](crate::GeneratorExt::inspect) for details.
+#[derive(Clone)]
 pub struct Inspect<Src, F> {
     source: Src,
     inspector: F,
@@ -55,9 +56,17 @@ where
     }
 }
 
+impl<Src, F> FusedGenerator for Inspect<Src, F>
+where
+    Src: FusedGenerator,
+    F: FnMut(&Src::Output),
+{
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{GeneratorExt, ReverseGenerator, SliceGenerator, ValueResult};
+    use crate::test::StoppingGen;
+    use crate::{GeneratorExt, GeneratorResult, ReverseGenerator, SliceGenerator, ValueResult};
     use std::num::NonZeroUsize;
 
     #[test]
@@ -78,6 +87,20 @@ mod tests {
         assert_eq!(after_filter, [4, 2]);
     }
 
+    #[test]
+    fn spuriously_stopping() {
+        let a = [1, 2, 3];
+        for x in 0..3 {
+            let mut inspected = Vec::new();
+            let mut output = Vec::new();
+            let mut gen = StoppingGen::new(x, &a).inspect(|x| inspected.push(**x));
+            assert_eq!(gen.for_each(|x| output.push(*x)), GeneratorResult::Stopped);
+            assert_eq!(gen.for_each(|x| output.push(*x)), GeneratorResult::Complete);
+            assert_eq!(output, [1, 2, 3]);
+            assert_eq!(inspected, [1, 2, 3]);
+        }
+    }
+
     #[test]
     fn reverse() {
         let a = [1, 4, 2, 3];
@@ -0,0 +1,214 @@
+use crate::{Generator, GeneratorExt, GeneratorResult, ValueResult};
+
+/// Alternates values from two generators. See [`.interleave()`](crate::GeneratorExt::interleave) for details.
+#[derive(Clone)]
+pub struct Interleave<Left, Right> {
+    left: Left,
+    right: Right,
+    left_next: bool,
+    left_complete: bool,
+    right_complete: bool,
+}
+
+impl<Left, Right> Interleave<Left, Right>
+where
+    Left: Generator,
+    Right: Generator<Output = Left::Output>,
+{
+    #[inline]
+    pub(crate) fn new(left: Left, right: Right) -> Self {
+        Self {
+            left,
+            right,
+            left_next: true,
+            left_complete: false,
+            right_complete: false,
+        }
+    }
+}
+
+impl<Left, Right> Generator for Interleave<Left, Right>
+where
+    Left: Generator,
+    Right: Generator<Output = Left::Output>,
+{
+    type Output = Left::Output;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        loop {
+            match (self.left_complete, self.right_complete) {
+                (true, true) => return GeneratorResult::Complete,
+                (true, false) => {
+                    let result = self.right.run(output);
+                    self.right_complete = result == GeneratorResult::Complete;
+                    return result;
+                }
+                (false, true) => {
+                    let result = self.left.run(output);
+                    self.left_complete = result == GeneratorResult::Complete;
+                    return result;
+                }
+                (false, false) => {}
+            }
+
+            let value = if self.left_next {
+                self.left.next()
+            } else {
+                self.right.next()
+            };
+            match value {
+                Ok(x) => {
+                    self.left_next = !self.left_next;
+                    if output(x) == ValueResult::Stop {
+                        return GeneratorResult::Stopped;
+                    }
+                }
+                Err(GeneratorResult::Stopped) => return GeneratorResult::Stopped,
+                Err(GeneratorResult::Complete) => {
+                    if self.left_next {
+                        self.left_complete = true;
+                    } else {
+                        self.right_complete = true;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Alternates values from two generators until either completes. See
+/// [`.interleave_shortest()`](crate::GeneratorExt::interleave_shortest) for details.
+#[derive(Clone)]
+pub struct InterleaveShortest<Left, Right> {
+    left: Left,
+    right: Right,
+    left_next: bool,
+    is_complete: bool,
+}
+
+impl<Left, Right> InterleaveShortest<Left, Right>
+where
+    Left: Generator,
+    Right: Generator<Output = Left::Output>,
+{
+    #[inline]
+    pub(crate) fn new(left: Left, right: Right) -> Self {
+        Self {
+            left,
+            right,
+            left_next: true,
+            is_complete: false,
+        }
+    }
+}
+
+impl<Left, Right> Generator for InterleaveShortest<Left, Right>
+where
+    Left: Generator,
+    Right: Generator<Output = Left::Output>,
+{
+    type Output = Left::Output;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        if self.is_complete {
+            return GeneratorResult::Complete;
+        }
+
+        loop {
+            let value = if self.left_next {
+                self.left.next()
+            } else {
+                self.right.next()
+            };
+            match value {
+                Ok(x) => {
+                    self.left_next = !self.left_next;
+                    if output(x) == ValueResult::Stop {
+                        return GeneratorResult::Stopped;
+                    }
+                }
+                Err(result) => {
+                    self.is_complete = result == GeneratorResult::Complete;
+                    return result;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::StoppingGen;
+    use crate::{GeneratorExt, GeneratorResult, IntoGenerator};
+
+    #[test]
+    fn interleave() {
+        let output: Vec<_> = [1, 3, 5]
+            .into_gen()
+            .interleave([2, 4, 6].into_gen())
+            .collect();
+        assert_eq!(output, [1, 2, 3, 4, 5, 6]);
+
+        let output: Vec<_> = [1, 3, 5, 7, 8]
+            .into_gen()
+            .interleave([2, 4].into_gen())
+            .collect();
+        assert_eq!(output, [1, 2, 3, 4, 5, 7, 8]);
+
+        let output: Vec<_> = [1].into_gen().interleave([2, 3, 4].into_gen()).collect();
+        assert_eq!(output, [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn interleave_shortest() {
+        let output: Vec<_> = [1, 3, 5]
+            .into_gen()
+            .interleave_shortest([2, 4, 6].into_gen())
+            .collect();
+        assert_eq!(output, [1, 2, 3, 4, 5, 6]);
+
+        let output: Vec<_> = [1, 3, 5, 7]
+            .into_gen()
+            .interleave_shortest([2, 4].into_gen())
+            .collect();
+        assert_eq!(output, [1, 2, 3, 4, 5]);
+
+        let output: Vec<_> = [1, 3]
+            .into_gen()
+            .interleave_shortest([2, 4, 6].into_gen())
+            .collect();
+        assert_eq!(output, [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn interleave_shortest_spuriously_stopping() {
+        let left = [1, 3, 5, 7];
+        let right = [2, 4];
+        for x in 0..4 {
+            for y in 0..2 {
+                let mut gen =
+                    StoppingGen::new(x, &left).interleave_shortest(StoppingGen::new(y, &right));
+                let mut output = Vec::new();
+                while gen.for_each(|x| output.push(*x)) == GeneratorResult::Stopped {}
+                assert_eq!(output, [1, 2, 3, 4, 5]);
+                assert_eq!(gen.next(), Err(GeneratorResult::Complete));
+            }
+        }
+    }
+
+    #[test]
+    fn spuriously_stopping() {
+        let left = [1, 3, 5, 7];
+        let right = [2, 4];
+        for x in 0..4 {
+            for y in 0..2 {
+                let mut gen = StoppingGen::new(x, &left).interleave(StoppingGen::new(y, &right));
+                let mut output = Vec::new();
+                while gen.for_each(|x| output.push(*x)) == GeneratorResult::Stopped {}
+                assert_eq!(output, [1, 2, 3, 4, 5, 7]);
+            }
+        }
+    }
+}
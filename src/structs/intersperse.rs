@@ -0,0 +1,239 @@
+use crate::{Generator, GeneratorResult, ValueResult};
+
+/// Places a separator between adjacent values. See [`.intersperse()`](crate::GeneratorExt::intersperse) for details.
+#[derive(Clone)]
+pub struct Intersperse<Src>
+where
+    Src: Generator,
+{
+    source: Src,
+    separator: Src::Output,
+    // A value whose preceding separator has already been generated.
+    pending: Option<Src::Output>,
+    started: bool,
+}
+
+impl<Src> Intersperse<Src>
+where
+    Src: Generator,
+    Src::Output: Clone,
+{
+    #[inline]
+    pub(crate) fn new(source: Src, separator: Src::Output) -> Self {
+        Self {
+            source,
+            separator,
+            pending: None,
+            started: false,
+        }
+    }
+}
+
+impl<Src> Generator for Intersperse<Src>
+where
+    Src: Generator,
+    Src::Output: Clone,
+{
+    type Output = Src::Output;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        if let Some(value) = self.pending.take() {
+            if output(value) == ValueResult::Stop {
+                return GeneratorResult::Stopped;
+            }
+        }
+
+        let separator = &self.separator;
+        let pending = &mut self.pending;
+        let started = &mut self.started;
+        self.source.run(|x| {
+            // A separator is only generated once the value following it is available, this
+            // makes sure that no trailing separator is ever generated.
+            if *started {
+                if output(separator.clone()) == ValueResult::Stop {
+                    *pending = Some(x);
+                    return ValueResult::Stop;
+                }
+            } else {
+                *started = true;
+            }
+            output(x)
+        })
+    }
+}
+
+/// Places a separator produced by a closure between adjacent values. See
+/// [`.intersperse_with()`](crate::GeneratorExt::intersperse_with) for details.
+#[derive(Clone)]
+pub struct IntersperseWith<Src, F>
+where
+    Src: Generator,
+{
+    source: Src,
+    separator: F,
+    // A value whose preceding separator has already been generated.
+    pending: Option<Src::Output>,
+    started: bool,
+}
+
+impl<Src, F> IntersperseWith<Src, F>
+where
+    Src: Generator,
+    F: FnMut() -> Src::Output,
+{
+    #[inline]
+    pub(crate) fn new(source: Src, separator: F) -> Self {
+        Self {
+            source,
+            separator,
+            pending: None,
+            started: false,
+        }
+    }
+}
+
+impl<Src, F> Generator for IntersperseWith<Src, F>
+where
+    Src: Generator,
+    F: FnMut() -> Src::Output,
+{
+    type Output = Src::Output;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        if let Some(value) = self.pending.take() {
+            if output(value) == ValueResult::Stop {
+                return GeneratorResult::Stopped;
+            }
+        }
+
+        let separator = &mut self.separator;
+        let pending = &mut self.pending;
+        let started = &mut self.started;
+        self.source.run(|x| {
+            if *started {
+                if output(separator()) == ValueResult::Stop {
+                    *pending = Some(x);
+                    return ValueResult::Stop;
+                }
+            } else {
+                *started = true;
+            }
+            output(x)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::{MultiStoppingGen, StoppingGen};
+    use crate::{Generator, GeneratorExt, GeneratorResult, IntoGenerator, ValueResult};
+
+    #[test]
+    fn intersperse() {
+        let data = [1, 2, 3];
+        let output: Vec<_> = data.into_gen().intersperse(0).collect();
+        assert_eq!(output, [1, 0, 2, 0, 3]);
+
+        let output: Vec<_> = [1].into_gen().intersperse(0).collect();
+        assert_eq!(output, [1]);
+
+        let empty: [i32; 0] = [];
+        let output: Vec<_> = empty.into_gen().intersperse(0).collect();
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn no_trailing_separator_on_stop() {
+        let data = [Some(1), Some(2), None, None, Some(3)];
+        let mut gen = MultiStoppingGen::new(&data).copied().intersperse(0);
+        let mut output = Vec::new();
+        assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Stopped);
+        assert_eq!(output, [1, 0, 2]);
+        assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Stopped);
+        assert_eq!(output, [1, 0, 2]);
+        assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Complete);
+        assert_eq!(output, [1, 0, 2, 0, 3]);
+    }
+
+    #[test]
+    fn no_leading_separator_on_stop() {
+        let data = [None, Some(1), None, Some(2)];
+        let mut gen = MultiStoppingGen::new(&data).copied().intersperse(0);
+        let mut output = Vec::new();
+        assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Stopped);
+        assert!(output.is_empty());
+        assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Stopped);
+        assert_eq!(output, [1]);
+        assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Complete);
+        assert_eq!(output, [1, 0, 2]);
+    }
+
+    #[test]
+    fn stop_after_separator() {
+        let data = [1, 2, 3];
+        let mut gen = data.into_gen().intersperse(0);
+        let mut output = Vec::new();
+        let result = gen.run(|x| {
+            output.push(x);
+            if x == 0 {
+                ValueResult::Stop
+            } else {
+                ValueResult::MoreValues
+            }
+        });
+        assert_eq!(result, GeneratorResult::Stopped);
+        assert_eq!(output, [1, 0]);
+
+        assert_eq!(gen.next(), Ok(2));
+        assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Complete);
+        assert_eq!(output, [1, 0, 0, 3]);
+    }
+
+    #[test]
+    fn spuriously_stopping() {
+        let data = [1, 2, 3];
+        for x in 0..3 {
+            let mut gen = StoppingGen::new(x, &data).intersperse(&0);
+            let mut output = Vec::new();
+            assert_eq!(gen.for_each(|x| output.push(*x)), GeneratorResult::Stopped);
+            assert_eq!(gen.for_each(|x| output.push(*x)), GeneratorResult::Complete);
+            assert_eq!(output, [1, 0, 2, 0, 3]);
+        }
+    }
+
+    #[test]
+    fn intersperse_with() {
+        let data = ["a", "b", "c"];
+        let mut count = 0;
+        let output: Vec<String> = data
+            .into_gen()
+            .map(String::from)
+            .intersperse_with(|| {
+                count += 1;
+                count.to_string()
+            })
+            .collect();
+        assert_eq!(output, ["a", "1", "b", "2", "c"]);
+    }
+
+    #[test]
+    fn intersperse_with_spuriously_stopping() {
+        let data = [Some(1), None, Some(2), None, None, Some(3)];
+        for stop_after_separator in [false, true] {
+            let mut gen = MultiStoppingGen::new(&data).copied().intersperse_with(|| 0);
+            let mut output = Vec::new();
+            loop {
+                let result = gen.run(|x| {
+                    output.push(x);
+                    (!stop_after_separator || x != 0).into()
+                });
+                if result == GeneratorResult::Complete {
+                    break;
+                }
+            }
+            assert_eq!(output, [1, 0, 2, 0, 3]);
+        }
+    }
+}
@@ -0,0 +1,244 @@
+use crate::{Generator, GeneratorExt, GeneratorResult, IntoGenerator, ValueResult};
+
+/// Merges any number of generators sorted in ascending order into one sorted generator.
+///
+/// Each item of `generators` may be anything implementing [`IntoGenerator`]. The next value of
+/// every generator is kept in a small binary heap, so each value costs `O(log k)` comparisons for
+/// `k` generators.
+///
+/// The output is only sorted if all inputs are sorted. Use [`kmerge_by()`] for other orderings.
+///
+/// ## Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use pushgen::{GeneratorExt, IntoGenerator};
+/// let generators = vec![vec![1, 4, 7], vec![2, 5], vec![3, 6, 8]];
+/// let output: Vec<_> = pushgen::kmerge(generators).collect();
+/// assert_eq!(output, [1, 2, 3, 4, 5, 6, 7, 8]);
+/// ```
+///
+/// Merging boxed generators:
+///
+/// ```
+/// use pushgen::{generators::BoxedGenerator, GeneratorExt, IntoGenerator};
+/// let generators: Vec<BoxedGenerator<i32>> = vec![
+///     [1, 3].into_gen().boxed(),
+///     [2, 4].into_gen().map(|x| x * 2).boxed(),
+/// ];
+/// let output: Vec<_> = pushgen::kmerge(generators).collect();
+/// assert_eq!(output, [1, 3, 4, 8]);
+/// ```
+///
+/// [`kmerge_by()`]: crate::kmerge_by
+#[inline]
+pub fn kmerge<I>(generators: I) -> KMerge<<I::Item as IntoGenerator>::IntoGen>
+where
+    I: IntoIterator,
+    I::Item: IntoGenerator,
+    <I::Item as IntoGenerator>::Output: PartialOrd,
+{
+    kmerge_by(generators, |a, b| a < b)
+}
+
+/// Merges any number of sorted generators into one sorted generator, using a predicate to decide
+/// the order.
+///
+/// `first(a, b)` should return `true` if `a` should be generated before `b`.
+/// [`kmerge()`](crate::kmerge) is the same as `kmerge_by(generators, |a, b| a < b)`.
+///
+/// ## Examples
+///
+/// Merging generators sorted in descending order:
+///
+/// ```
+/// use pushgen::GeneratorExt;
+/// let generators = vec![vec![7, 4, 1], vec![5, 2], vec![8, 6, 3]];
+/// let output: Vec<_> = pushgen::kmerge_by(generators, |a, b| a > b).collect();
+/// assert_eq!(output, [8, 7, 6, 5, 4, 3, 2, 1]);
+/// ```
+#[inline]
+pub fn kmerge_by<I, F>(generators: I, first: F) -> KMergeBy<<I::Item as IntoGenerator>::IntoGen, F>
+where
+    I: IntoIterator,
+    I::Item: IntoGenerator,
+    F: FnMut(&<I::Item as IntoGenerator>::Output, &<I::Item as IntoGenerator>::Output) -> bool,
+{
+    let unprimed: Vec<_> = generators
+        .into_iter()
+        .map(IntoGenerator::into_gen)
+        .collect();
+    KMergeBy {
+        heap: Vec::with_capacity(unprimed.len()),
+        unprimed,
+        first,
+    }
+}
+
+/// A generator that merges sorted generators in ascending order.
+///
+/// This type is created by the [`kmerge()`] function.
+/// See its documentation for more.
+///
+/// [`kmerge()`]: crate::kmerge
+pub type KMerge<G> = KMergeBy<G, fn(&<G as Generator>::Output, &<G as Generator>::Output) -> bool>;
+
+/// A generator that merges sorted generators using a predicate.
+///
+/// This `struct` is created by the [`kmerge_by()`] function.
+/// See its documentation for more.
+///
+/// [`kmerge_by()`]: crate::kmerge_by
+#[derive(Clone)]
+pub struct KMergeBy<G, F>
+where
+    G: Generator,
+{
+    /// Generators whose next value is in the heap, ordered by `first`.
+    heap: Vec<(G::Output, G)>,
+    /// Generators whose next value hasn't been retrieved yet.
+    unprimed: Vec<G>,
+    first: F,
+}
+
+impl<G, F> Generator for KMergeBy<G, F>
+where
+    G: Generator,
+    F: FnMut(&G::Output, &G::Output) -> bool,
+{
+    type Output = G::Output;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        loop {
+            // Every generator must have its next value in the heap before the smallest value is
+            // known.
+            while let Some(mut gen) = self.unprimed.pop() {
+                match gen.next() {
+                    Ok(value) => {
+                        let pos = self.heap.len();
+                        self.heap.push((value, gen));
+                        sift_up(&mut self.heap, pos, &mut self.first);
+                    }
+                    Err(GeneratorResult::Complete) => {}
+                    Err(GeneratorResult::Stopped) => {
+                        self.unprimed.push(gen);
+                        return GeneratorResult::Stopped;
+                    }
+                }
+            }
+
+            let top = match self.heap.first_mut() {
+                Some(top) => top,
+                None => return GeneratorResult::Complete,
+            };
+            let value = match top.1.next() {
+                Ok(next) => {
+                    let value = core::mem::replace(&mut top.0, next);
+                    sift_down(&mut self.heap, 0, &mut self.first);
+                    value
+                }
+                Err(result) => {
+                    let (value, gen) = self.heap.swap_remove(0);
+                    sift_down(&mut self.heap, 0, &mut self.first);
+                    if result == GeneratorResult::Stopped {
+                        self.unprimed.push(gen);
+                    }
+                    value
+                }
+            };
+
+            if output(value) == ValueResult::Stop {
+                return GeneratorResult::Stopped;
+            }
+        }
+    }
+}
+
+#[inline]
+fn sift_up<T, G>(heap: &mut [(T, G)], mut pos: usize, first: &mut impl FnMut(&T, &T) -> bool) {
+    while pos > 0 {
+        let parent = (pos - 1) / 2;
+        if !first(&heap[pos].0, &heap[parent].0) {
+            break;
+        }
+        heap.swap(pos, parent);
+        pos = parent;
+    }
+}
+
+#[inline]
+fn sift_down<T, G>(heap: &mut [(T, G)], mut pos: usize, first: &mut impl FnMut(&T, &T) -> bool) {
+    loop {
+        let left = 2 * pos + 1;
+        let right = left + 1;
+        let mut smallest = pos;
+        if left < heap.len() && first(&heap[left].0, &heap[smallest].0) {
+            smallest = left;
+        }
+        if right < heap.len() && first(&heap[right].0, &heap[smallest].0) {
+            smallest = right;
+        }
+        if smallest == pos {
+            break;
+        }
+        heap.swap(pos, smallest);
+        pos = smallest;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::{collect_one_at_a_time, StoppingGen};
+    use crate::{GeneratorExt, GeneratorResult, IntoGenerator};
+
+    #[test]
+    fn kmerge() {
+        let generators = vec![
+            vec![1, 5, 9],
+            vec![],
+            vec![2, 3, 4, 10],
+            vec![0],
+            vec![6, 7, 8],
+        ];
+        let output: Vec<_> = crate::kmerge(generators).collect();
+        assert_eq!(output, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+
+        let output: Vec<i32> = crate::kmerge(Vec::<Vec<i32>>::new()).collect();
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn kmerge_by() {
+        let generators = [[5, 3, 1].into_gen(), [6, 4, 2].into_gen()];
+        let output: Vec<_> = crate::kmerge_by(generators, |a, b| a > b).collect();
+        assert_eq!(output, [6, 5, 4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn consumer_stop() {
+        let mut gen = crate::kmerge(vec![vec![1, 4], vec![2, 3]]);
+        let output = collect_one_at_a_time(&mut gen);
+        assert_eq!(output, [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn spuriously_stopping() {
+        let a = [1, 4, 7];
+        let b = [2, 5, 8];
+        let c = [3, 6, 9];
+        for x in 0..3 {
+            for y in 0..3 {
+                let mut gen = crate::kmerge([
+                    StoppingGen::new(x, &a),
+                    StoppingGen::new(y, &b),
+                    StoppingGen::new(1, &c),
+                ]);
+                let mut output = Vec::new();
+                while gen.for_each(|x| output.push(*x)) == GeneratorResult::Stopped {}
+                assert_eq!(output, [1, 2, 3, 4, 5, 6, 7, 8, 9]);
+            }
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Generator, GeneratorResult, ReverseGenerator, ValueResult};
+use crate::{FusedGenerator, Generator, GeneratorResult, ReverseGenerator, ValueResult};
 use core::num::NonZeroUsize;
 
 /// Implements a mapped generator. See [`.map()`](crate::GeneratorExt::map) for details.
@@ -55,6 +55,13 @@ where
     }
 }
 
+impl<Gen, Func, Out> FusedGenerator for Map<Gen, Func>
+where
+    Gen: FusedGenerator,
+    Func: FnMut(Gen::Output) -> Out,
+{
+}
+
 #[cfg(test)]
 mod tests {
     use crate::test::StoppingGen;
@@ -76,6 +83,13 @@ mod tests {
         }
     }
 
+    #[test]
+    fn map_sum() {
+        let data = [1, 2, 3, 4, 5];
+        let sum: i32 = SliceGenerator::new(&data).map(|x| x + 1).sum();
+        assert_eq!(sum, data.iter().map(|x| x + 1).sum());
+    }
+
     #[test]
     fn reverse() {
         let data = [1, 2, 3];
@@ -0,0 +1,168 @@
+use crate::{FusedGenerator, Generator, GeneratorResult, ReverseGenerator, ValueResult};
+use core::marker::PhantomData;
+use core::num::NonZeroUsize;
+
+/// Maps the `Err` values of a generator of `Result`s. See
+/// [`.map_err()`](crate::GeneratorExt::map_err) for details.
+#[derive(Clone)]
+pub struct MapErr<Src, F> {
+    source: Src,
+    transform: F,
+}
+
+impl<Src, F> MapErr<Src, F> {
+    #[inline]
+    pub(crate) fn new(source: Src, transform: F) -> Self {
+        Self { source, transform }
+    }
+}
+
+impl<Src, F, T, E, U> Generator for MapErr<Src, F>
+where
+    Src: Generator<Output = Result<T, E>>,
+    F: FnMut(E) -> U,
+{
+    type Output = Result<T, U>;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        let transform = &mut self.transform;
+        self.source
+            .run(move |value| output(value.map_err(&mut *transform)))
+    }
+
+    #[inline]
+    fn try_advance(&mut self, n: NonZeroUsize) -> (usize, GeneratorResult) {
+        self.source.try_advance(n)
+    }
+}
+
+impl<Src, F, T, E, U> ReverseGenerator for MapErr<Src, F>
+where
+    Src: ReverseGenerator<Output = Result<T, E>>,
+    F: FnMut(E) -> U,
+{
+    #[inline]
+    fn run_back(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        let transform = &mut self.transform;
+        self.source
+            .run_back(move |value| output(value.map_err(&mut *transform)))
+    }
+
+    #[inline]
+    fn try_advance_back(&mut self, n: NonZeroUsize) -> (usize, GeneratorResult) {
+        self.source.try_advance_back(n)
+    }
+}
+
+impl<Src, F, T, E, U> FusedGenerator for MapErr<Src, F>
+where
+    Src: FusedGenerator<Output = Result<T, E>>,
+    F: FnMut(E) -> U,
+{
+}
+
+/// Converts the `Err` values of a generator of `Result`s with [`Into`]. See
+/// [`.err_into()`](crate::GeneratorExt::err_into) for details.
+pub struct ErrInto<Src, U> {
+    source: Src,
+    _target: PhantomData<fn() -> U>,
+}
+
+impl<Src, U> ErrInto<Src, U> {
+    #[inline]
+    pub(crate) fn new(source: Src) -> Self {
+        Self {
+            source,
+            _target: PhantomData,
+        }
+    }
+}
+
+impl<Src: Clone, U> Clone for ErrInto<Src, U> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(self.source.clone())
+    }
+}
+
+impl<Src, T, E, U> Generator for ErrInto<Src, U>
+where
+    Src: Generator<Output = Result<T, E>>,
+    E: Into<U>,
+{
+    type Output = Result<T, U>;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        self.source
+            .run(move |value| output(value.map_err(Into::into)))
+    }
+
+    #[inline]
+    fn try_advance(&mut self, n: NonZeroUsize) -> (usize, GeneratorResult) {
+        self.source.try_advance(n)
+    }
+}
+
+impl<Src, T, E, U> ReverseGenerator for ErrInto<Src, U>
+where
+    Src: ReverseGenerator<Output = Result<T, E>>,
+    E: Into<U>,
+{
+    #[inline]
+    fn run_back(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        self.source
+            .run_back(move |value| output(value.map_err(Into::into)))
+    }
+
+    #[inline]
+    fn try_advance_back(&mut self, n: NonZeroUsize) -> (usize, GeneratorResult) {
+        self.source.try_advance_back(n)
+    }
+}
+
+impl<Src, T, E, U> FusedGenerator for ErrInto<Src, U>
+where
+    Src: FusedGenerator<Output = Result<T, E>>,
+    E: Into<U>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::StoppingGen;
+    use crate::{GeneratorExt, GeneratorResult, IntoGenerator};
+
+    #[test]
+    fn map_err() {
+        let data = [Ok(1), Err(2), Ok(3)];
+        let output: Vec<_> = data.into_gen().map_err(|e| e * 10).collect();
+        assert_eq!(output, [Ok(1), Err(20), Ok(3)]);
+
+        let output: Vec<_> = data.into_gen().map_err(|e| e * 10).rev().collect();
+        assert_eq!(output, [Ok(3), Err(20), Ok(1)]);
+    }
+
+    #[test]
+    fn err_into() {
+        let data: [Result<i32, u8>; 2] = [Ok(1), Err(2)];
+        let output: Vec<Result<i32, u64>> = data.into_gen().err_into().collect();
+        assert_eq!(output, [Ok(1), Err(2u64)]);
+    }
+
+    #[test]
+    fn spuriously_stopping() {
+        let data: [Result<i32, u8>; 3] = [Ok(1), Err(2), Ok(3)];
+        for x in 0..3 {
+            let mut gen = StoppingGen::new(x, &data)
+                .copied()
+                .map_err(|e| e + 1)
+                .err_into::<u32>();
+            let mut output = Vec::new();
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Stopped);
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Complete);
+            assert_eq!(output, [Ok(1), Err(3u32), Ok(3)]);
+        }
+    }
+}
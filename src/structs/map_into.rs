@@ -0,0 +1,94 @@
+use crate::{FusedGenerator, Generator, GeneratorResult, ReverseGenerator, ValueResult};
+use core::marker::PhantomData;
+use core::num::NonZeroUsize;
+
+/// Converts each value with [`Into`]. See [`.map_into()`](crate::GeneratorExt::map_into) for details.
+pub struct MapInto<Src, U> {
+    source: Src,
+    _target: PhantomData<fn() -> U>,
+}
+
+impl<Src, U> MapInto<Src, U> {
+    #[inline]
+    pub(crate) fn new(source: Src) -> Self {
+        Self {
+            source,
+            _target: PhantomData,
+        }
+    }
+}
+
+impl<Src: Clone, U> Clone for MapInto<Src, U> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(self.source.clone())
+    }
+}
+
+impl<Src, U> Generator for MapInto<Src, U>
+where
+    Src: Generator,
+    Src::Output: Into<U>,
+{
+    type Output = U;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        self.source.run(move |value| output(value.into()))
+    }
+
+    #[inline]
+    fn try_advance(&mut self, n: NonZeroUsize) -> (usize, GeneratorResult) {
+        self.source.try_advance(n)
+    }
+}
+
+impl<Src, U> ReverseGenerator for MapInto<Src, U>
+where
+    Src: ReverseGenerator,
+    Src::Output: Into<U>,
+{
+    #[inline]
+    fn run_back(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        self.source.run_back(move |value| output(value.into()))
+    }
+
+    #[inline]
+    fn try_advance_back(&mut self, n: NonZeroUsize) -> (usize, GeneratorResult) {
+        self.source.try_advance_back(n)
+    }
+}
+
+impl<Src, U> FusedGenerator for MapInto<Src, U>
+where
+    Src: FusedGenerator,
+    Src::Output: Into<U>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::StoppingGen;
+    use crate::{GeneratorExt, GeneratorResult, IntoGenerator};
+
+    #[test]
+    fn map_into() {
+        let output: Vec<_> = [1u8, 2, 3].into_gen().map_into::<u32>().collect();
+        assert_eq!(output, [1u32, 2, 3]);
+
+        let output: Vec<_> = [1u8, 2, 3].into_gen().map_into::<i64>().rev().collect();
+        assert_eq!(output, [3i64, 2, 1]);
+    }
+
+    #[test]
+    fn spuriously_stopping() {
+        let a = [1u8, 2, 3];
+        for x in 0..3 {
+            let mut output: Vec<u16> = Vec::new();
+            let mut gen = StoppingGen::new(x, &a).copied().map_into();
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Stopped);
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Complete);
+            assert_eq!(output, [1, 2, 3]);
+        }
+    }
+}
@@ -0,0 +1,157 @@
+use crate::{FusedGenerator, Generator, GeneratorResult, ReverseGenerator, ValueResult};
+use core::num::NonZeroUsize;
+
+/// Maps the `Ok` values of a generator of `Result`s. See [`.map_ok()`](crate::GeneratorExt::map_ok)
+/// for details.
+#[derive(Clone)]
+pub struct MapOk<Src, F> {
+    source: Src,
+    transform: F,
+}
+
+impl<Src, F> MapOk<Src, F> {
+    #[inline]
+    pub(crate) fn new(source: Src, transform: F) -> Self {
+        Self { source, transform }
+    }
+}
+
+impl<Src, F, T, E, U> Generator for MapOk<Src, F>
+where
+    Src: Generator<Output = Result<T, E>>,
+    F: FnMut(T) -> U,
+{
+    type Output = Result<U, E>;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        let transform = &mut self.transform;
+        self.source
+            .run(move |value| output(value.map(&mut *transform)))
+    }
+
+    #[inline]
+    fn try_advance(&mut self, n: NonZeroUsize) -> (usize, GeneratorResult) {
+        self.source.try_advance(n)
+    }
+}
+
+impl<Src, F, T, E, U> ReverseGenerator for MapOk<Src, F>
+where
+    Src: ReverseGenerator<Output = Result<T, E>>,
+    F: FnMut(T) -> U,
+{
+    #[inline]
+    fn run_back(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        let transform = &mut self.transform;
+        self.source
+            .run_back(move |value| output(value.map(&mut *transform)))
+    }
+
+    #[inline]
+    fn try_advance_back(&mut self, n: NonZeroUsize) -> (usize, GeneratorResult) {
+        self.source.try_advance_back(n)
+    }
+}
+
+impl<Src, F, T, E, U> FusedGenerator for MapOk<Src, F>
+where
+    Src: FusedGenerator<Output = Result<T, E>>,
+    F: FnMut(T) -> U,
+{
+}
+
+/// Filters the `Ok` values of a generator of `Result`s. See
+/// [`.filter_ok()`](crate::GeneratorExt::filter_ok) for details.
+#[derive(Clone)]
+pub struct FilterOk<Src, P> {
+    source: Src,
+    predicate: P,
+}
+
+impl<Src, P> FilterOk<Src, P> {
+    #[inline]
+    pub(crate) fn new(source: Src, predicate: P) -> Self {
+        Self { source, predicate }
+    }
+}
+
+impl<Src, P, T, E> Generator for FilterOk<Src, P>
+where
+    Src: Generator<Output = Result<T, E>>,
+    P: FnMut(&T) -> bool,
+{
+    type Output = Result<T, E>;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        let predicate = &mut self.predicate;
+        self.source.run(move |value| match &value {
+            Ok(x) if !predicate(x) => ValueResult::MoreValues,
+            _ => output(value),
+        })
+    }
+}
+
+impl<Src, P, T, E> ReverseGenerator for FilterOk<Src, P>
+where
+    Src: ReverseGenerator<Output = Result<T, E>>,
+    P: FnMut(&T) -> bool,
+{
+    #[inline]
+    fn run_back(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        let predicate = &mut self.predicate;
+        self.source.run_back(move |value| match &value {
+            Ok(x) if !predicate(x) => ValueResult::MoreValues,
+            _ => output(value),
+        })
+    }
+}
+
+impl<Src, P, T, E> FusedGenerator for FilterOk<Src, P>
+where
+    Src: FusedGenerator<Output = Result<T, E>>,
+    P: FnMut(&T) -> bool,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::StoppingGen;
+    use crate::{GeneratorExt, GeneratorResult, IntoGenerator};
+
+    #[test]
+    fn map_ok() {
+        let data = [Ok(1), Err("bad"), Ok(3)];
+        let output: Vec<_> = data.into_gen().map_ok(|x| x * 10).collect();
+        assert_eq!(output, [Ok(10), Err("bad"), Ok(30)]);
+
+        let output: Vec<_> = data.into_gen().map_ok(|x| x * 10).rev().collect();
+        assert_eq!(output, [Ok(30), Err("bad"), Ok(10)]);
+    }
+
+    #[test]
+    fn filter_ok() {
+        let data = [Ok(1), Err("bad"), Ok(2), Ok(3), Err("worse")];
+        let output: Vec<_> = data.into_gen().filter_ok(|x| x % 2 == 1).collect();
+        assert_eq!(output, [Ok(1), Err("bad"), Ok(3), Err("worse")]);
+
+        let output: Vec<_> = data.into_gen().filter_ok(|x| *x > 1).rev().collect();
+        assert_eq!(output, [Err("worse"), Ok(3), Ok(2), Err("bad")]);
+    }
+
+    #[test]
+    fn spuriously_stopping() {
+        let data: [Result<i32, i32>; 4] = [Ok(1), Err(2), Ok(3), Ok(4)];
+        for x in 0..4 {
+            let mut gen = StoppingGen::new(x, &data)
+                .copied()
+                .filter_ok(|x| *x != 3)
+                .map_ok(|x| x + 1);
+            let mut output = Vec::new();
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Stopped);
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Complete);
+            assert_eq!(output, [Ok(2), Err(2), Ok(5)]);
+        }
+    }
+}
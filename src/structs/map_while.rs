@@ -0,0 +1,118 @@
+use crate::{FusedGenerator, Generator, GeneratorResult, ValueResult};
+
+/// A generator that maps values until the closure returns `None`. See [`.map_while()`](crate::GeneratorExt::map_while) for details.
+#[derive(Clone)]
+pub struct MapWhile<Src, F> {
+    source: Src,
+    func: F,
+    is_complete: bool,
+}
+
+impl<Src, F, B> MapWhile<Src, F>
+where
+    Src: Generator,
+    F: FnMut(Src::Output) -> Option<B>,
+{
+    #[inline]
+    pub(crate) fn new(source: Src, func: F) -> Self {
+        Self {
+            source,
+            func,
+            is_complete: false,
+        }
+    }
+}
+
+impl<Src, F, B> Generator for MapWhile<Src, F>
+where
+    Src: Generator,
+    F: FnMut(Src::Output) -> Option<B>,
+{
+    type Output = B;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        let is_complete = &mut self.is_complete;
+        if *is_complete {
+            return GeneratorResult::Complete;
+        }
+
+        let func = &mut self.func;
+        let result = self.source.run(|x| match func(x) {
+            Some(value) => output(value),
+            None => {
+                *is_complete = true;
+                ValueResult::Stop
+            }
+        });
+
+        if *is_complete {
+            GeneratorResult::Complete
+        } else {
+            result
+        }
+    }
+}
+
+impl<Src, F, B> FusedGenerator for MapWhile<Src, F>
+where
+    Src: FusedGenerator,
+    F: FnMut(Src::Output) -> Option<B>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::StoppingGen;
+    use crate::{Generator, GeneratorExt, GeneratorResult, IntoGenerator, ValueResult};
+
+    #[test]
+    fn map_while() {
+        let data = ["1", "2", "x", "4"];
+        let output: Vec<i32> = data.into_gen().map_while(|s| s.parse().ok()).collect();
+        assert_eq!(output, [1, 2]);
+    }
+
+    #[test]
+    fn complete_after_none() {
+        let data = [1, 2, -1, 4];
+        let mut gen = data
+            .into_gen()
+            .map_while(|x| if x > 0 { Some(x * 10) } else { None });
+        assert_eq!(gen.next(), Ok(10));
+        let mut output = Vec::new();
+        assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Complete);
+        assert_eq!(output, [20]);
+        assert_eq!(gen.next(), Err(GeneratorResult::Complete));
+    }
+
+    #[test]
+    fn consumer_stop() {
+        let data = [1, 2, 3, -1, 4];
+        let mut gen = data
+            .into_gen()
+            .map_while(|x| if x > 0 { Some(x) } else { None });
+        let mut output = Vec::new();
+        let result = gen.run(|x| {
+            output.push(x);
+            ValueResult::Stop
+        });
+        assert_eq!(result, GeneratorResult::Stopped);
+        assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Complete);
+        assert_eq!(output, [1, 2, 3]);
+    }
+
+    #[test]
+    fn spuriously_stopping() {
+        let data = [1i32, 2, 3, 4, -1, 1, 2];
+        for x in 0..5 {
+            let mut gen = StoppingGen::new(x, &data).map_while(|x| x.is_positive().then_some(*x));
+            let mut output = Vec::new();
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Stopped);
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Complete);
+            assert_eq!(output, [1, 2, 3, 4]);
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Complete);
+            assert_eq!(output, [1, 2, 3, 4]);
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use crate::{
+    structs::utility::{set_some, ArrayBuilder},
+    Generator, GeneratorResult, ValueResult,
+};
+
+/// Maps sliding windows of `N` values. See [`.map_windows()`](crate::GeneratorExt::map_windows) for details.
+#[derive(Clone)]
+pub struct MapWindows<Src, F, const N: usize>
+where
+    Src: Generator,
+{
+    source: Src,
+    func: F,
+    // Collects the values of the first window.
+    first_window: ArrayBuilder<Src::Output, N>,
+    window: Option<[Src::Output; N]>,
+}
+
+impl<Src, F, U, const N: usize> MapWindows<Src, F, N>
+where
+    Src: Generator,
+    F: FnMut(&[Src::Output; N]) -> U,
+{
+    #[inline]
+    pub(crate) fn new(source: Src, func: F) -> Self {
+        if N == 0 {
+            panic!("Window size must not be 0");
+        }
+        Self {
+            source,
+            func,
+            first_window: ArrayBuilder::new(),
+            window: None,
+        }
+    }
+}
+
+impl<Src, F, U, const N: usize> Generator for MapWindows<Src, F, N>
+where
+    Src: Generator,
+    F: FnMut(&[Src::Output; N]) -> U,
+{
+    type Output = U;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        let func = &mut self.func;
+        let first_window = &mut self.first_window;
+        let window = &mut self.window;
+        self.source.run(|x| {
+            if let Some(current) = window.as_mut() {
+                current.rotate_left(1);
+                current[N - 1] = x;
+                output(func(current))
+            } else {
+                first_window.push(x);
+                match first_window.take_array() {
+                    Some(full) => output(func(set_some(window, full))),
+                    None => ValueResult::MoreValues,
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::StoppingGen;
+    use crate::{GeneratorExt, GeneratorResult, IntoGenerator};
+
+    #[test]
+    fn map_windows() {
+        let data = [1, 2, 3, 4, 5];
+        let output: Vec<_> = data
+            .into_gen()
+            .map_windows(|w: &[_; 3]| w.iter().sum::<i32>())
+            .collect();
+        assert_eq!(output, [6, 9, 12]);
+
+        let output: Vec<_> = data.into_gen().map_windows(|w: &[_; 1]| w[0]).collect();
+        assert_eq!(output, data);
+
+        let output: Vec<_> = data.into_gen().map_windows(|w: &[i32; 6]| w[0]).collect();
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn non_clone_values() {
+        let data = [1, 2, 3];
+        let output: Vec<_> = data
+            .into_gen()
+            .map(Box::new)
+            .map_windows(|[a, b]| **a * 10 + **b)
+            .collect();
+        assert_eq!(output, [12, 23]);
+    }
+
+    #[test]
+    fn spuriously_stopping() {
+        let data = [1, 2, 3, 4];
+        for x in 0..4 {
+            let mut gen = StoppingGen::new(x, &data).map_windows(|[a, b]| **b - **a);
+            let mut output = Vec::new();
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Stopped);
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Complete);
+            assert_eq!(output, [1, 1, 1]);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_size() {
+        let data = [1, 2, 3];
+        let _gen = data.into_gen().map_windows(|_: &[i32; 0]| ());
+    }
+}
@@ -0,0 +1,321 @@
+use crate::{Generator, GeneratorExt, GeneratorResult, ValueResult};
+
+/// Merge two sorted generators. See [`.merge()`](crate::GeneratorExt::merge) for details.
+#[derive(Clone)]
+pub struct Merge<Left, Right>
+where
+    Left: Generator,
+{
+    left: Left,
+    right: Right,
+    pending_left: Option<Left::Output>,
+    pending_right: Option<Left::Output>,
+    left_complete: bool,
+    right_complete: bool,
+}
+
+impl<Left, Right> Merge<Left, Right>
+where
+    Left: Generator,
+    Right: Generator<Output = Left::Output>,
+{
+    #[inline]
+    pub(crate) fn new(left: Left, right: Right) -> Self {
+        Self {
+            left,
+            right,
+            pending_left: None,
+            pending_right: None,
+            left_complete: false,
+            right_complete: false,
+        }
+    }
+
+    /// Runs the merge, taking the left value whenever `take_left(left, right)` returns `true`.
+    #[inline]
+    pub(crate) fn run_by(
+        &mut self,
+        mut take_left: impl FnMut(&Left::Output, &Left::Output) -> bool,
+        mut output: impl FnMut(Left::Output) -> ValueResult,
+    ) -> GeneratorResult {
+        loop {
+            if self.pending_left.is_none() && !self.left_complete {
+                match self.left.next() {
+                    Ok(value) => self.pending_left = Some(value),
+                    Err(GeneratorResult::Complete) => self.left_complete = true,
+                    Err(GeneratorResult::Stopped) => return GeneratorResult::Stopped,
+                }
+            }
+            if self.pending_right.is_none() && !self.right_complete {
+                match self.right.next() {
+                    Ok(value) => self.pending_right = Some(value),
+                    Err(GeneratorResult::Complete) => self.right_complete = true,
+                    Err(GeneratorResult::Stopped) => return GeneratorResult::Stopped,
+                }
+            }
+
+            let value = match (&self.pending_left, &self.pending_right) {
+                (Some(left), Some(right)) => {
+                    if take_left(left, right) {
+                        self.pending_left.take()
+                    } else {
+                        self.pending_right.take()
+                    }
+                }
+                // Only one side is left, so its values can be passed through directly.
+                (Some(_), None) => {
+                    if let Some(value) = self.pending_left.take() {
+                        if output(value) == ValueResult::Stop {
+                            return GeneratorResult::Stopped;
+                        }
+                    }
+                    let result = self.left.run(output);
+                    self.left_complete = result == GeneratorResult::Complete;
+                    return result;
+                }
+                (None, Some(_)) => {
+                    if let Some(value) = self.pending_right.take() {
+                        if output(value) == ValueResult::Stop {
+                            return GeneratorResult::Stopped;
+                        }
+                    }
+                    let result = self.right.run(output);
+                    self.right_complete = result == GeneratorResult::Complete;
+                    return result;
+                }
+                (None, None) => return GeneratorResult::Complete,
+            };
+
+            if let Some(value) = value {
+                if output(value) == ValueResult::Stop {
+                    return GeneratorResult::Stopped;
+                }
+            }
+        }
+    }
+}
+
+impl<Left, Right> Generator for Merge<Left, Right>
+where
+    Left: Generator,
+    Left::Output: PartialOrd,
+    Right: Generator<Output = Left::Output>,
+{
+    type Output = Left::Output;
+
+    #[inline]
+    fn run(&mut self, output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        self.run_by(|left, right| left <= right, output)
+    }
+}
+
+/// Merge two sorted generators using a predicate. See
+/// [`.merge_by()`](crate::GeneratorExt::merge_by) for details.
+pub struct MergeBy<Left, Right, F>
+where
+    Left: Generator,
+{
+    merge: Merge<Left, Right>,
+    take_left: F,
+}
+
+impl<Left, Right, F> MergeBy<Left, Right, F>
+where
+    Left: Generator,
+    Right: Generator<Output = Left::Output>,
+{
+    #[inline]
+    pub(crate) fn new(left: Left, right: Right, take_left: F) -> Self {
+        Self {
+            merge: Merge::new(left, right),
+            take_left,
+        }
+    }
+}
+
+impl<Left, Right, F> Clone for MergeBy<Left, Right, F>
+where
+    Left: Generator + Clone,
+    Left::Output: Clone,
+    Right: Clone,
+    F: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            merge: self.merge.clone(),
+            take_left: self.take_left.clone(),
+        }
+    }
+}
+
+impl<Left, Right, F> Generator for MergeBy<Left, Right, F>
+where
+    Left: Generator,
+    Right: Generator<Output = Left::Output>,
+    F: FnMut(&Left::Output, &Left::Output) -> bool,
+{
+    type Output = Left::Output;
+
+    #[inline]
+    fn run(&mut self, output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        self.merge.run_by(&mut self.take_left, output)
+    }
+}
+
+/// Merge two generators sorted by a key. See
+/// [`.merge_by_key()`](crate::GeneratorExt::merge_by_key) for details.
+pub struct MergeByKey<Left, Right, F>
+where
+    Left: Generator,
+{
+    merge: Merge<Left, Right>,
+    key: F,
+}
+
+impl<Left, Right, F> MergeByKey<Left, Right, F>
+where
+    Left: Generator,
+    Right: Generator<Output = Left::Output>,
+{
+    #[inline]
+    pub(crate) fn new(left: Left, right: Right, key: F) -> Self {
+        Self {
+            merge: Merge::new(left, right),
+            key,
+        }
+    }
+}
+
+impl<Left, Right, F> Clone for MergeByKey<Left, Right, F>
+where
+    Left: Generator + Clone,
+    Left::Output: Clone,
+    Right: Clone,
+    F: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            merge: self.merge.clone(),
+            key: self.key.clone(),
+        }
+    }
+}
+
+impl<Left, Right, F, K> Generator for MergeByKey<Left, Right, F>
+where
+    Left: Generator,
+    Right: Generator<Output = Left::Output>,
+    F: FnMut(&Left::Output) -> K,
+    K: PartialOrd,
+{
+    type Output = Left::Output;
+
+    #[inline]
+    fn run(&mut self, output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        let key = &mut self.key;
+        self.merge
+            .run_by(|left, right| key(left) <= key(right), output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::{collect_one_at_a_time, StoppingGen};
+    use crate::{Generator, GeneratorExt, GeneratorResult, IntoGenerator};
+
+    #[test]
+    fn merge() {
+        let output: Vec<_> = [1, 3, 5, 6]
+            .into_gen()
+            .merge([2, 3, 4, 8, 9].into_gen())
+            .collect();
+        assert_eq!(output, [1, 2, 3, 3, 4, 5, 6, 8, 9]);
+
+        let output: Vec<_> = [1, 2].into_gen().merge([0; 0].into_gen()).collect();
+        assert_eq!(output, [1, 2]);
+
+        let output: Vec<_> = [0; 0].into_gen().merge([1, 2].into_gen()).collect();
+        assert_eq!(output, [1, 2]);
+    }
+
+    #[test]
+    fn consumer_stop() {
+        let mut gen = [1, 4].into_gen().merge([2, 3, 5].into_gen());
+        let output = collect_one_at_a_time(&mut gen);
+        assert_eq!(output, [1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn spuriously_stopping() {
+        let left = [1, 4, 5];
+        let right = [2, 3, 6];
+        for x in 0..3 {
+            for y in 0..3 {
+                let mut gen = StoppingGen::new(x, &left).merge(StoppingGen::new(y, &right));
+                let mut output = Vec::new();
+                while gen.for_each(|x| output.push(*x)) == GeneratorResult::Stopped {}
+                assert_eq!(output, [1, 2, 3, 4, 5, 6]);
+            }
+        }
+    }
+
+    #[test]
+    fn completed_side_is_not_resumed() {
+        // Generates 1 and 2, completes, and would generate 4 if it is run again.
+        fn unfused() -> impl Generator<Output = i32> {
+            let mut count = 0;
+            crate::from_fn(move || {
+                count += 1;
+                match count {
+                    1 | 2 | 4 => Some(count),
+                    _ => None,
+                }
+            })
+        }
+
+        let mut gen = unfused().merge([0; 0].into_gen());
+        assert_eq!(gen.for_each(|_| ()), GeneratorResult::Complete);
+        assert_eq!(gen.next(), Err(GeneratorResult::Complete));
+
+        let mut gen = [0; 0].into_gen().merge(unfused());
+        assert_eq!(gen.for_each(|_| ()), GeneratorResult::Complete);
+        assert_eq!(gen.next(), Err(GeneratorResult::Complete));
+    }
+
+    #[test]
+    fn merge_by() {
+        let output: Vec<_> = [6, 5, 1]
+            .into_gen()
+            .merge_by([4, 3, 2].into_gen(), |a, b| a >= b)
+            .collect();
+        assert_eq!(output, [6, 5, 4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn merge_by_key() {
+        let left = [(1, 'a'), (3, 'b')];
+        let right = [(1, 'c'), (2, 'd')];
+        let output: Vec<_> = left
+            .into_gen()
+            .merge_by_key(right.into_gen(), |x| x.0)
+            .collect();
+        assert_eq!(output, [(1, 'a'), (1, 'c'), (2, 'd'), (3, 'b')]);
+    }
+
+    #[test]
+    fn merge_by_spuriously_stopping() {
+        let left = [5, 4, 1];
+        let right = [6, 3, 2];
+        for x in 0..3 {
+            for y in 0..3 {
+                let mut gen =
+                    StoppingGen::new(x, &left).merge_by(StoppingGen::new(y, &right), |a, b| a > b);
+                let mut output = Vec::new();
+                while gen.for_each(|x| output.push(*x)) == GeneratorResult::Stopped {}
+                assert_eq!(output, [6, 5, 4, 3, 2, 1]);
+            }
+        }
+    }
+}
@@ -1,40 +1,208 @@
 //! Generator adaptor implementations. See [`GeneratorExt`](crate::GeneratorExt) for more info.
 
+pub use array_chunks::ArrayChunks;
+pub use batching::Batching;
+pub use cartesian_product::CartesianProduct;
 pub use chain::Chain;
 pub use cloned::Cloned;
+pub use coalesce::Coalesce;
 pub use copied::Copied;
-pub use cycle::Cycle;
-pub use dedup::Dedup;
+pub use cumulative::{CumProd, CumSum};
+pub use cycle::{Cycle, CycleN};
+pub use dedup::{Dedup, DedupBy, DedupByKey, DedupWithCount};
+pub use diff::{Diff, DiffBy};
 pub use enumerate::Enumerate;
 pub use filter::Filter;
 pub use filter_map::FilterMap;
-pub use flatten::Flatten;
+pub use flat_map::FlatMap;
+pub use flatten::{Flatten, FlattenOk, FlattenWithSep};
+pub use flatten_option::FlattenOption;
+pub use format::FormatWith;
+pub use fuse::Fuse;
+pub use gather::Gather;
+pub use group_by::{Group, GroupBy};
 pub use inspect::Inspect;
+pub use interleave::{Interleave, InterleaveShortest};
+pub use intersperse::{Intersperse, IntersperseWith};
 pub use iterator::IteratorAdaptor;
 pub use map::Map;
+pub use map_err::{ErrInto, MapErr};
+pub use map_into::MapInto;
+pub use map_ok::{FilterOk, MapOk};
+pub use map_while::MapWhile;
+pub use map_windows::MapWindows;
+pub use merge::{Merge, MergeBy, MergeByKey};
+pub use multizip::{multizip, GeneratorTuple, MultiZip};
+pub use pad_using::PadUsing;
+pub use peekable::Peekable;
+pub use positions::Positions;
+pub use put_back::{put_back, PutBack};
+pub use repeat::{repeat_n, RepeatN};
 pub use rev::Reverse;
+pub use rle::{Rld, Rle};
 pub use scan::Scan;
 pub use skip::{Skip, SkipWhile};
 pub use step_by::StepBy;
-pub use take::{Take, TakeWhile};
-pub use zip::Zip;
+pub use take::{Take, TakeWhile, TakeWhileInclusive};
+pub use tuple_windows::{TupleWindow, TupleWindows};
+pub use tuples::{HomogeneousTuple, Tuples};
+pub use unwrap_or::{UnwrapOr, UnwrapOrElse};
+pub use update::Update;
+pub use while_some::WhileSome;
+pub use with_position::{Position, WithPosition};
+pub use zip::{EitherOrBoth, TryZipEq, Zip, ZipEq, ZipLongest, ZipWith};
 
+mod array_chunks;
+mod batching;
+mod cartesian_product;
 mod chain;
 mod cloned;
+mod coalesce;
 mod copied;
+mod cumulative;
 mod cycle;
 mod dedup;
+mod diff;
 mod enumerate;
 mod filter;
 mod filter_map;
+mod flat_map;
 mod flatten;
+mod flatten_option;
+mod format;
+mod fuse;
+mod gather;
+mod group_by;
 mod inspect;
+mod interleave;
+mod intersperse;
 mod iterator;
 mod map;
+mod map_err;
+mod map_into;
+mod map_ok;
+mod map_while;
+mod map_windows;
+mod merge;
+mod multizip;
+mod pad_using;
+mod peekable;
+mod positions;
+mod put_back;
+mod repeat;
 mod rev;
+mod rle;
 mod scan;
 mod skip;
 mod step_by;
 mod take;
+mod tuple_windows;
+mod tuples;
+mod unwrap_or;
+mod update;
 pub(crate) mod utility;
+mod while_some;
+mod with_position;
 mod zip;
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+mod chunk_by;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use chunk_by::{ChunkBy, ChunkByWithKey};
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+mod windows_sum;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use windows_sum::WindowsSum;
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+mod split;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use split::{SplitOn, SplitWhen};
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+mod chunks;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use chunks::Chunks;
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+mod kmerge;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use kmerge::{kmerge, kmerge_by, KMerge, KMergeBy};
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+mod unique;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use unique::{Duplicates, Unique, UniqueBy};
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+mod multipeek;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use multipeek::MultiPeek;
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use put_back::{put_back_n, PutBackN};
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+mod dropping_back;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use dropping_back::DroppingBack;
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+mod tail;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use tail::Tail;
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+mod round_robin;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use round_robin::{round_robin, RoundRobin};
+
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+mod sample;
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+pub use sample::Sample;
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+mod sliding_windows;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use sliding_windows::SlidingWindows;
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+mod rolling;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use rolling::{Rolling, RollingStats};
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+mod combinations;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use combinations::{Combinations, Permutations, Powerset};
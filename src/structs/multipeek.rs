@@ -0,0 +1,142 @@
+use crate::{Generator, GeneratorExt, GeneratorResult, ValueResult};
+use std::collections::VecDeque;
+
+/// A generator that can peek at multiple values. See
+/// [`.multipeek()`](crate::GeneratorExt::multipeek) for details.
+#[derive(Clone)]
+pub struct MultiPeek<Src>
+where
+    Src: Generator,
+{
+    source: Src,
+    buffer: VecDeque<Src::Output>,
+    // Index into `buffer` of the value returned by the next call to `peek()`.
+    index: usize,
+}
+
+impl<Src> MultiPeek<Src>
+where
+    Src: Generator,
+{
+    #[inline]
+    pub(crate) fn new(source: Src) -> Self {
+        Self {
+            source,
+            buffer: VecDeque::new(),
+            index: 0,
+        }
+    }
+
+    /// Returns a reference to the next value that hasn't been peeked at, without consuming it.
+    ///
+    /// Every call advances the peek cursor by one value. The cursor is moved back to the first
+    /// value by [`reset_peek()`](MultiPeek::reset_peek) and whenever values are generated.
+    ///
+    /// Returns `None` if the source generator stopped or completed before generating a value.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let mut gen = [1, 2, 3].into_gen().multipeek();
+    /// assert_eq!(gen.peek(), Some(&1));
+    /// assert_eq!(gen.peek(), Some(&2));
+    /// assert_eq!(gen.next(), Ok(1));
+    /// assert_eq!(gen.peek(), Some(&2));
+    /// ```
+    #[inline]
+    pub fn peek(&mut self) -> Option<&Src::Output> {
+        if self.index == self.buffer.len() {
+            self.buffer.push_back(self.source.next().ok()?);
+        }
+        self.index += 1;
+        self.buffer.get(self.index - 1)
+    }
+
+    /// Moves the peek cursor back to the first value that hasn't been generated.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let mut gen = [1, 2, 3].into_gen().multipeek();
+    /// assert_eq!(gen.peek(), Some(&1));
+    /// assert_eq!(gen.peek(), Some(&2));
+    /// gen.reset_peek();
+    /// assert_eq!(gen.peek(), Some(&1));
+    /// ```
+    #[inline]
+    pub fn reset_peek(&mut self) {
+        self.index = 0;
+    }
+}
+
+impl<Src> Generator for MultiPeek<Src>
+where
+    Src: Generator,
+{
+    type Output = Src::Output;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        self.index = 0;
+        while let Some(x) = self.buffer.pop_front() {
+            if output(x) == ValueResult::Stop {
+                return GeneratorResult::Stopped;
+            }
+        }
+        self.source.run(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::{collect_one_at_a_time, StoppingGen};
+    use crate::{GeneratorExt, GeneratorResult, IntoGenerator};
+
+    #[test]
+    fn multipeek() {
+        let mut gen = [1, 2, 3, 4].into_gen().multipeek();
+        assert_eq!(gen.peek(), Some(&1));
+        assert_eq!(gen.peek(), Some(&2));
+        assert_eq!(gen.peek(), Some(&3));
+        assert_eq!(gen.next(), Ok(1));
+        assert_eq!(gen.peek(), Some(&2));
+        gen.reset_peek();
+        assert_eq!(gen.peek(), Some(&2));
+        assert_eq!(gen.peek(), Some(&3));
+        assert_eq!(gen.peek(), Some(&4));
+        assert_eq!(gen.peek(), None);
+        let output: Vec<_> = gen.collect();
+        assert_eq!(output, [2, 3, 4]);
+    }
+
+    #[test]
+    fn consumer_stop() {
+        let mut gen = [1, 2, 3].into_gen().multipeek();
+        assert_eq!(gen.peek(), Some(&1));
+        assert_eq!(gen.peek(), Some(&2));
+        let output = collect_one_at_a_time(&mut gen);
+        assert_eq!(output, [1, 2, 3]);
+    }
+
+    #[test]
+    fn spuriously_stopping() {
+        let data = [1, 2, 3];
+        for x in 0..3 {
+            let mut gen = StoppingGen::new(x, &data).multipeek();
+            let mut peeked = Vec::new();
+            while peeked.len() < 3 {
+                if let Some(x) = gen.peek() {
+                    peeked.push(**x);
+                }
+            }
+            assert_eq!(peeked, data);
+            assert_eq!(gen.peek(), None);
+
+            let mut output = Vec::new();
+            assert_eq!(gen.for_each(|x| output.push(*x)), GeneratorResult::Complete);
+            assert_eq!(output, data);
+        }
+    }
+}
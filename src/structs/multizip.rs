@@ -0,0 +1,203 @@
+use crate::{Generator, GeneratorExt, GeneratorResult, ValueResult};
+
+/// Zips a tuple of generators into a single generator of tuples.
+///
+/// `multizip((a, b, c))` generates `(a_value, b_value, c_value)` tuples, which saves nesting
+/// [`zip()`](crate::GeneratorExt::zip) calls and flattening the nested tuples with
+/// [`map()`](crate::GeneratorExt::map) afterwards. Tuples of 2 up to 8 generators are supported.
+///
+/// The generator will complete when any of the generators completes.
+///
+/// ## Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use pushgen::{GeneratorExt, IntoGenerator};
+/// let a = [1, 2, 3];
+/// let b = ['a', 'b', 'c'];
+/// let c = [true, false];
+/// let output: Vec<_> = pushgen::multizip((a.into_gen(), b.into_gen(), c.into_gen())).collect();
+/// assert_eq!(output, [(1, 'a', true), (2, 'b', false)]);
+/// ```
+#[inline]
+pub fn multizip<T: GeneratorTuple>(generators: T) -> MultiZip<T> {
+    MultiZip {
+        generators,
+        pending: Default::default(),
+    }
+}
+
+/// A tuple of generators that can be zipped with [`multizip()`].
+///
+/// [`multizip()`]: crate::multizip
+pub trait GeneratorTuple {
+    /// The tuple of values generated by the zipped generators.
+    type Output;
+    #[doc(hidden)]
+    type Pending: Default;
+
+    #[doc(hidden)]
+    fn run_zipped(
+        &mut self,
+        pending: &mut Self::Pending,
+        output: impl FnMut(Self::Output) -> ValueResult,
+    ) -> GeneratorResult;
+}
+
+/// A generator that zips a tuple of generators.
+///
+/// This `struct` is created by the [`multizip()`] function.
+/// See its documentation for more.
+///
+/// [`multizip()`]: crate::multizip
+pub struct MultiZip<T: GeneratorTuple> {
+    generators: T,
+    pending: T::Pending,
+}
+
+impl<T> Clone for MultiZip<T>
+where
+    T: GeneratorTuple + Clone,
+    T::Pending: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            generators: self.generators.clone(),
+            pending: self.pending.clone(),
+        }
+    }
+}
+
+impl<T: GeneratorTuple> Generator for MultiZip<T> {
+    type Output = T::Output;
+
+    #[inline]
+    fn run(&mut self, output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        self.generators.run_zipped(&mut self.pending, output)
+    }
+}
+
+macro_rules! impl_generator_tuple {
+    ($G0:ident $g0:ident $p0:ident, $($G:ident $g:ident $p:ident),+) => {
+        impl<$G0: Generator, $($G: Generator),+> GeneratorTuple for ($G0, $($G),+) {
+            type Output = ($G0::Output, $($G::Output),+);
+            type Pending = (Option<$G0::Output>, $(Option<$G::Output>),+);
+
+            #[inline]
+            fn run_zipped(
+                &mut self,
+                pending: &mut Self::Pending,
+                mut output: impl FnMut(Self::Output) -> ValueResult,
+            ) -> GeneratorResult {
+                let ($g0, $($g),+) = self;
+                let ($p0, $($p),+) = pending;
+
+                // Finish the tuple that was interrupted by a stopping generator. Values already
+                // taken from the other generators are kept in `pending` until then.
+                if let Some(v0) = $p0.take() {
+                    $(
+                        if $p.is_none() {
+                            match $g.next() {
+                                Ok(v) => *$p = Some(v),
+                                Err(result) => {
+                                    *$p0 = Some(v0);
+                                    return result;
+                                }
+                            }
+                        }
+                    )+
+                    let value = (v0, $(match $p.take() {
+                        Some(v) => v,
+                        None => unreachable!(),
+                    }),+);
+                    if output(value) == ValueResult::Stop {
+                        return GeneratorResult::Stopped;
+                    }
+                }
+
+                let mut other_result = GeneratorResult::Stopped;
+                let result = $g0.run(|v0| {
+                    $(
+                        match $g.next() {
+                            Ok(v) => *$p = Some(v),
+                            Err(result) => {
+                                *$p0 = Some(v0);
+                                other_result = result;
+                                return ValueResult::Stop;
+                            }
+                        }
+                    )+
+                    let value = (v0, $(match $p.take() {
+                        Some(v) => v,
+                        None => unreachable!(),
+                    }),+);
+                    output(value)
+                });
+
+                if result == GeneratorResult::Complete || other_result == GeneratorResult::Complete
+                {
+                    GeneratorResult::Complete
+                } else {
+                    GeneratorResult::Stopped
+                }
+            }
+        }
+    };
+}
+
+impl_generator_tuple!(A a pa, B b pb);
+impl_generator_tuple!(A a pa, B b pb, C c pc);
+impl_generator_tuple!(A a pa, B b pb, C c pc, D d pd);
+impl_generator_tuple!(A a pa, B b pb, C c pc, D d pd, E e pe);
+impl_generator_tuple!(A a pa, B b pb, C c pc, D d pd, E e pe, F f pf);
+impl_generator_tuple!(A a pa, B b pb, C c pc, D d pd, E e pe, F f pf, G g pg);
+impl_generator_tuple!(A a pa, B b pb, C c pc, D d pd, E e pe, F f pf, G g pg, H h ph);
+
+#[cfg(test)]
+mod tests {
+    use crate::test::StoppingGen;
+    use crate::{GeneratorExt, GeneratorResult, SliceGenerator};
+
+    #[test]
+    fn multizip() {
+        let a = [1, 2, 3];
+        let b = [4, 5, 6, 7];
+        let c = [8, 9, 10];
+        let output: Vec<_> = crate::multizip((
+            SliceGenerator::new(&a),
+            SliceGenerator::new(&b),
+            SliceGenerator::new(&c),
+        ))
+        .map(|(a, b, c)| a + b + c)
+        .collect();
+        assert_eq!(output, [13, 16, 19]);
+
+        let output: Vec<_> = crate::multizip((SliceGenerator::new(&a), SliceGenerator::new(&[])))
+            .map(|(a, b): (&i32, &i32)| a + b)
+            .collect();
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn spuriously_stopping() {
+        let data = [1, 2, 3];
+        for x in 0..3 {
+            for y in 0..3 {
+                for z in 0..3 {
+                    let mut gen = crate::multizip((
+                        StoppingGen::new(x, &data),
+                        StoppingGen::new(y, &data),
+                        StoppingGen::new(z, &data),
+                    ));
+                    let mut output = Vec::new();
+                    while gen.for_each(|(a, b, c)| output.push(a + b + c))
+                        == GeneratorResult::Stopped
+                    {}
+                    assert_eq!(output, [3, 6, 9]);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use crate::{Generator, GeneratorResult, ValueResult};
+
+/// Padding of a generator to a minimum length. See
+/// [`.pad_using()`](crate::GeneratorExt::pad_using) for details.
+#[derive(Clone)]
+pub struct PadUsing<Src, F> {
+    source: Src,
+    f: F,
+    min_len: usize,
+    // Number of values generated so far.
+    index: usize,
+    source_complete: bool,
+}
+
+impl<Src, F> PadUsing<Src, F> {
+    #[inline]
+    pub(crate) fn new(source: Src, min_len: usize, f: F) -> Self {
+        Self {
+            source,
+            f,
+            min_len,
+            index: 0,
+            source_complete: false,
+        }
+    }
+}
+
+impl<Src, F> Generator for PadUsing<Src, F>
+where
+    Src: Generator,
+    F: FnMut(usize) -> Src::Output,
+{
+    type Output = Src::Output;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        if !self.source_complete {
+            let index = &mut self.index;
+            let result = self.source.run(|x| {
+                *index += 1;
+                output(x)
+            });
+            if result == GeneratorResult::Stopped {
+                return result;
+            }
+            self.source_complete = true;
+        }
+
+        while self.index < self.min_len {
+            let value = (self.f)(self.index);
+            self.index += 1;
+            if output(value) == ValueResult::Stop {
+                return GeneratorResult::Stopped;
+            }
+        }
+
+        GeneratorResult::Complete
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::{collect_one_at_a_time, StoppingGen};
+    use crate::{GeneratorExt, GeneratorResult, IntoGenerator};
+
+    #[test]
+    fn pad_using() {
+        let output: Vec<_> = [1, 2].into_gen().pad_using(5, |i| i * 10).collect();
+        assert_eq!(output, [1, 2, 20, 30, 40]);
+
+        let output: Vec<_> = [1, 2, 3].into_gen().pad_using(2, |_| 0).collect();
+        assert_eq!(output, [1, 2, 3]);
+
+        let empty: [usize; 0] = [];
+        let output: Vec<_> = empty.into_gen().pad_using(2, |i| i).collect();
+        assert_eq!(output, [0, 1]);
+    }
+
+    #[test]
+    fn consumer_stop() {
+        let mut gen = [1].into_gen().pad_using(3, |_| 0);
+        let output = collect_one_at_a_time(&mut gen);
+        assert_eq!(output, [1, 0, 0]);
+    }
+
+    #[test]
+    fn spuriously_stopping() {
+        let data = [1, 2, 3];
+        for x in 0..3 {
+            let mut gen = StoppingGen::new(x, &data).copied().pad_using(5, |_| 0);
+            let mut output = Vec::new();
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Stopped);
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Complete);
+            assert_eq!(output, [1, 2, 3, 0, 0]);
+        }
+    }
+}
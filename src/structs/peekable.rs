@@ -0,0 +1,136 @@
+use crate::{FusedGenerator, Generator, GeneratorExt, GeneratorResult, ValueResult};
+
+/// A generator with a `peek()` method. See [`.peekable()`](crate::GeneratorExt::peekable) for
+/// details.
+#[derive(Clone)]
+pub struct Peekable<Src>
+where
+    Src: Generator,
+{
+    source: Src,
+    peeked: Option<Src::Output>,
+}
+
+impl<Src> Peekable<Src>
+where
+    Src: Generator,
+{
+    #[inline]
+    pub(crate) fn new(source: Src) -> Self {
+        Self {
+            source,
+            peeked: None,
+        }
+    }
+
+    /// Returns a reference to the next value without consuming it.
+    ///
+    /// Returns `None` if the source generator stopped or completed before generating a value.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let mut gen = [1, 2].into_gen().peekable();
+    /// assert_eq!(gen.peek(), Some(&1));
+    /// assert_eq!(gen.peek(), Some(&1));
+    /// assert_eq!(gen.next(), Ok(1));
+    /// assert_eq!(gen.peek(), Some(&2));
+    /// assert_eq!(gen.next(), Ok(2));
+    /// assert_eq!(gen.peek(), None);
+    /// ```
+    #[inline]
+    pub fn peek(&mut self) -> Option<&Src::Output> {
+        self.peek_mut().map(|x| &*x)
+    }
+
+    /// Returns a mutable reference to the next value without consuming it.
+    ///
+    /// Returns `None` if the source generator stopped or completed before generating a value.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let mut gen = [1, 2].into_gen().peekable();
+    /// if let Some(x) = gen.peek_mut() {
+    ///     *x *= 10;
+    /// }
+    /// let output: Vec<_> = gen.collect();
+    /// assert_eq!(output, [10, 2]);
+    /// ```
+    #[inline]
+    pub fn peek_mut(&mut self) -> Option<&mut Src::Output> {
+        if self.peeked.is_none() {
+            self.peeked = self.source.next().ok();
+        }
+        self.peeked.as_mut()
+    }
+}
+
+impl<Src> Generator for Peekable<Src>
+where
+    Src: Generator,
+{
+    type Output = Src::Output;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        if let Some(x) = self.peeked.take() {
+            if output(x) == ValueResult::Stop {
+                return GeneratorResult::Stopped;
+            }
+        }
+        self.source.run(output)
+    }
+}
+
+impl<Src> FusedGenerator for Peekable<Src> where Src: FusedGenerator {}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::StoppingGen;
+    use crate::{Generator, GeneratorExt, GeneratorResult, IntoGenerator};
+
+    #[test]
+    fn peekable() {
+        let mut gen = [1, 2, 3].into_gen().peekable();
+        assert_eq!(gen.peek(), Some(&1));
+        let output: Vec<_> = gen.collect();
+        assert_eq!(output, [1, 2, 3]);
+
+        let empty: [i32; 0] = [];
+        let mut gen = empty.into_gen().peekable();
+        assert_eq!(gen.peek(), None);
+        assert_eq!(gen.next(), Err(GeneratorResult::Complete));
+    }
+
+    #[test]
+    fn consumer_stop() {
+        let mut gen = [1, 2, 3].into_gen().peekable();
+        let mut output = Vec::new();
+        while gen.run(|x| {
+            output.push(x);
+            false.into()
+        }) == GeneratorResult::Stopped
+        {
+            if let Some(x) = gen.peek() {
+                output.push(*x * 10);
+            }
+        }
+        assert_eq!(output, [1, 20, 2, 30, 3]);
+    }
+
+    #[test]
+    fn spuriously_stopping() {
+        let data = [1, 2, 3];
+        for x in 0..3 {
+            let mut gen = StoppingGen::new(x, &data).peekable();
+            let mut output = Vec::new();
+            while gen.peek().is_none() {}
+            assert_eq!(gen.peek(), Some(&&1));
+            while gen.for_each(|x| output.push(*x)) == GeneratorResult::Stopped {}
+            assert_eq!(output, data);
+        }
+    }
+}
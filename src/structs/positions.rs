@@ -0,0 +1,87 @@
+use crate::{FusedGenerator, Generator, GeneratorResult, ValueResult};
+
+/// A generator of the indices of the values matching a predicate. See
+/// [`.positions()`](crate::GeneratorExt::positions) for details.
+#[derive(Clone)]
+pub struct Positions<Src, P> {
+    source: Src,
+    predicate: P,
+    index: usize,
+}
+
+impl<Src, P> Positions<Src, P> {
+    #[inline]
+    pub(crate) fn new(source: Src, predicate: P) -> Self {
+        Self {
+            source,
+            predicate,
+            index: 0,
+        }
+    }
+}
+
+impl<Src, P> Generator for Positions<Src, P>
+where
+    Src: Generator,
+    P: FnMut(Src::Output) -> bool,
+{
+    type Output = usize;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        let index = &mut self.index;
+        let predicate = &mut self.predicate;
+        self.source.run(|x| {
+            let current = *index;
+            *index += 1;
+            if predicate(x) {
+                output(current)
+            } else {
+                ValueResult::MoreValues
+            }
+        })
+    }
+}
+
+impl<Src, P> FusedGenerator for Positions<Src, P>
+where
+    Src: FusedGenerator,
+    P: FnMut(Src::Output) -> bool,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::{collect_one_at_a_time, StoppingGen};
+    use crate::{GeneratorExt, GeneratorResult, IntoGenerator};
+
+    #[test]
+    fn positions() {
+        let data = [1, 2, 3, 4, 6, 7];
+        let output: Vec<_> = data.into_gen().positions(|x| x % 2 == 0).collect();
+        assert_eq!(output, [1, 3, 4]);
+
+        let output: Vec<_> = data.into_gen().positions(|x| x > 10).collect();
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn consumer_stop() {
+        let data = [2, 1, 4, 4];
+        let mut gen = data.into_gen().positions(|x| x % 2 == 0);
+        let output = collect_one_at_a_time(&mut gen);
+        assert_eq!(output, [0, 2, 3]);
+    }
+
+    #[test]
+    fn spuriously_stopping() {
+        let data = [1, 2, 3, 4, 6, 7];
+        for x in 0..data.len() {
+            let mut gen = StoppingGen::new(x as i32, &data).positions(|x| x % 2 == 0);
+            let mut output = Vec::new();
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Stopped);
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Complete);
+            assert_eq!(output, [1, 3, 4]);
+        }
+    }
+}
@@ -0,0 +1,195 @@
+use crate::{Generator, GeneratorResult, IntoGenerator, ValueResult};
+
+/// Creates a generator that can put back one value, to be generated before the rest of the
+/// source.
+///
+/// ## Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use pushgen::GeneratorExt;
+/// let mut gen = pushgen::put_back(vec![1, 2, 3]);
+/// let first = gen.next().unwrap();
+/// gen.put_back(first * 10);
+/// let output: Vec<_> = gen.collect();
+/// assert_eq!(output, [10, 2, 3]);
+/// ```
+#[inline]
+pub fn put_back<G: IntoGenerator>(generator: G) -> PutBack<G::IntoGen> {
+    PutBack {
+        source: generator.into_gen(),
+        top: None,
+    }
+}
+
+/// A generator that can put back one value.
+///
+/// This `struct` is created by the [`put_back()`] function.
+/// See its documentation for more.
+///
+/// [`put_back()`]: crate::put_back
+#[derive(Clone)]
+pub struct PutBack<Src>
+where
+    Src: Generator,
+{
+    source: Src,
+    top: Option<Src::Output>,
+}
+
+impl<Src> PutBack<Src>
+where
+    Src: Generator,
+{
+    /// Puts back a value, to be generated before any other value.
+    ///
+    /// Only one value can be put back. If a value has already been put back it is replaced and
+    /// returned.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use pushgen::GeneratorExt;
+    /// let mut gen = pushgen::put_back(vec![3]);
+    /// assert_eq!(gen.put_back(1), None);
+    /// assert_eq!(gen.put_back(2), Some(1));
+    /// let output: Vec<_> = gen.collect();
+    /// assert_eq!(output, [2, 3]);
+    /// ```
+    #[inline]
+    pub fn put_back(&mut self, value: Src::Output) -> Option<Src::Output> {
+        self.top.replace(value)
+    }
+}
+
+impl<Src> Generator for PutBack<Src>
+where
+    Src: Generator,
+{
+    type Output = Src::Output;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        if let Some(x) = self.top.take() {
+            if output(x) == ValueResult::Stop {
+                return GeneratorResult::Stopped;
+            }
+        }
+        self.source.run(output)
+    }
+}
+
+/// Creates a generator that can put back any number of values, to be generated before the rest
+/// of the source.
+///
+/// ## Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use pushgen::GeneratorExt;
+/// let mut gen = pushgen::put_back_n(vec![3, 4]);
+/// gen.put_back(2);
+/// gen.put_back(1);
+/// let output: Vec<_> = gen.collect();
+/// assert_eq!(output, [1, 2, 3, 4]);
+/// ```
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[inline]
+pub fn put_back_n<G: IntoGenerator>(generator: G) -> PutBackN<G::IntoGen> {
+    PutBackN {
+        source: generator.into_gen(),
+        stack: Vec::new(),
+    }
+}
+
+/// A generator that can put back any number of values.
+///
+/// This `struct` is created by the [`put_back_n()`] function.
+/// See its documentation for more.
+///
+/// [`put_back_n()`]: crate::put_back_n
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[derive(Clone)]
+pub struct PutBackN<Src>
+where
+    Src: Generator,
+{
+    source: Src,
+    // Values that have been put back, the last one is generated first.
+    stack: Vec<Src::Output>,
+}
+
+#[cfg(feature = "std")]
+impl<Src> PutBackN<Src>
+where
+    Src: Generator,
+{
+    /// Puts back a value, to be generated before any other value.
+    ///
+    /// Values are generated in the reverse order they were put back.
+    #[inline]
+    pub fn put_back(&mut self, value: Src::Output) {
+        self.stack.push(value);
+    }
+}
+
+#[cfg(feature = "std")]
+impl<Src> Generator for PutBackN<Src>
+where
+    Src: Generator,
+{
+    type Output = Src::Output;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        while let Some(x) = self.stack.pop() {
+            if output(x) == ValueResult::Stop {
+                return GeneratorResult::Stopped;
+            }
+        }
+        self.source.run(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::{collect_one_at_a_time, StoppingGen};
+    use crate::{GeneratorExt, GeneratorResult};
+
+    #[test]
+    fn put_back() {
+        let mut gen = crate::put_back([1, 2, 3]);
+        assert_eq!(gen.next(), Ok(1));
+        assert_eq!(gen.next(), Ok(2));
+        assert_eq!(gen.put_back(2), None);
+        let output: Vec<_> = gen.collect();
+        assert_eq!(output, [2, 3]);
+    }
+
+    #[test]
+    fn put_back_n() {
+        let mut gen = crate::put_back_n([1, 2, 3]);
+        assert_eq!(gen.next(), Ok(1));
+        gen.put_back(1);
+        gen.put_back(0);
+        let output = collect_one_at_a_time(&mut gen);
+        assert_eq!(output, [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn spuriously_stopping() {
+        let data = [1, 2, 3];
+        for x in 0..3 {
+            let mut gen = crate::put_back(StoppingGen::new(x, &data));
+            gen.put_back(&0);
+            let mut output = Vec::new();
+            assert_eq!(gen.for_each(|x| output.push(*x)), GeneratorResult::Stopped);
+            assert_eq!(gen.for_each(|x| output.push(*x)), GeneratorResult::Complete);
+            assert_eq!(output, [0, 1, 2, 3]);
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use crate::{FusedGenerator, Generator, GeneratorResult, ReverseGenerator, ValueResult};
+use core::num::NonZeroUsize;
+
+/// Creates a generator that generates `value` exactly `n` times.
+///
+/// Unlike an endlessly repeating generator, this one completes after `n` values, so it can be
+/// reduced directly.
+///
+/// ## Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use pushgen::GeneratorExt;
+/// let sum: i32 = pushgen::repeat_n(7, 3).sum();
+/// assert_eq!(sum, 21);
+///
+/// let padding: Vec<_> = pushgen::repeat_n(' ', 2).collect();
+/// assert_eq!(padding, [' ', ' ']);
+/// ```
+#[inline]
+pub fn repeat_n<T: Clone>(value: T, n: usize) -> RepeatN<T> {
+    RepeatN {
+        value,
+        remaining: n,
+    }
+}
+
+/// A generator that generates a value a fixed number of times.
+///
+/// This `struct` is created by the [`repeat_n()`] function.
+/// See its documentation for more.
+///
+/// [`repeat_n()`]: crate::repeat_n
+#[derive(Clone)]
+pub struct RepeatN<T> {
+    value: T,
+    remaining: usize,
+}
+
+impl<T: Clone> Generator for RepeatN<T> {
+    type Output = T;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        while self.remaining > 0 {
+            self.remaining -= 1;
+            if output(self.value.clone()) == ValueResult::Stop {
+                return GeneratorResult::Stopped;
+            }
+        }
+        GeneratorResult::Complete
+    }
+
+    #[inline]
+    fn try_advance(&mut self, n: NonZeroUsize) -> (usize, GeneratorResult) {
+        let n = n.get();
+        if n > self.remaining {
+            let advanced = self.remaining;
+            self.remaining = 0;
+            (advanced, GeneratorResult::Complete)
+        } else {
+            self.remaining -= n;
+            (n, GeneratorResult::Stopped)
+        }
+    }
+}
+
+impl<T: Clone> ReverseGenerator for RepeatN<T> {
+    #[inline]
+    fn run_back(&mut self, output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        // All values are the same, so generating from the back is the same as from the front.
+        self.run(output)
+    }
+
+    #[inline]
+    fn try_advance_back(&mut self, n: NonZeroUsize) -> (usize, GeneratorResult) {
+        self.try_advance(n)
+    }
+}
+
+impl<T: Clone> FusedGenerator for RepeatN<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GeneratorExt;
+
+    #[test]
+    fn repeat_n_sum() {
+        assert_eq!(repeat_n(7, 3).sum::<i32>(), 21);
+        assert_eq!(repeat_n(7, 0).sum::<i32>(), 0);
+    }
+
+    #[test]
+    fn stop_and_resume() {
+        let mut gen = repeat_n(1, 3);
+        assert_eq!(gen.next(), Ok(1));
+        let mut output = Vec::new();
+        assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Complete);
+        assert_eq!(output, [1, 1]);
+        assert_eq!(gen.next(), Err(GeneratorResult::Complete));
+    }
+
+    #[test]
+    fn try_advance() {
+        let mut gen = repeat_n(1, 5);
+        assert_eq!(
+            gen.try_advance(NonZeroUsize::new(2).unwrap()),
+            (2, GeneratorResult::Stopped)
+        );
+        assert_eq!(
+            gen.try_advance(NonZeroUsize::new(10).unwrap()),
+            (3, GeneratorResult::Complete)
+        );
+        assert_eq!(gen.next(), Err(GeneratorResult::Complete));
+    }
+}
@@ -2,6 +2,7 @@ use crate::{Generator, GeneratorResult, ReverseGenerator, ValueResult};
 use core::num::NonZeroUsize;
 
 /// Reverses a generators direction. See [`rev()`](crate::GeneratorExt::rev) for details.
+#[derive(Clone)]
 pub struct Reverse<Src> {
     source: Src,
 }
@@ -62,4 +63,18 @@ mod tests {
         assert_eq!(gen.next(), Err(GeneratorResult::Complete));
         assert_eq!(gen.next_back(), Err(GeneratorResult::Complete));
     }
+
+    #[test]
+    fn reversed_pipeline() {
+        let data = [1, 2, 3, 4, 5, 6];
+        let output: Vec<_> = SliceGenerator::new(&data)
+            .map(|x| x * 10)
+            .filter(|x| x % 20 == 0)
+            .rev()
+            .collect();
+        assert_eq!(output, [60, 40, 20]);
+
+        let twice: Vec<_> = SliceGenerator::new(&data).rev().rev().collect();
+        assert_eq!(twice, [&1, &2, &3, &4, &5, &6]);
+    }
 }
@@ -0,0 +1,200 @@
+use crate::structs::utility::set_some;
+use crate::structs::{repeat_n, DedupWithCount, RepeatN};
+use crate::{Generator, GeneratorResult, ValueResult};
+
+/// Run-length encodes values. See [`.rle()`](crate::GeneratorExt::rle) for details.
+pub struct Rle<Src>
+where
+    Src: Generator,
+{
+    runs: DedupWithCount<Src>,
+}
+
+impl<Src> Rle<Src>
+where
+    Src: Generator,
+    Src::Output: PartialEq,
+{
+    #[inline]
+    pub(crate) fn new(source: Src) -> Self {
+        Self {
+            runs: DedupWithCount::new(source),
+        }
+    }
+}
+
+impl<Src> Clone for Rle<Src>
+where
+    Src: Generator + Clone,
+    Src::Output: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            runs: self.runs.clone(),
+        }
+    }
+}
+
+impl<Src> Generator for Rle<Src>
+where
+    Src: Generator,
+    Src::Output: PartialEq,
+{
+    type Output = (Src::Output, usize);
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        self.runs
+            .run(|(run_length, value)| output((value, run_length)))
+    }
+}
+
+/// Run-length decodes `(value, count)` pairs. See [`.rld()`](crate::GeneratorExt::rld) for
+/// details.
+#[derive(Clone)]
+pub struct Rld<Src, T> {
+    source: Src,
+    current: Option<RepeatN<T>>,
+}
+
+impl<Src, T> Rld<Src, T> {
+    #[inline]
+    pub(crate) fn new(source: Src) -> Self {
+        Self {
+            source,
+            current: None,
+        }
+    }
+}
+
+impl<Src, T> Generator for Rld<Src, T>
+where
+    Src: Generator<Output = (T, usize)>,
+    T: Clone,
+{
+    type Output = T;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        // Finish a run that was interrupted by the consumer.
+        if let Some(current) = self.current.as_mut() {
+            if current.run(&mut output) == GeneratorResult::Stopped {
+                return GeneratorResult::Stopped;
+            }
+            self.current = None;
+        }
+
+        let current = &mut self.current;
+        self.source.run(|(value, count)| {
+            match set_some(current, repeat_n(value, count)).run(&mut output) {
+                GeneratorResult::Stopped => ValueResult::Stop,
+                GeneratorResult::Complete => {
+                    *current = None;
+                    ValueResult::MoreValues
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::{collect_one_at_a_time, MultiStoppingGen, StoppingGen};
+    use crate::{Generator, GeneratorExt, GeneratorResult, IntoGenerator, ValueResult};
+
+    #[test]
+    fn rle() {
+        let data = ['a', 'a', 'b', 'c', 'c', 'c'];
+        let output: Vec<_> = data.into_gen().rle().collect();
+        assert_eq!(output, [('a', 2), ('b', 1), ('c', 3)]);
+
+        let empty: [i32; 0] = [];
+        assert_eq!(empty.into_gen().rle().count(), 0);
+    }
+
+    #[test]
+    fn clone_mid_run() {
+        // Stop after the first run has been generated and the second one has been started.
+        let data = [
+            Some('a'),
+            Some('a'),
+            Some('b'),
+            None,
+            Some('b'),
+            Some('b'),
+            Some('c'),
+        ];
+        let mut gen = MultiStoppingGen::new(&data).rle();
+        let mut output = Vec::new();
+        assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Stopped);
+        assert_eq!(output, [(&'a', 2)]);
+
+        // The clone continues counting the pending run independently of the original.
+        let cloned = gen.clone();
+        assert_eq!(gen.next(), Ok((&'b', 3)));
+        let output: Vec<_> = cloned.collect();
+        assert_eq!(output, [(&'b', 3), (&'c', 1)]);
+        let output: Vec<_> = gen.collect();
+        assert_eq!(output, [(&'c', 1)]);
+    }
+
+    #[test]
+    fn consumer_stop() {
+        let data = [1, 1, 2, 3, 3];
+        let mut gen = data.into_gen().rle();
+        let output = collect_one_at_a_time(&mut gen);
+        assert_eq!(output, [(1, 2), (2, 1), (3, 2)]);
+        assert_eq!(
+            gen.run(|_| ValueResult::MoreValues),
+            GeneratorResult::Complete
+        );
+    }
+
+    #[test]
+    fn rld() {
+        let data = [('a', 2), ('b', 0), ('c', 3)];
+        let output: String = data.into_gen().rld().collect();
+        assert_eq!(output, "aaccc");
+
+        let data = [1, 1, 1, 2, 3, 3, 4];
+        let decoded: Vec<_> = data.into_gen().rle().rld().collect();
+        assert_eq!(decoded, data);
+    }
+
+    #[test]
+    fn rld_consumer_stop() {
+        let data = [(1, 2), (2, 1), (3, 3)];
+        let mut gen = data.into_gen().rld();
+        let output = collect_one_at_a_time(&mut gen);
+        assert_eq!(output, [1, 1, 2, 3, 3, 3]);
+        assert_eq!(
+            gen.run(|_| ValueResult::MoreValues),
+            GeneratorResult::Complete
+        );
+    }
+
+    #[test]
+    fn rld_spuriously_stopping() {
+        let data = [(1, 2), (2, 1), (3, 3)];
+        for x in 0..data.len() {
+            let mut gen = StoppingGen::new(x as i32, &data).copied().rld();
+            let mut output = Vec::new();
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Stopped);
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Complete);
+            assert_eq!(output, [1, 1, 2, 3, 3, 3]);
+        }
+    }
+
+    #[test]
+    fn spuriously_stopping() {
+        let data = [1, 1, 2, 3, 3, 3];
+        for x in 0..data.len() {
+            let mut gen = StoppingGen::new(x as i32, &data).copied().rle();
+            let mut output = Vec::new();
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Stopped);
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Complete);
+            assert_eq!(output, [(1, 2), (2, 1), (3, 3)]);
+        }
+    }
+}
@@ -0,0 +1,247 @@
+use crate::{structs::utility::set_some, Generator, GeneratorResult, ValueResult};
+use core::ops::{Add, Sub};
+use std::collections::VecDeque;
+
+/// Statistics of one window, generated by [`.rolling()`](crate::GeneratorExt::rolling).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RollingStats<T> {
+    sum: T,
+    min: T,
+    max: T,
+    len: usize,
+}
+
+impl<T> RollingStats<T> {
+    /// The sum of the values in the window.
+    #[inline]
+    pub fn sum(&self) -> &T {
+        &self.sum
+    }
+
+    /// The smallest value in the window.
+    #[inline]
+    pub fn min(&self) -> &T {
+        &self.min
+    }
+
+    /// The largest value in the window.
+    #[inline]
+    pub fn max(&self) -> &T {
+        &self.max
+    }
+
+    /// The number of values in the window.
+    #[inline]
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// The mean of the values in the window.
+    ///
+    /// This is only available for types that convert losslessly into `f64`, which excludes `i64`,
+    /// `u64` and `usize`. For those, divide [`sum()`](RollingStats::sum) by
+    /// [`len()`](RollingStats::len) instead.
+    #[inline]
+    pub fn mean(&self) -> f64
+    where
+        T: Clone + Into<f64>,
+    {
+        self.sum.clone().into() / self.len as f64
+    }
+}
+
+/// Statistics over a sliding window. See [`.rolling()`](crate::GeneratorExt::rolling) for details.
+#[derive(Clone)]
+pub struct Rolling<Src>
+where
+    Src: Generator,
+{
+    source: Src,
+    size: usize,
+    window: VecDeque<Src::Output>,
+    sum: Option<Src::Output>,
+    // Candidates for the minimum and maximum of the current and future windows, with the index of
+    // the value. The front is the minimum (maximum) of the current window.
+    min: VecDeque<(usize, Src::Output)>,
+    max: VecDeque<(usize, Src::Output)>,
+    index: usize,
+}
+
+impl<Src> Rolling<Src>
+where
+    Src: Generator,
+{
+    #[inline]
+    pub(crate) fn new(source: Src, size: usize) -> Self {
+        if size == 0 {
+            panic!("Window size must not be 0");
+        }
+        Self {
+            source,
+            size,
+            window: VecDeque::new(),
+            sum: None,
+            min: VecDeque::new(),
+            max: VecDeque::new(),
+            index: 0,
+        }
+    }
+}
+
+impl<Src> Generator for Rolling<Src>
+where
+    Src: Generator,
+    Src::Output: Add<Output = Src::Output> + Sub<Output = Src::Output> + PartialOrd + Clone,
+{
+    type Output = RollingStats<Src::Output>;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        let size = self.size;
+        let window = &mut self.window;
+        let sum = &mut self.sum;
+        let min = &mut self.min;
+        let max = &mut self.max;
+        let index = &mut self.index;
+        self.source.run(|x| {
+            let current = *index;
+            *index += 1;
+
+            while matches!(min.back(), Some((_, v)) if *v >= x) {
+                min.pop_back();
+            }
+            min.push_back((current, x.clone()));
+            while matches!(max.back(), Some((_, v)) if *v <= x) {
+                max.pop_back();
+            }
+            max.push_back((current, x.clone()));
+
+            let leaving = if window.len() == size {
+                window.pop_front()
+            } else {
+                None
+            };
+            window.push_back(x.clone());
+            // Subtract the leaving value first, so the running sum never exceeds a window sum.
+            let new_sum = match (sum.take(), leaving) {
+                (Some(prev), Some(leaving)) => prev - leaving + x,
+                (Some(prev), None) => prev + x,
+                (None, _) => x,
+            };
+            let new_sum = set_some(sum, new_sum);
+            if window.len() < size {
+                return ValueResult::MoreValues;
+            }
+
+            // Drop the candidates that have left the window.
+            let first = current + 1 - size;
+            while matches!(min.front(), Some((i, _)) if *i < first) {
+                min.pop_front();
+            }
+            while matches!(max.front(), Some((i, _)) if *i < first) {
+                max.pop_front();
+            }
+
+            match (min.front(), max.front()) {
+                (Some((_, min)), Some((_, max))) => output(RollingStats {
+                    sum: new_sum.clone(),
+                    min: min.clone(),
+                    max: max.clone(),
+                    len: size,
+                }),
+                _ => unreachable!(),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::StoppingGen;
+    use crate::{GeneratorExt, GeneratorResult, IntoGenerator};
+
+    #[test]
+    fn rolling() {
+        let data = [3, 1, 4, 1, 5, 9, 2, 6];
+        let stats: Vec<_> = data.into_gen().rolling(3).collect();
+        let expected: Vec<_> = data
+            .windows(3)
+            .map(|w| {
+                (
+                    w.iter().sum::<i32>(),
+                    *w.iter().min().unwrap(),
+                    *w.iter().max().unwrap(),
+                )
+            })
+            .collect();
+        let actual: Vec<_> = stats
+            .iter()
+            .map(|s| (*s.sum(), *s.min(), *s.max()))
+            .collect();
+        assert_eq!(actual, expected);
+        assert!(stats.iter().all(|s| s.len() == 3));
+
+        assert_eq!(data.into_gen().rolling(9).count(), 0);
+        assert_eq!(data.into_gen().rolling(usize::MAX).count(), 0);
+    }
+
+    #[test]
+    fn mean() {
+        let data = [1.0, 2.0, 3.0, 4.0];
+        let means: Vec<_> = data.into_gen().rolling(2).map(|s| s.mean()).collect();
+        assert_eq!(means, [1.5, 2.5, 3.5]);
+    }
+
+    #[test]
+    fn stats_at_type_limits() {
+        let data = [100i8, 27, 100, -128, 127];
+        let stats: Vec<_> = data
+            .into_gen()
+            .rolling(2)
+            .map(|s| (*s.sum(), *s.min(), *s.max()))
+            .collect();
+        assert_eq!(
+            stats,
+            [
+                (127, 27, 100),
+                (127, 27, 100),
+                (-28, -128, 100),
+                (-1, -128, 127)
+            ]
+        );
+    }
+
+    #[test]
+    fn min_and_max_leave_the_window() {
+        let data = [5, 4, 3, 2, 1, 2, 3, 4];
+        let stats: Vec<_> = data
+            .into_gen()
+            .rolling(3)
+            .map(|s| (*s.min(), *s.max()))
+            .collect();
+        assert_eq!(stats, [(3, 5), (2, 4), (1, 3), (1, 2), (1, 3), (2, 4)]);
+    }
+
+    #[test]
+    fn spuriously_stopping() {
+        let data = [3, 1, 4, 1, 5];
+        for x in 0..data.len() {
+            let mut gen = StoppingGen::new(x as i32, &data)
+                .copied()
+                .rolling(2)
+                .map(|s| (*s.sum(), *s.min(), *s.max()));
+            let mut output = Vec::new();
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Stopped);
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Complete);
+            assert_eq!(output, [(4, 1, 3), (5, 1, 4), (5, 1, 4), (6, 1, 5)]);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_size() {
+        let data = [1, 2, 3];
+        let _gen = data.into_gen().rolling(0);
+    }
+}
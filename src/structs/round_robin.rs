@@ -0,0 +1,142 @@
+use crate::{FusedGenerator, Generator, GeneratorExt, GeneratorResult, IntoGenerator, ValueResult};
+
+/// Interleaves any number of generators, taking one value from each of them in turn.
+///
+/// Each item of `generators` may be anything implementing [`IntoGenerator`]. Generators that
+/// complete are dropped from the rotation, and the remaining generators keep their turns until all
+/// of them have completed.
+///
+/// ## Spuriously stopping generators
+///
+/// If the generator whose turn it is stops, the round robin stops too. The next run resumes with
+/// the same generator, so no generator loses its turn.
+///
+/// ## Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use pushgen::GeneratorExt;
+/// let generators = vec![vec![1, 4, 6], vec![2], vec![3, 5]];
+/// let output: Vec<_> = pushgen::round_robin(generators).collect();
+/// assert_eq!(output, [1, 2, 3, 4, 5, 6]);
+/// ```
+#[inline]
+pub fn round_robin<I>(generators: I) -> RoundRobin<<I::Item as IntoGenerator>::IntoGen>
+where
+    I: IntoIterator,
+    I::Item: IntoGenerator,
+{
+    RoundRobin {
+        generators: generators
+            .into_iter()
+            .map(IntoGenerator::into_gen)
+            .collect(),
+        index: 0,
+    }
+}
+
+/// A generator that interleaves generators in rotating order.
+///
+/// This `struct` is created by the [`round_robin()`] function.
+/// See its documentation for more.
+///
+/// [`round_robin()`]: crate::round_robin
+#[derive(Clone)]
+pub struct RoundRobin<G> {
+    generators: Vec<G>,
+    /// The generator whose turn it is.
+    index: usize,
+}
+
+impl<G> Generator for RoundRobin<G>
+where
+    G: Generator,
+{
+    type Output = G::Output;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        loop {
+            if self.index >= self.generators.len() {
+                if self.generators.is_empty() {
+                    return GeneratorResult::Complete;
+                }
+                self.index = 0;
+            }
+
+            match self.generators[self.index].next() {
+                Ok(value) => {
+                    self.index += 1;
+                    if output(value) == ValueResult::Stop {
+                        return GeneratorResult::Stopped;
+                    }
+                }
+                // Removing keeps the order of the rotation, and `index` now refers to the next
+                // generator.
+                Err(GeneratorResult::Complete) => {
+                    self.generators.remove(self.index);
+                }
+                Err(GeneratorResult::Stopped) => return GeneratorResult::Stopped,
+            }
+        }
+    }
+}
+
+impl<G> FusedGenerator for RoundRobin<G> where G: Generator {}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::{collect_one_at_a_time, MultiStoppingGen, StoppingGen};
+    use crate::{GeneratorExt, GeneratorResult, IntoGenerator};
+
+    #[test]
+    fn round_robin() {
+        let generators = vec![vec![1, 4, 7, 9], vec![], vec![2, 5], vec![3, 6, 8]];
+        let output: Vec<_> = super::round_robin(generators).collect();
+        assert_eq!(output, [1, 2, 3, 4, 5, 6, 7, 8, 9]);
+
+        let empty: Vec<Vec<i32>> = Vec::new();
+        assert_eq!(
+            super::round_robin(empty).next(),
+            Err(GeneratorResult::Complete)
+        );
+    }
+
+    #[test]
+    fn consumer_stop() {
+        let generators = vec![[1, 3].into_gen(), [2, 4].into_gen()];
+        let mut gen = super::round_robin(generators);
+        let output = collect_one_at_a_time(&mut gen);
+        assert_eq!(output, [1, 2, 3, 4]);
+        assert_eq!(gen.next(), Err(GeneratorResult::Complete));
+    }
+
+    #[test]
+    fn spuriously_stopping() {
+        let left = [1, 3, 5];
+        let right = [2, 4];
+        for x in 0..left.len() {
+            let generators = vec![
+                StoppingGen::new(x as i32, &left),
+                StoppingGen::new(-1, &right),
+            ];
+            let mut gen = super::round_robin(generators);
+            let mut output = Vec::new();
+            assert_eq!(gen.for_each(|x| output.push(*x)), GeneratorResult::Stopped);
+            assert_eq!(gen.for_each(|x| output.push(*x)), GeneratorResult::Complete);
+            assert_eq!(output, [1, 2, 3, 4, 5]);
+        }
+    }
+
+    #[test]
+    fn multi_stopping() {
+        let left = [None, Some(1), None, Some(3)];
+        let right = [Some(2), None, None, Some(4), Some(6)];
+        let generators = vec![MultiStoppingGen::new(&left), MultiStoppingGen::new(&right)];
+        let mut gen = super::round_robin(generators);
+        let mut output = Vec::new();
+        while gen.for_each(|x| output.push(*x)) == GeneratorResult::Stopped {}
+        assert_eq!(output, [1, 2, 3, 4, 6]);
+    }
+}
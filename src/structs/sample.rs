@@ -0,0 +1,100 @@
+use crate::{FusedGenerator, Generator, GeneratorResult, ValueResult};
+use rand::distributions::{Bernoulli, Distribution};
+use rand::Rng;
+
+/// A generator that randomly forwards values of its source. See
+/// [`.sample()`](crate::GeneratorExt::sample) for details.
+#[derive(Clone)]
+pub struct Sample<Src, R> {
+    source: Src,
+    rng: R,
+    distribution: Bernoulli,
+}
+
+impl<Src, R> Sample<Src, R> {
+    #[inline]
+    pub(crate) fn new(source: Src, p: f64, rng: R) -> Self {
+        let distribution = match Bernoulli::new(p) {
+            Ok(distribution) => distribution,
+            Err(_) => panic!("Sample probability must be in the range [0, 1], got {}", p),
+        };
+        Self {
+            source,
+            rng,
+            distribution,
+        }
+    }
+}
+
+impl<Src, R> Generator for Sample<Src, R>
+where
+    Src: Generator,
+    R: Rng,
+{
+    type Output = Src::Output;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        let rng = &mut self.rng;
+        let distribution = &self.distribution;
+        self.source.run(|x| {
+            if distribution.sample(rng) {
+                output(x)
+            } else {
+                ValueResult::MoreValues
+            }
+        })
+    }
+}
+
+impl<Src, R> FusedGenerator for Sample<Src, R>
+where
+    Src: FusedGenerator,
+    R: Rng,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::StoppingGen;
+    use crate::{GeneratorExt, GeneratorResult, IntoGenerator};
+    use rand::rngs::mock::StepRng;
+
+    #[test]
+    fn sample_all_or_nothing() {
+        let data = [1, 2, 3, 4, 5];
+        let rng = StepRng::new(0, 0x1234_5678_9abc_def1);
+
+        let output: Vec<_> = data.into_gen().sample(1.0, rng.clone()).collect();
+        assert_eq!(output, data);
+
+        let output: Vec<_> = data.into_gen().sample(0.0, rng).collect();
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn sample_rate() {
+        // Steps through the whole range of `u64` in 1000 evenly spaced values.
+        let rng = StepRng::new(0, u64::MAX / 1000 + 1);
+        let count = crate::from_iter(0..1000).sample(0.25, rng).count();
+        assert!((240..=260).contains(&count), "{}", count);
+    }
+
+    #[test]
+    fn spuriously_stopping() {
+        let data = [1, 2, 3, 4];
+        for x in 0..data.len() {
+            let mut gen = StoppingGen::new(x as i32, &data).sample(1.0, StepRng::new(0, 1));
+            let mut output = Vec::new();
+            assert_eq!(gen.for_each(|x| output.push(*x)), GeneratorResult::Stopped);
+            assert_eq!(gen.for_each(|x| output.push(*x)), GeneratorResult::Complete);
+            assert_eq!(output, data);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_probability() {
+        let _gen = [1].into_gen().sample(1.5, StepRng::new(0, 1));
+    }
+}
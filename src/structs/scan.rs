@@ -39,6 +39,7 @@ where
 
 #[cfg(test)]
 mod tests {
+    use crate::test::StoppingGen;
     use crate::{GeneratorExt, GeneratorResult, SliceGenerator};
 
     #[test]
@@ -72,4 +73,19 @@ mod tests {
         assert_eq!(gen.next(), Ok(&4));
         assert_eq!(gen.next(), Err(GeneratorResult::Complete))
     }
+
+    #[test]
+    fn spuriously_stopping() {
+        let data = [1, 2, 3, 4];
+        for x in 0..4 {
+            let mut gen = StoppingGen::new(x, &data).scan(0, |sum, value| {
+                *sum += *value;
+                Some(*sum)
+            });
+            let mut output = Vec::new();
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Stopped);
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Complete);
+            assert_eq!(output, [1, 3, 6, 10]);
+        }
+    }
 }
@@ -1,4 +1,5 @@
-use crate::{Generator, GeneratorResult, ValueResult};
+use crate::structs::utility::non_zero;
+use crate::{Generator, GeneratorResult, ReverseGenerator, ValueResult};
 use core::num::NonZeroUsize;
 
 /// Skip over a set amount of values. See [`.skip()`](crate::GeneratorExt::skip) for more details.
@@ -15,61 +16,89 @@ impl<Gen> Skip<Gen> {
     }
 }
 
-impl<Gen> Generator for Skip<Gen>
+impl<Gen> Skip<Gen>
 where
     Gen: Generator,
 {
-    type Output = Gen::Output;
-
+    /// Skips the values that are still left to skip at the front of the source generator.
+    ///
+    /// Returns the result to report if not all values could be skipped.
     #[inline]
-    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+    fn skip_front(&mut self) -> Option<GeneratorResult> {
         if self.amount > 0 {
             // Safety: checked by if clause
-            match self
-                .generator
-                .try_advance(unsafe { core::num::NonZeroUsize::new_unchecked(self.amount) })
-            {
+            #[cfg(not(feature = "safe"))]
+            let amount = unsafe { non_zero(self.amount) };
+            #[cfg(feature = "safe")]
+            let amount = non_zero(self.amount);
+            match self.generator.try_advance(amount) {
                 (_, GeneratorResult::Complete) => {
                     self.amount = 0;
-                    return GeneratorResult::Complete;
+                    return Some(GeneratorResult::Complete);
                 }
                 (x, _) => {
                     self.amount -= x;
                     if self.amount != 0 {
-                        return GeneratorResult::Stopped;
+                        return Some(GeneratorResult::Stopped);
                     }
                 }
             }
         }
+        None
+    }
+}
+
+impl<Gen> Generator for Skip<Gen>
+where
+    Gen: Generator,
+{
+    type Output = Gen::Output;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        if let Some(result) = self.skip_front() {
+            return result;
+        }
 
         self.generator.run(|value| output(value))
     }
 
     #[inline]
     fn try_advance(&mut self, n: NonZeroUsize) -> (usize, GeneratorResult) {
-        if self.amount > 0 {
-            match self
-                .generator
-                .try_advance(unsafe { NonZeroUsize::new_unchecked(self.amount) })
-            {
-                (_, GeneratorResult::Complete) => {
-                    self.amount = 0;
-                    return (0, GeneratorResult::Complete);
-                }
-                (x, _) => {
-                    self.amount -= x;
-                    if self.amount != 0 {
-                        return (0, GeneratorResult::Stopped);
-                    }
-                }
-            }
+        if let Some(result) = self.skip_front() {
+            return (0, result);
         }
 
         self.generator.try_advance(n)
     }
 }
 
-/// Skip over of values based on a closure. See [`.skip()`](crate::GeneratorExt::skip_while) for more details.
+impl<Gen> ReverseGenerator for Skip<Gen>
+where
+    Gen: ReverseGenerator,
+{
+    // Forward and reverse generation never cross, so the skipped values are removed from the
+    // front before any value is generated from the back.
+    #[inline]
+    fn run_back(&mut self, output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        if let Some(result) = self.skip_front() {
+            return result;
+        }
+
+        self.generator.run_back(output)
+    }
+
+    #[inline]
+    fn try_advance_back(&mut self, n: NonZeroUsize) -> (usize, GeneratorResult) {
+        if let Some(result) = self.skip_front() {
+            return (0, result);
+        }
+
+        self.generator.try_advance_back(n)
+    }
+}
+
+/// Skip over of values based on a closure. See [`.skip_while()`](crate::GeneratorExt::skip_while) for more details.
 #[derive(Clone)]
 pub struct SkipWhile<Src, P> {
     source: Src,
@@ -185,6 +214,43 @@ mod tests {
         }
     }
 
+    #[test]
+    fn skip_while_consumer_stop() {
+        let data = [-1i32, -2, 0, -1, 2];
+        for stop_after in 1..4 {
+            let mut gen = data.into_gen().skip_while(|x| x.is_negative());
+            let mut output = Vec::new();
+            let result = gen.run(|x| {
+                output.push(x);
+                (output.len() < stop_after).into()
+            });
+            assert_eq!(result, GeneratorResult::Stopped);
+            // The predicate is not applied again after the first value was forwarded.
+            let result = gen.for_each(|x| output.push(x));
+            assert_eq!(result, GeneratorResult::Complete);
+            assert_eq!(output, [0, -1, 2]);
+        }
+    }
+
+    #[test]
+    fn skip_reverse() {
+        let data = [0, 1, 2, 3, 4, 5];
+        let output: Vec<_> = data.into_gen().skip(2).rev().collect();
+        assert_eq!(output, [5, 4, 3, 2]);
+
+        let output: Vec<_> = data.into_gen().skip(7).rev().collect();
+        assert!(output.is_empty());
+
+        let mut gen = data.into_gen().skip(2);
+        assert_eq!(gen.next_back(), Ok(5));
+        assert_eq!(
+            gen.try_advance_back(NonZeroUsize::new(2).unwrap()),
+            (2, GeneratorResult::Stopped)
+        );
+        assert_eq!(gen.next(), Ok(2));
+        assert_eq!(gen.next_back(), Err(GeneratorResult::Complete));
+    }
+
     #[test]
     fn try_advance() {
         let data = [0, 1, 2, 3, 4, 5, 6, 7, 8];
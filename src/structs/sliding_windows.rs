@@ -0,0 +1,124 @@
+use crate::{Generator, GeneratorResult, ValueResult};
+
+/// Generates `Vec` windows of a fixed size, advancing by a fixed stride. See
+/// [`.sliding_windows()`](crate::GeneratorExt::sliding_windows) for details.
+#[derive(Clone)]
+pub struct SlidingWindows<Src>
+where
+    Src: Generator,
+{
+    source: Src,
+    size: usize,
+    stride: usize,
+    window: Vec<Src::Output>,
+    // Values between two windows that are skipped when `stride > size`.
+    to_skip: usize,
+}
+
+impl<Src> SlidingWindows<Src>
+where
+    Src: Generator,
+{
+    #[inline]
+    pub(crate) fn new(source: Src, size: usize, stride: usize) -> Self {
+        if size == 0 {
+            panic!("Window size must not be 0");
+        }
+        if stride == 0 {
+            panic!("Window stride must not be 0");
+        }
+        Self {
+            source,
+            size,
+            stride,
+            window: Vec::with_capacity(size),
+            to_skip: 0,
+        }
+    }
+}
+
+impl<Src> Generator for SlidingWindows<Src>
+where
+    Src: Generator,
+    Src::Output: Clone,
+{
+    type Output = Vec<Src::Output>;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        let (size, stride) = (self.size, self.stride);
+        let window = &mut self.window;
+        let to_skip = &mut self.to_skip;
+        self.source.run(|x| {
+            if *to_skip > 0 {
+                *to_skip -= 1;
+                return ValueResult::MoreValues;
+            }
+
+            window.push(x);
+            if window.len() < size {
+                return ValueResult::MoreValues;
+            }
+
+            let full = if stride >= size {
+                *to_skip = stride - size;
+                core::mem::replace(window, Vec::with_capacity(size))
+            } else {
+                let full = window.clone();
+                window.drain(..stride);
+                full
+            };
+            output(full)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::StoppingGen;
+    use crate::{GeneratorExt, GeneratorResult, IntoGenerator};
+
+    fn windows(data: &[i32], size: usize, stride: usize) -> Vec<Vec<i32>> {
+        data.into_gen()
+            .copied()
+            .sliding_windows(size, stride)
+            .collect()
+    }
+
+    #[test]
+    fn sliding_windows() {
+        let data = [1, 2, 3, 4, 5, 6, 7];
+        assert_eq!(
+            windows(&data, 3, 2),
+            [vec![1, 2, 3], vec![3, 4, 5], vec![5, 6, 7]]
+        );
+
+        let expected: Vec<Vec<i32>> = data.windows(3).map(|w| w.to_vec()).collect();
+        assert_eq!(windows(&data, 3, 1), expected);
+
+        assert_eq!(windows(&data, 2, 2), [vec![1, 2], vec![3, 4], vec![5, 6]]);
+        assert_eq!(windows(&data, 2, 3), [vec![1, 2], vec![4, 5]]);
+        assert!(windows(&data, 8, 1).is_empty());
+    }
+
+    #[test]
+    fn spuriously_stopping() {
+        let data = [1, 2, 3, 4, 5, 6, 7];
+        for x in 0..data.len() {
+            let mut gen = StoppingGen::new(x as i32, &data)
+                .copied()
+                .sliding_windows(3, 2);
+            let mut output = Vec::new();
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Stopped);
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Complete);
+            assert_eq!(output, [vec![1, 2, 3], vec![3, 4, 5], vec![5, 6, 7]]);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_stride() {
+        let data = [1, 2, 3];
+        let _gen = data.into_gen().sliding_windows(2, 0);
+    }
+}
@@ -0,0 +1,206 @@
+use crate::{Generator, GeneratorResult, ValueResult};
+
+/// Splits values into segments separated by delimiters. See [`.split_on()`](crate::GeneratorExt::split_on) for details.
+#[derive(Clone)]
+pub struct SplitOn<Src, F>
+where
+    Src: Generator,
+{
+    source: Src,
+    is_delimiter: F,
+    // `None` once the final segment has been generated.
+    current: Option<Vec<Src::Output>>,
+}
+
+impl<Src, F> SplitOn<Src, F>
+where
+    Src: Generator,
+    F: FnMut(&Src::Output) -> bool,
+{
+    #[inline]
+    pub(crate) fn new(source: Src, is_delimiter: F) -> Self {
+        Self {
+            source,
+            is_delimiter,
+            current: Some(Vec::new()),
+        }
+    }
+}
+
+impl<Src, F> Generator for SplitOn<Src, F>
+where
+    Src: Generator,
+    F: FnMut(&Src::Output) -> bool,
+{
+    type Output = Vec<Src::Output>;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        let current = match &mut self.current {
+            Some(current) => current,
+            None => return GeneratorResult::Complete,
+        };
+        let is_delimiter = &mut self.is_delimiter;
+        let result = self.source.run(|x| {
+            if is_delimiter(&x) {
+                output(core::mem::take(current))
+            } else {
+                current.push(x);
+                ValueResult::MoreValues
+            }
+        });
+
+        if result == GeneratorResult::Complete {
+            if let Some(last) = self.current.take() {
+                if output(last) == ValueResult::Stop {
+                    return GeneratorResult::Stopped;
+                }
+            }
+        }
+
+        result
+    }
+}
+
+/// Splits values into segments that each start at a value matching a predicate. See
+/// [`.split_when()`](crate::GeneratorExt::split_when) for details.
+#[derive(Clone)]
+pub struct SplitWhen<Src, F>
+where
+    Src: Generator,
+{
+    source: Src,
+    starts_segment: F,
+    current: Vec<Src::Output>,
+}
+
+impl<Src, F> SplitWhen<Src, F>
+where
+    Src: Generator,
+    F: FnMut(&Src::Output) -> bool,
+{
+    #[inline]
+    pub(crate) fn new(source: Src, starts_segment: F) -> Self {
+        Self {
+            source,
+            starts_segment,
+            current: Vec::new(),
+        }
+    }
+}
+
+impl<Src, F> Generator for SplitWhen<Src, F>
+where
+    Src: Generator,
+    F: FnMut(&Src::Output) -> bool,
+{
+    type Output = Vec<Src::Output>;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        let current = &mut self.current;
+        let starts_segment = &mut self.starts_segment;
+        let result = self.source.run(|x| {
+            if starts_segment(&x) && !current.is_empty() {
+                let segment = core::mem::replace(current, vec![x]);
+                output(segment)
+            } else {
+                current.push(x);
+                ValueResult::MoreValues
+            }
+        });
+
+        if result == GeneratorResult::Complete
+            && !self.current.is_empty()
+            && output(core::mem::take(&mut self.current)) == ValueResult::Stop
+        {
+            return GeneratorResult::Stopped;
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::StoppingGen;
+    use crate::{GeneratorExt, GeneratorResult, IntoGenerator};
+
+    fn split(data: &[i32]) -> Vec<Vec<i32>> {
+        data.into_gen().copied().split_on(|x| *x == 0).collect()
+    }
+
+    #[test]
+    fn split_on() {
+        let data = [1, 2, 0, 3, 0, 4, 5];
+        let expected: Vec<Vec<i32>> = data.split(|x| *x == 0).map(|s| s.to_vec()).collect();
+        assert_eq!(split(&data), expected);
+        assert_eq!(split(&data), [vec![1, 2], vec![3], vec![4, 5]]);
+    }
+
+    #[test]
+    fn leading_and_trailing_delimiters() {
+        let data = [0, 1, 0];
+        assert_eq!(split(&data), [vec![], vec![1], vec![]]);
+
+        let empty: [i32; 0] = [];
+        assert_eq!(split(&empty), [Vec::<i32>::new()]);
+    }
+
+    #[test]
+    fn adjacent_delimiters() {
+        let data = [1, 0, 0, 2];
+        let expected: Vec<Vec<i32>> = data.split(|x| *x == 0).map(|s| s.to_vec()).collect();
+        assert_eq!(split(&data), expected);
+        assert_eq!(split(&data), [vec![1], vec![], vec![2]]);
+    }
+
+    #[test]
+    fn completed_generator_stays_complete() {
+        let data = [1, 0, 2];
+        let mut gen = data.into_gen().split_on(|x| *x == 0);
+        assert_eq!(gen.next(), Ok(vec![1]));
+        assert_eq!(gen.next(), Ok(vec![2]));
+        assert_eq!(gen.next(), Err(GeneratorResult::Complete));
+        assert_eq!(gen.next(), Err(GeneratorResult::Complete));
+    }
+
+    fn split_when(data: &[i32]) -> Vec<Vec<i32>> {
+        data.into_gen().copied().split_when(|x| *x == 0).collect()
+    }
+
+    #[test]
+    fn split_when_starts_segments() {
+        assert_eq!(
+            split_when(&[1, 0, 2, 3, 0, 4]),
+            [vec![1], vec![0, 2, 3], vec![0, 4]]
+        );
+        assert_eq!(split_when(&[0, 1, 0]), [vec![0, 1], vec![0]]);
+        assert_eq!(split_when(&[0, 0]), [vec![0], vec![0]]);
+        assert!(split_when(&[]).is_empty());
+    }
+
+    #[test]
+    fn split_when_spuriously_stopping() {
+        let data = [0, 1, 2, 0, 3, 0];
+        for x in 0..data.len() {
+            let mut gen = StoppingGen::new(x as i32, &data).split_when(|x| **x == 0);
+            let mut output = Vec::new();
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Stopped);
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Complete);
+            assert_eq!(output, [vec![&0, &1, &2], vec![&0, &3], vec![&0]]);
+        }
+    }
+
+    #[test]
+    fn spuriously_stopping() {
+        let data = [1, 2, 0, 3, 0, 4, 5];
+        for x in 0..data.len() {
+            let mut gen = StoppingGen::new(x as i32, &data).split_on(|x| **x == 0);
+            let mut output = Vec::new();
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Stopped);
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Complete);
+            assert_eq!(output, [vec![&1, &2], vec![&3], vec![&4, &5]]);
+        }
+    }
+}
@@ -1,5 +1,5 @@
+use crate::structs::utility::non_zero;
 use crate::{Generator, GeneratorExt, GeneratorResult, ValueResult};
-use core::num::NonZeroUsize;
 
 /// Create a stepping generator. See [`step_by()`](crate::GeneratorExt::step_by) for details.
 pub struct StepBy<Src> {
@@ -12,13 +12,18 @@ pub struct StepBy<Src> {
 
 impl<Src> StepBy<Src> {
     pub(crate) fn new(source: Src, step_size: usize) -> Self {
+        Self::with_offset(source, 0, step_size)
+    }
+
+    pub(crate) fn with_offset(source: Src, offset: usize, step_size: usize) -> Self {
         if step_size == 0 {
             panic!("Step size must not be 0");
         }
         Self {
             source,
             advance_amount: step_size - 1,
-            amount_to_advance: 0,
+            // The initial offset is skipped the same way as any left-over advances.
+            amount_to_advance: offset,
         }
     }
 }
@@ -28,25 +33,27 @@ impl<Src: Generator> Generator for StepBy<Src> {
 
     #[inline]
     fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
-        if self.advance_amount == 0 {
-            self.source.run(output)
-        } else {
-            if self.amount_to_advance != 0 {
-                // There are left-over advances needed from previous runs.
-                // Safety: not zero
-                match self
-                    .source
-                    .try_advance(unsafe { NonZeroUsize::new_unchecked(self.amount_to_advance) })
-                {
-                    (_, GeneratorResult::Complete) => return GeneratorResult::Complete,
-                    (x, GeneratorResult::Stopped) => {
-                        if x != self.amount_to_advance {
-                            self.amount_to_advance -= x;
-                            return GeneratorResult::Stopped;
-                        }
+        if self.amount_to_advance != 0 {
+            // There are left-over advances needed from previous runs, or an initial offset.
+            // Safety: not zero
+            #[cfg(not(feature = "safe"))]
+            let amount = unsafe { non_zero(self.amount_to_advance) };
+            #[cfg(feature = "safe")]
+            let amount = non_zero(self.amount_to_advance);
+            match self.source.try_advance(amount) {
+                (_, GeneratorResult::Complete) => return GeneratorResult::Complete,
+                (x, GeneratorResult::Stopped) => {
+                    if x != self.amount_to_advance {
+                        self.amount_to_advance -= x;
+                        return GeneratorResult::Stopped;
                     }
                 }
             }
+        }
+        if self.advance_amount == 0 {
+            self.amount_to_advance = 0;
+            self.source.run(output)
+        } else {
             // Base case
             self.amount_to_advance = 0;
             loop {
@@ -63,10 +70,11 @@ impl<Src: Generator> Generator for StepBy<Src> {
                 }
 
                 // Safety: self.advance_amount is never 0
-                match self
-                    .source
-                    .try_advance(unsafe { NonZeroUsize::new_unchecked(self.advance_amount) })
-                {
+                #[cfg(not(feature = "safe"))]
+                let amount = unsafe { non_zero(self.advance_amount) };
+                #[cfg(feature = "safe")]
+                let amount = non_zero(self.advance_amount);
+                match self.source.try_advance(amount) {
                     (_, GeneratorResult::Complete) => return GeneratorResult::Complete,
                     (x, _) => {
                         if x != self.advance_amount {
@@ -86,6 +94,7 @@ mod tests {
     use super::*;
     use crate::test::{MultiStoppingGen, StoppingGen};
     use crate::{GeneratorExt, GeneratorResult, IntoGenerator};
+    use core::num::NonZeroUsize;
 
     #[test]
     fn basic_test() {
@@ -169,6 +178,73 @@ mod tests {
         assert_eq!(gen.next(), Ok(4));
     }
 
+    #[test]
+    fn step_by_from() {
+        let data = [0, 1, 2, 3, 4, 5];
+        let output: Vec<_> = data.into_gen().step_by_from(1, 2).collect();
+        assert_eq!(output, [1, 3, 5]);
+
+        let output: Vec<_> = data.into_gen().step_by_from(2, 1).collect();
+        assert_eq!(output, [2, 3, 4, 5]);
+
+        let output: Vec<_> = data.into_gen().step_by_from(7, 2).collect();
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn step_by_from_spuriously_stopping() {
+        let data = [0, 1, 2, 3, 4, 5];
+        for stop_at in 0..6 {
+            let mut output = Vec::new();
+            let mut gen = StepBy::with_offset(StoppingGen::new(stop_at, &data), 1, 2);
+
+            let result = gen.for_each(|x| output.push(x));
+            assert_eq!(result, GeneratorResult::Stopped);
+            let result = gen.for_each(|x| output.push(x));
+            assert_eq!(result, GeneratorResult::Complete);
+            assert_eq!(output, [&1, &3, &5]);
+        }
+    }
+
+    #[test]
+    fn skips_with_try_advance() {
+        // Counts the values that are pushed by `run`, skipped values must not be among them.
+        struct CountingGen<'a> {
+            source: crate::SliceGenerator<'a, i32>,
+            generated: usize,
+        }
+
+        impl<'a> Generator for CountingGen<'a> {
+            type Output = &'a i32;
+
+            fn run(
+                &mut self,
+                mut output: impl FnMut(Self::Output) -> ValueResult,
+            ) -> GeneratorResult {
+                let generated = &mut self.generated;
+                self.source.run(|x| {
+                    *generated += 1;
+                    output(x)
+                })
+            }
+
+            fn try_advance(&mut self, n: NonZeroUsize) -> (usize, GeneratorResult) {
+                self.source.try_advance(n)
+            }
+        }
+
+        let data = [0, 1, 2, 3, 4, 5, 6, 7, 8];
+        let mut gen = CountingGen {
+            source: crate::SliceGenerator::new(&data),
+            generated: 0,
+        }
+        .step_by(3);
+        let mut output = Vec::new();
+        assert_eq!(gen.for_each(|x| output.push(*x)), GeneratorResult::Complete);
+        assert_eq!(output, [0, 3, 6]);
+        assert_eq!(gen.source.generated, 3);
+    }
+
     #[test]
     #[should_panic]
     fn zero_step_size() {
@@ -0,0 +1,137 @@
+use crate::{FusedGenerator, Generator, GeneratorResult, ValueResult};
+use std::collections::VecDeque;
+
+/// A generator of the last values of its source. See [`.tail()`](crate::GeneratorExt::tail)
+/// for details.
+pub struct Tail<Src>
+where
+    Src: Generator,
+{
+    source: Src,
+    buffer: VecDeque<Src::Output>,
+    amount: usize,
+    source_complete: bool,
+}
+
+impl<Src> Tail<Src>
+where
+    Src: Generator,
+{
+    #[inline]
+    pub(crate) fn new(source: Src, amount: usize) -> Self {
+        Self {
+            source,
+            buffer: VecDeque::new(),
+            amount,
+            source_complete: false,
+        }
+    }
+}
+
+impl<Src> Clone for Tail<Src>
+where
+    Src: Generator + Clone,
+    Src::Output: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            source: self.source.clone(),
+            buffer: self.buffer.clone(),
+            amount: self.amount,
+            source_complete: self.source_complete,
+        }
+    }
+}
+
+impl<Src> Generator for Tail<Src>
+where
+    Src: Generator,
+{
+    type Output = Src::Output;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        if !self.source_complete {
+            let amount = self.amount;
+            let buffer = &mut self.buffer;
+            let result = self.source.run(|x| {
+                if amount != 0 {
+                    if buffer.len() == amount {
+                        buffer.pop_front();
+                    }
+                    buffer.push_back(x);
+                }
+                ValueResult::MoreValues
+            });
+            if result == GeneratorResult::Stopped {
+                return GeneratorResult::Stopped;
+            }
+            self.source_complete = true;
+        }
+
+        while let Some(x) = self.buffer.pop_front() {
+            if output(x) == ValueResult::Stop {
+                return if self.buffer.is_empty() {
+                    GeneratorResult::Complete
+                } else {
+                    GeneratorResult::Stopped
+                };
+            }
+        }
+        GeneratorResult::Complete
+    }
+}
+
+impl<Src> FusedGenerator for Tail<Src> where Src: Generator {}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::{collect_one_at_a_time, StoppingGen};
+    use crate::{Generator, GeneratorExt, GeneratorResult, IntoGenerator, ValueResult};
+
+    #[test]
+    fn tail() {
+        let data = [1, 2, 3, 4, 5];
+        let output: Vec<_> = data.into_gen().tail(2).collect();
+        assert_eq!(output, [4, 5]);
+
+        let output: Vec<_> = data.into_gen().tail(0).collect();
+        assert!(output.is_empty());
+
+        let output: Vec<_> = data.into_gen().tail(10).collect();
+        assert_eq!(output, data);
+    }
+
+    #[test]
+    fn huge_amount() {
+        let data = [1, 2, 3];
+        let output: Vec<_> = data.into_gen().tail(usize::MAX).collect();
+        assert_eq!(output, data);
+    }
+
+    #[test]
+    fn consumer_stop() {
+        let data = [1, 2, 3, 4, 5];
+        let mut gen = data.into_gen().tail(3);
+        let output = collect_one_at_a_time(&mut gen);
+        assert_eq!(output, [3, 4, 5]);
+        assert_eq!(
+            gen.run(|_| ValueResult::MoreValues),
+            GeneratorResult::Complete
+        );
+    }
+
+    #[test]
+    fn spuriously_stopping() {
+        let data = [1, 2, 3, 4, 5];
+        for x in 0..data.len() {
+            let mut gen = StoppingGen::new(x as i32, &data).copied().tail(2);
+            let mut output = Vec::new();
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Stopped);
+            assert!(output.is_empty());
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Complete);
+            assert_eq!(output, [4, 5]);
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Generator, GeneratorResult, ValueResult};
+use crate::{FusedGenerator, Generator, GeneratorResult, ValueResult};
 
 /// Take `n` values from a generator. See [`.take()`](crate::GeneratorExt::take) for details.
 #[derive(Clone)]
@@ -46,6 +46,9 @@ impl<Src: Generator> Generator for Take<Src> {
     }
 }
 
+// `amount_left` reaches zero when the source completes, so `Take` stays complete.
+impl<Src: Generator> FusedGenerator for Take<Src> {}
+
 /// A generator that only forwards values while the predicate returns `true`. See [`.take_while()`](crate::GeneratorExt::take_while) for details.
 #[derive(Clone)]
 pub struct TakeWhile<Src, P> {
@@ -101,11 +104,84 @@ where
     }
 }
 
+impl<Src, P> FusedGenerator for TakeWhile<Src, P>
+where
+    Src: FusedGenerator,
+    P: FnMut(&Src::Output) -> bool,
+{
+}
+
+/// A generator that forwards values while the predicate returns `true`, including the first value
+/// for which it returns `false`. See
+/// [`.take_while_inclusive()`](crate::GeneratorExt::take_while_inclusive) for details.
+#[derive(Clone)]
+pub struct TakeWhileInclusive<Src, P> {
+    source: Src,
+    predicate: P,
+    is_complete: bool,
+}
+
+impl<Src, P> TakeWhileInclusive<Src, P>
+where
+    Src: Generator,
+    P: FnMut(&Src::Output) -> bool,
+{
+    #[inline]
+    pub(crate) fn new(source: Src, predicate: P) -> Self {
+        Self {
+            source,
+            predicate,
+            is_complete: false,
+        }
+    }
+}
+
+impl<Src, P> Generator for TakeWhileInclusive<Src, P>
+where
+    Src: Generator,
+    P: FnMut(&Src::Output) -> bool,
+{
+    type Output = Src::Output;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        let is_complete = &mut self.is_complete;
+        if *is_complete {
+            return GeneratorResult::Complete;
+        }
+
+        let predicate = &mut self.predicate;
+        let result = self.source.run(|x| {
+            if predicate(&x) {
+                output(x)
+            } else {
+                // The failing value is the last one, so the result of `output` doesn't matter.
+                *is_complete = true;
+                output(x);
+                ValueResult::Stop
+            }
+        });
+
+        if *is_complete {
+            GeneratorResult::Complete
+        } else {
+            result
+        }
+    }
+}
+
+impl<Src, P> FusedGenerator for TakeWhileInclusive<Src, P>
+where
+    Src: FusedGenerator,
+    P: FnMut(&Src::Output) -> bool,
+{
+}
+
 #[cfg(test)]
 mod tests {
     use crate::structs::take::TakeWhile;
     use crate::structs::Take;
-    use crate::test::StoppingGen;
+    use crate::test::{collect_one_at_a_time, StoppingGen};
     use crate::{Generator, GeneratorExt, GeneratorResult, SliceGenerator, ValueResult};
 
     #[test]
@@ -221,4 +297,61 @@ mod tests {
             assert_eq!(output, [&1, &2, &3, &4]);
         }
     }
+
+    #[test]
+    fn take_while_consumer_stop() {
+        let data = [1, 2, 3, 4, 2];
+        let mut gen = SliceGenerator::new(&data).take_while(|x| **x <= 3);
+        let mut output = Vec::new();
+
+        let result = gen.run(|x| {
+            output.push(*x);
+            ValueResult::Stop
+        });
+        assert_eq!(result, GeneratorResult::Stopped);
+        assert_eq!(output, [1]);
+
+        let result = gen.run(|x| {
+            output.push(*x);
+            ValueResult::MoreValues
+        });
+        assert_eq!(result, GeneratorResult::Complete);
+        assert_eq!(output, [1, 2, 3]);
+
+        assert_eq!(gen.next(), Err(GeneratorResult::Complete));
+    }
+
+    #[test]
+    fn take_while_inclusive() {
+        let data = [1, 2, 0, 3, 4];
+        let mut gen = SliceGenerator::new(&data).take_while_inclusive(|x| **x != 0);
+        let mut output = Vec::new();
+        assert_eq!(gen.for_each(|x| output.push(*x)), GeneratorResult::Complete);
+        assert_eq!(gen.for_each(|x| output.push(*x)), GeneratorResult::Complete);
+        assert_eq!(output, [1, 2, 0]);
+
+        let output: Vec<_> = SliceGenerator::new(&data)
+            .take_while_inclusive(|x| **x < 10)
+            .collect();
+        assert_eq!(output, [&1, &2, &0, &3, &4]);
+    }
+
+    #[test]
+    fn take_while_inclusive_consumer_stop() {
+        let data = [1, 2, 0, 3];
+        let mut gen = SliceGenerator::new(&data).take_while_inclusive(|x| **x != 0);
+        assert_eq!(collect_one_at_a_time(&mut gen), [&1, &2, &0]);
+    }
+
+    #[test]
+    fn spuriously_stopping_take_while_inclusive() {
+        let data = [1, 2, 0, 3];
+        for x in 0..3 {
+            let mut gen = StoppingGen::new(x, &data).take_while_inclusive(|x| **x != 0);
+            let mut output = Vec::new();
+            assert_eq!(gen.for_each(|x| output.push(*x)), GeneratorResult::Stopped);
+            assert_eq!(gen.for_each(|x| output.push(*x)), GeneratorResult::Complete);
+            assert_eq!(output, [1, 2, 0]);
+        }
+    }
 }
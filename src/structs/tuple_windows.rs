@@ -0,0 +1,159 @@
+use crate::{structs::utility::set_some, Generator, GeneratorResult, ValueResult};
+
+/// A tuple of `LEN` values of the same type that can be used as a sliding window.
+///
+/// Implemented for tuples of 2, 3 and 4 values. See
+/// [`.tuple_windows()`](crate::GeneratorExt::tuple_windows) for details.
+pub trait TupleWindow: Clone {
+    /// The type of the values in the tuple.
+    type Item;
+
+    /// The number of values in the tuple.
+    const LEN: usize;
+
+    /// Creates a tuple with every position holding `value`.
+    fn filled_with(value: Self::Item) -> Self;
+
+    /// Drops the first value of the tuple and appends `value` at the end.
+    fn shift(self, value: Self::Item) -> Self;
+}
+
+impl<T: Clone> TupleWindow for (T, T) {
+    type Item = T;
+    const LEN: usize = 2;
+
+    #[inline]
+    fn filled_with(value: T) -> Self {
+        (value.clone(), value)
+    }
+
+    #[inline]
+    fn shift(self, value: T) -> Self {
+        (self.1, value)
+    }
+}
+
+impl<T: Clone> TupleWindow for (T, T, T) {
+    type Item = T;
+    const LEN: usize = 3;
+
+    #[inline]
+    fn filled_with(value: T) -> Self {
+        (value.clone(), value.clone(), value)
+    }
+
+    #[inline]
+    fn shift(self, value: T) -> Self {
+        (self.1, self.2, value)
+    }
+}
+
+impl<T: Clone> TupleWindow for (T, T, T, T) {
+    type Item = T;
+    const LEN: usize = 4;
+
+    #[inline]
+    fn filled_with(value: T) -> Self {
+        (value.clone(), value.clone(), value.clone(), value)
+    }
+
+    #[inline]
+    fn shift(self, value: T) -> Self {
+        (self.1, self.2, self.3, value)
+    }
+}
+
+/// Generates overlapping tuples of consecutive values. See [`.tuple_windows()`](crate::GeneratorExt::tuple_windows) for details.
+#[derive(Clone)]
+pub struct TupleWindows<Src, Tup> {
+    source: Src,
+    window: Option<Tup>,
+    // Number of values in the window, saturating at the window length.
+    filled: usize,
+}
+
+impl<Src, Tup> TupleWindows<Src, Tup>
+where
+    Src: Generator,
+    Tup: TupleWindow<Item = Src::Output>,
+{
+    #[inline]
+    pub(crate) fn new(source: Src) -> Self {
+        Self {
+            source,
+            window: None,
+            filled: 0,
+        }
+    }
+}
+
+impl<Src, Tup> Generator for TupleWindows<Src, Tup>
+where
+    Src: Generator,
+    Tup: TupleWindow<Item = Src::Output>,
+{
+    type Output = Tup;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        let window = &mut self.window;
+        let filled = &mut self.filled;
+        self.source.run(|x| {
+            // The window starts out filled with copies of the first value, those are shifted out
+            // before the first window is generated.
+            let next = match window.take() {
+                Some(current) => current.shift(x),
+                None => Tup::filled_with(x),
+            };
+            let next = set_some(window, next);
+            if *filled < Tup::LEN {
+                *filled += 1;
+            }
+            if *filled == Tup::LEN {
+                output(next.clone())
+            } else {
+                ValueResult::MoreValues
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::StoppingGen;
+    use crate::{GeneratorExt, GeneratorResult, IntoGenerator};
+
+    #[test]
+    fn pairs() {
+        let data = [1, 2, 3, 4];
+        let output: Vec<(_, _)> = data.into_gen().tuple_windows().collect();
+        assert_eq!(output, [(1, 2), (2, 3), (3, 4)]);
+
+        let output: Vec<(_, _)> = [1].into_gen().tuple_windows().collect();
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn triples_and_quadruples() {
+        let data = [1, 2, 3, 4, 5];
+        let output: Vec<(_, _, _)> = data.into_gen().tuple_windows().collect();
+        assert_eq!(output, [(1, 2, 3), (2, 3, 4), (3, 4, 5)]);
+
+        let output: Vec<(_, _, _, _)> = data.into_gen().tuple_windows().collect();
+        assert_eq!(output, [(1, 2, 3, 4), (2, 3, 4, 5)]);
+    }
+
+    #[test]
+    fn spuriously_stopping() {
+        let data = [1, 2, 3, 4];
+        for x in 0..4 {
+            let mut gen = StoppingGen::new(x, &data)
+                .copied()
+                .tuple_windows::<(_, _, _)>();
+            let mut output = Vec::new();
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Stopped);
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Complete);
+            assert_eq!(output, [(1, 2, 3), (2, 3, 4)]);
+        }
+    }
+}
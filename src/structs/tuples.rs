@@ -0,0 +1,186 @@
+use crate::{Generator, GeneratorResult, ValueResult};
+
+/// A tuple of values of the same type.
+///
+/// Implemented for tuples of 2, 3 and 4 values. See [`.tuples()`](crate::GeneratorExt::tuples)
+/// for details.
+pub trait HomogeneousTuple: Sized {
+    /// The type of the values in the tuple.
+    type Item;
+
+    /// Buffer holding the values of a tuple while it is being filled.
+    #[doc(hidden)]
+    type Buffer: Default + AsMut<[Option<Self::Item>]>;
+
+    /// Takes the values out of a full buffer.
+    #[doc(hidden)]
+    fn take_from(buffer: &mut Self::Buffer) -> Option<Self>;
+}
+
+impl<T> HomogeneousTuple for (T, T) {
+    type Item = T;
+    type Buffer = [Option<T>; 2];
+
+    #[inline]
+    fn take_from(buffer: &mut Self::Buffer) -> Option<Self> {
+        let [a, b] = buffer;
+        Some((a.take()?, b.take()?))
+    }
+}
+
+impl<T> HomogeneousTuple for (T, T, T) {
+    type Item = T;
+    type Buffer = [Option<T>; 3];
+
+    #[inline]
+    fn take_from(buffer: &mut Self::Buffer) -> Option<Self> {
+        let [a, b, c] = buffer;
+        Some((a.take()?, b.take()?, c.take()?))
+    }
+}
+
+impl<T> HomogeneousTuple for (T, T, T, T) {
+    type Item = T;
+    type Buffer = [Option<T>; 4];
+
+    #[inline]
+    fn take_from(buffer: &mut Self::Buffer) -> Option<Self> {
+        let [a, b, c, d] = buffer;
+        Some((a.take()?, b.take()?, c.take()?, d.take()?))
+    }
+}
+
+/// Groups values into non-overlapping tuples. See [`.tuples()`](crate::GeneratorExt::tuples) for
+/// details.
+pub struct Tuples<Src, Tup>
+where
+    Tup: HomogeneousTuple,
+{
+    source: Src,
+    buffer: Tup::Buffer,
+    // Number of values in the buffer.
+    len: usize,
+}
+
+impl<Src, Tup> Tuples<Src, Tup>
+where
+    Src: Generator,
+    Tup: HomogeneousTuple<Item = Src::Output>,
+{
+    #[inline]
+    pub(crate) fn new(source: Src) -> Self {
+        Self {
+            source,
+            buffer: Default::default(),
+            len: 0,
+        }
+    }
+
+    /// Returns the values that haven't been generated as part of a tuple.
+    ///
+    /// Once the generator has completed, these are the values that were left over because there
+    /// weren't enough of them to fill a whole tuple.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt, GeneratorResult};
+    /// let a = [1, 2, 3, 4, 5];
+    /// let mut gen = a.into_gen().tuples::<(_, _, _)>();
+    /// assert_eq!(gen.next(), Ok((1, 2, 3)));
+    /// assert_eq!(gen.next(), Err(GeneratorResult::Complete));
+    /// let remainder: Vec<_> = gen.into_remainder().collect();
+    /// assert_eq!(remainder, [4, 5]);
+    /// ```
+    #[inline]
+    pub fn into_remainder(self) -> impl Iterator<Item = Src::Output> {
+        let mut buffer = self.buffer;
+        (0..self.len).filter_map(move |i| buffer.as_mut()[i].take())
+    }
+}
+
+impl<Src, Tup> Clone for Tuples<Src, Tup>
+where
+    Src: Clone,
+    Tup: HomogeneousTuple,
+    Tup::Buffer: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            source: self.source.clone(),
+            buffer: self.buffer.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<Src, Tup> Generator for Tuples<Src, Tup>
+where
+    Src: Generator,
+    Tup: HomogeneousTuple<Item = Src::Output>,
+{
+    type Output = Tup;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        let buffer = &mut self.buffer;
+        let len = &mut self.len;
+        self.source.run(|x| {
+            let slots = buffer.as_mut();
+            slots[*len] = Some(x);
+            *len += 1;
+            if *len < slots.len() {
+                return ValueResult::MoreValues;
+            }
+            *len = 0;
+            match Tup::take_from(buffer) {
+                Some(tuple) => output(tuple),
+                None => unreachable!(),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::StoppingGen;
+    use crate::{GeneratorExt, GeneratorResult, IntoGenerator};
+
+    #[test]
+    fn tuples() {
+        let data = [1, 2, 3, 4, 5, 6, 7];
+        let output: Vec<(_, _)> = data.into_gen().tuples().collect();
+        assert_eq!(output, [(1, 2), (3, 4), (5, 6)]);
+
+        let output: Vec<(_, _, _)> = data.into_gen().tuples().collect();
+        assert_eq!(output, [(1, 2, 3), (4, 5, 6)]);
+
+        let output: Vec<(_, _, _, _)> = data.into_gen().tuples().collect();
+        assert_eq!(output, [(1, 2, 3, 4)]);
+    }
+
+    #[test]
+    fn into_remainder() {
+        let data = [1, 2, 3, 4, 5];
+        let mut gen = data.into_gen().tuples::<(_, _)>();
+        assert_eq!(gen.for_each(|_| ()), GeneratorResult::Complete);
+        assert_eq!(gen.into_remainder().collect::<Vec<_>>(), [5]);
+
+        let mut gen = [1, 2, 3, 4].into_gen().tuples::<(_, _, _, _)>();
+        assert_eq!(gen.for_each(|_| ()), GeneratorResult::Complete);
+        assert!(gen.into_remainder().next().is_none());
+    }
+
+    #[test]
+    fn spuriously_stopping() {
+        let data = [1, 2, 3, 4, 5, 6];
+        for x in 0..6 {
+            let mut gen = StoppingGen::new(x, &data).tuples::<(_, _, _)>();
+            let mut output = Vec::new();
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Stopped);
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Complete);
+            assert_eq!(output, [(&1, &2, &3), (&4, &5, &6)]);
+        }
+    }
+}
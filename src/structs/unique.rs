@@ -0,0 +1,205 @@
+use crate::{Generator, GeneratorResult, ValueResult};
+use core::hash::Hash;
+use std::collections::{HashMap, HashSet};
+
+/// Removal of values that have been generated before. See
+/// [`.unique()`](crate::GeneratorExt::unique) for details.
+#[derive(Clone)]
+pub struct Unique<Src>
+where
+    Src: Generator,
+{
+    source: Src,
+    seen: HashSet<Src::Output>,
+}
+
+impl<Src> Unique<Src>
+where
+    Src: Generator,
+{
+    #[inline]
+    pub(crate) fn new(source: Src) -> Self {
+        Self {
+            source,
+            seen: HashSet::new(),
+        }
+    }
+}
+
+impl<Src> Generator for Unique<Src>
+where
+    Src: Generator,
+    Src::Output: Eq + Hash + Clone,
+{
+    type Output = Src::Output;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        let seen = &mut self.seen;
+        self.source.run(|x| {
+            if seen.contains(&x) {
+                ValueResult::MoreValues
+            } else {
+                seen.insert(x.clone());
+                output(x)
+            }
+        })
+    }
+}
+
+/// Removal of values whose key has been seen before. See
+/// [`.unique_by()`](crate::GeneratorExt::unique_by) for details.
+#[derive(Clone)]
+pub struct UniqueBy<Src, F, K> {
+    source: Src,
+    key: F,
+    seen: HashSet<K>,
+}
+
+impl<Src, F, K> UniqueBy<Src, F, K> {
+    #[inline]
+    pub(crate) fn new(source: Src, key: F) -> Self {
+        Self {
+            source,
+            key,
+            seen: HashSet::new(),
+        }
+    }
+}
+
+impl<Src, F, K> Generator for UniqueBy<Src, F, K>
+where
+    Src: Generator,
+    F: FnMut(&Src::Output) -> K,
+    K: Eq + Hash,
+{
+    type Output = Src::Output;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        let key = &mut self.key;
+        let seen = &mut self.seen;
+        self.source.run(|x| {
+            if seen.insert(key(&x)) {
+                output(x)
+            } else {
+                ValueResult::MoreValues
+            }
+        })
+    }
+}
+
+/// Generation of values that appear more than once. See
+/// [`.duplicates()`](crate::GeneratorExt::duplicates) for details.
+#[derive(Clone)]
+pub struct Duplicates<Src>
+where
+    Src: Generator,
+{
+    source: Src,
+    // Maps every value seen to whether a duplicate of it has been generated.
+    seen: HashMap<Src::Output, bool>,
+}
+
+impl<Src> Duplicates<Src>
+where
+    Src: Generator,
+{
+    #[inline]
+    pub(crate) fn new(source: Src) -> Self {
+        Self {
+            source,
+            seen: HashMap::new(),
+        }
+    }
+}
+
+impl<Src> Generator for Duplicates<Src>
+where
+    Src: Generator,
+    Src::Output: Eq + Hash,
+{
+    type Output = Src::Output;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        let seen = &mut self.seen;
+        self.source.run(|x| match seen.get_mut(&x) {
+            None => {
+                seen.insert(x, false);
+                ValueResult::MoreValues
+            }
+            Some(generated) if !*generated => {
+                *generated = true;
+                output(x)
+            }
+            Some(_) => ValueResult::MoreValues,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::StoppingGen;
+    use crate::{GeneratorExt, GeneratorResult, IntoGenerator};
+
+    #[test]
+    fn unique() {
+        let data = [1, 2, 1, 3, 2, 2, 4, 3];
+        let output: Vec<_> = data.into_gen().unique().collect();
+        assert_eq!(output, [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn spuriously_stopping() {
+        let data = [1, 2, 1, 3, 2];
+        for x in 0..5 {
+            let mut gen = StoppingGen::new(x, &data).unique();
+            let mut output = Vec::new();
+            assert_eq!(gen.for_each(|x| output.push(*x)), GeneratorResult::Stopped);
+            assert_eq!(gen.for_each(|x| output.push(*x)), GeneratorResult::Complete);
+            assert_eq!(output, [1, 2, 3]);
+        }
+    }
+
+    #[test]
+    fn unique_by() {
+        let data = ["apple", "avocado", "banana", "blueberry", "cherry"];
+        let output: Vec<_> = data.into_gen().unique_by(|s| s.chars().next()).collect();
+        assert_eq!(output, ["apple", "banana", "cherry"]);
+    }
+
+    #[test]
+    fn unique_by_spuriously_stopping() {
+        let data = [1, 2, 11, 3, 12];
+        for x in 0..5 {
+            let mut gen = StoppingGen::new(x, &data).unique_by(|x| *x % 10);
+            let mut output = Vec::new();
+            assert_eq!(gen.for_each(|x| output.push(*x)), GeneratorResult::Stopped);
+            assert_eq!(gen.for_each(|x| output.push(*x)), GeneratorResult::Complete);
+            assert_eq!(output, [1, 2, 3]);
+        }
+    }
+
+    #[test]
+    fn duplicates() {
+        let data = [1, 2, 1, 3, 2, 2, 4, 3, 1];
+        let output: Vec<_> = data.into_gen().duplicates().collect();
+        assert_eq!(output, [1, 2, 3]);
+
+        let output: Vec<_> = [1, 2, 3].into_gen().duplicates().collect();
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn duplicates_spuriously_stopping() {
+        let data = [1, 2, 1, 3, 2, 1];
+        for x in 0..6 {
+            let mut gen = StoppingGen::new(x, &data).duplicates();
+            let mut output = Vec::new();
+            assert_eq!(gen.for_each(|x| output.push(*x)), GeneratorResult::Stopped);
+            assert_eq!(gen.for_each(|x| output.push(*x)), GeneratorResult::Complete);
+            assert_eq!(output, [1, 2]);
+        }
+    }
+}
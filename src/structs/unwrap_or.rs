@@ -0,0 +1,144 @@
+use crate::{Generator, GeneratorResult, ReverseGenerator, ValueResult};
+use core::num::NonZeroUsize;
+
+/// Replace each `Err` with a default value. See [`.unwrap_or()`](crate::GeneratorExt::unwrap_or) for details.
+#[derive(Clone)]
+pub struct UnwrapOr<Src, T> {
+    source: Src,
+    default: T,
+}
+
+impl<Src, T> UnwrapOr<Src, T> {
+    #[inline]
+    pub(crate) fn new(source: Src, default: T) -> Self {
+        Self { source, default }
+    }
+}
+
+impl<Src, T, E> Generator for UnwrapOr<Src, T>
+where
+    Src: Generator<Output = Result<T, E>>,
+    T: Clone,
+{
+    type Output = T;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        let default = &self.default;
+        self.source
+            .run(|x| output(x.unwrap_or_else(|_| default.clone())))
+    }
+
+    #[inline]
+    fn try_advance(&mut self, n: NonZeroUsize) -> (usize, GeneratorResult) {
+        self.source.try_advance(n)
+    }
+}
+
+impl<Src, T, E> ReverseGenerator for UnwrapOr<Src, T>
+where
+    Src: ReverseGenerator<Output = Result<T, E>>,
+    T: Clone,
+{
+    #[inline]
+    fn run_back(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        let default = &self.default;
+        self.source
+            .run_back(|x| output(x.unwrap_or_else(|_| default.clone())))
+    }
+
+    #[inline]
+    fn try_advance_back(&mut self, n: NonZeroUsize) -> (usize, GeneratorResult) {
+        self.source.try_advance_back(n)
+    }
+}
+
+/// Replace each `Err` with a computed value. See [`.unwrap_or_else()`](crate::GeneratorExt::unwrap_or_else) for details.
+#[derive(Clone)]
+pub struct UnwrapOrElse<Src, F> {
+    source: Src,
+    func: F,
+}
+
+impl<Src, F> UnwrapOrElse<Src, F> {
+    #[inline]
+    pub(crate) fn new(source: Src, func: F) -> Self {
+        Self { source, func }
+    }
+}
+
+impl<Src, F, T, E> Generator for UnwrapOrElse<Src, F>
+where
+    Src: Generator<Output = Result<T, E>>,
+    F: FnMut(E) -> T,
+{
+    type Output = T;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        let func = &mut self.func;
+        self.source.run(|x| output(x.unwrap_or_else(&mut *func)))
+    }
+
+    #[inline]
+    fn try_advance(&mut self, n: NonZeroUsize) -> (usize, GeneratorResult) {
+        self.source.try_advance(n)
+    }
+}
+
+impl<Src, F, T, E> ReverseGenerator for UnwrapOrElse<Src, F>
+where
+    Src: ReverseGenerator<Output = Result<T, E>>,
+    F: FnMut(E) -> T,
+{
+    #[inline]
+    fn run_back(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        let func = &mut self.func;
+        self.source
+            .run_back(|x| output(x.unwrap_or_else(&mut *func)))
+    }
+
+    #[inline]
+    fn try_advance_back(&mut self, n: NonZeroUsize) -> (usize, GeneratorResult) {
+        self.source.try_advance_back(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::StoppingGen;
+    use crate::{GeneratorExt, GeneratorResult, IntoGenerator};
+
+    #[test]
+    fn unwrap_or() {
+        let data = [Ok(1), Err("x"), Ok(3)];
+        let out: Vec<_> = data.into_gen().unwrap_or(0).collect();
+        assert_eq!(out, [1, 0, 3]);
+    }
+
+    #[test]
+    fn unwrap_or_else() {
+        let data = [Ok(1), Err("xy"), Ok(3)];
+        let out: Vec<_> = data.into_gen().unwrap_or_else(|e| e.len()).collect();
+        assert_eq!(out, [1, 2, 3]);
+    }
+
+    #[test]
+    fn reverse() {
+        let data = [Ok(1), Err("x"), Ok(3)];
+        let out: Vec<_> = data.into_gen().unwrap_or(0).rev().collect();
+        assert_eq!(out, [3, 0, 1]);
+    }
+
+    #[test]
+    fn spuriously_stopping() {
+        let data = [Ok(1), Err(()), Ok(3)];
+        for x in 0..3 {
+            let mut gen = StoppingGen::new(x, &data).copied().unwrap_or(0);
+            let mut output = Vec::new();
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Stopped);
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Complete);
+            assert_eq!(output, [1, 0, 3]);
+        }
+    }
+}
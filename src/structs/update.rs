@@ -0,0 +1,95 @@
+use crate::{FusedGenerator, Generator, GeneratorResult, ReverseGenerator, ValueResult};
+use core::num::NonZeroUsize;
+
+/// Mutate each value in place and then pass it on. See [`update()`] for details.
+///
+/// [`update()`]: crate::GeneratorExt::update
+#[derive(Clone)]
+pub struct Update<Src, F> {
+    source: Src,
+    updater: F,
+}
+
+impl<Src, F> Update<Src, F> {
+    #[inline]
+    pub(crate) fn new(source: Src, updater: F) -> Self {
+        Self { source, updater }
+    }
+}
+
+impl<Src, F> Generator for Update<Src, F>
+where
+    Src: Generator,
+    F: FnMut(&mut Src::Output),
+{
+    type Output = Src::Output;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        let updater = &mut self.updater;
+        self.source.run(move |mut x| {
+            updater(&mut x);
+            output(x)
+        })
+    }
+
+    #[inline]
+    fn try_advance(&mut self, n: NonZeroUsize) -> (usize, GeneratorResult) {
+        self.source.try_advance(n)
+    }
+}
+
+impl<Src, F> ReverseGenerator for Update<Src, F>
+where
+    Src: ReverseGenerator,
+    F: FnMut(&mut Src::Output),
+{
+    #[inline]
+    fn run_back(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        let updater = &mut self.updater;
+        self.source.run_back(move |mut x| {
+            updater(&mut x);
+            output(x)
+        })
+    }
+
+    #[inline]
+    fn try_advance_back(&mut self, n: NonZeroUsize) -> (usize, GeneratorResult) {
+        self.source.try_advance_back(n)
+    }
+}
+
+impl<Src, F> FusedGenerator for Update<Src, F>
+where
+    Src: FusedGenerator,
+    F: FnMut(&mut Src::Output),
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::StoppingGen;
+    use crate::{GeneratorExt, GeneratorResult, IntoGenerator};
+
+    #[test]
+    fn update() {
+        let data = vec![vec![1], vec![2, 3]];
+        let output: Vec<_> = data.into_gen().update(|v| v.push(0)).collect();
+        assert_eq!(output, [vec![1, 0], vec![2, 3, 0]]);
+
+        let output: Vec<_> = [1, 2, 3].into_gen().update(|x| *x *= 2).rev().collect();
+        assert_eq!(output, [6, 4, 2]);
+    }
+
+    #[test]
+    fn spuriously_stopping() {
+        let a = [1, 2, 3];
+        for x in 0..3 {
+            let mut output = Vec::new();
+            let mut gen = StoppingGen::new(x, &a).copied().update(|x| *x += 1);
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Stopped);
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Complete);
+            assert_eq!(output, [2, 3, 4]);
+        }
+    }
+}
@@ -1,7 +1,17 @@
+#[cfg(not(feature = "safe"))]
 use core::hint;
+#[cfg(not(feature = "safe"))]
+use core::mem::MaybeUninit;
+use core::num::NonZeroUsize;
 
+/// Unwrap an option that is known to be `Some`.
+///
+/// ## Safety
+///
+/// `option` must be `Some`.
+#[cfg(not(feature = "safe"))]
 #[inline(always)]
-pub unsafe fn unwrap_unchecked<T>(option: Option<T>) -> T {
+pub unsafe fn unwrap_some<T>(option: Option<T>) -> T {
     match option {
         Some(val) => val,
         // unlike unreachable!() which will panic on reaching, unreachable_unchecked
@@ -10,6 +20,32 @@ pub unsafe fn unwrap_unchecked<T>(option: Option<T>) -> T {
     }
 }
 
+#[cfg(feature = "safe")]
+#[inline(always)]
+pub fn unwrap_some<T>(option: Option<T>) -> T {
+    match option {
+        Some(val) => val,
+        None => unreachable!(),
+    }
+}
+
+/// Create a `NonZeroUsize` from a value that is known not to be zero.
+///
+/// ## Safety
+///
+/// `n` must not be zero.
+#[cfg(not(feature = "safe"))]
+#[inline(always)]
+pub unsafe fn non_zero(n: usize) -> NonZeroUsize {
+    NonZeroUsize::new_unchecked(n)
+}
+
+#[cfg(feature = "safe")]
+#[inline(always)]
+pub fn non_zero(n: usize) -> NonZeroUsize {
+    unwrap_some(NonZeroUsize::new(n))
+}
+
 /// Implements this functionality as a struct instead of a function taking Option<T>
 /// to make sure that we won't accidentally treats a None as a Some and avoids the
 /// unsafe fn altogether.
@@ -27,17 +63,30 @@ impl<T> InplaceUpdatable<T> {
         InplaceUpdatable { inner: Some(value) }
     }
 
+    #[cfg(not(feature = "safe"))]
+    #[inline(always)]
+    fn take_inner(&mut self) -> T {
+        // Safety: self.inner is only None during unwind
+        unsafe { unwrap_some(self.inner.take()) }
+    }
+
+    #[cfg(feature = "safe")]
+    #[inline(always)]
+    fn take_inner(&mut self) -> T {
+        unwrap_some(self.inner.take())
+    }
+
     #[inline(always)]
     pub fn update(&mut self, updater: impl FnOnce(T) -> T) {
         // take self.inner to ensure nothing will be dropped here during unwind,
         // if updater ever panic
-        let new_val = updater(unsafe { unwrap_unchecked(self.inner.take()) });
+        let new_val = updater(self.take_inner());
         self.inner = Some(new_val);
     }
 
     #[inline(always)]
     pub fn update_with_result<R>(&mut self, updater: impl FnOnce(T) -> (T, R)) -> R {
-        let (new_val, r) = updater(unsafe { unwrap_unchecked(self.inner.take()) });
+        let (new_val, r) = updater(self.take_inner());
         self.inner = Some(new_val);
         r
     }
@@ -46,18 +95,164 @@ impl<T> InplaceUpdatable<T> {
     pub fn inplace_reduce(&mut self, right_value: T, reducer: impl FnOnce(T, T) -> T) {
         // take self.inner to ensure nothing will be dropped here during unwind,
         // if reducer ever panic
-        let new_val = reducer(unsafe { unwrap_unchecked(self.inner.take()) }, right_value);
+        let new_val = reducer(self.take_inner(), right_value);
         self.inner = Some(new_val);
     }
 
     #[inline(always)]
-    pub fn get_inner(self) -> T {
-        unsafe { unwrap_unchecked(self.inner) }
+    pub fn get_inner(mut self) -> T {
+        self.take_inner()
     }
 }
 
+#[cfg(not(feature = "safe"))]
 #[inline(always)]
 pub fn set_some<T>(option: &mut Option<T>, value: T) -> &mut T {
     *option = Some(value);
-    unsafe { unwrap_unchecked(option.as_mut()) }
+    // Safety: option has just been set to Some
+    unsafe { unwrap_some(option.as_mut()) }
+}
+
+#[cfg(feature = "safe")]
+#[inline(always)]
+pub fn set_some<T>(option: &mut Option<T>, value: T) -> &mut T {
+    option.insert(value)
+}
+
+/// Fills an array one value at a time, dropping any initialized values if it is dropped before
+/// the array is full.
+///
+/// With the `safe` feature the values are kept in a `Vec` instead, since a partially initialized
+/// array can't be viewed as a slice without `unsafe` code.
+#[cfg(not(feature = "safe"))]
+pub struct ArrayBuilder<T, const N: usize> {
+    data: [MaybeUninit<T>; N],
+    // Values in the range [0, len) are initialized.
+    len: usize,
+}
+
+#[cfg(feature = "safe")]
+pub struct ArrayBuilder<T, const N: usize> {
+    data: alloc::vec::Vec<T>,
+}
+
+#[cfg(not(feature = "safe"))]
+impl<T, const N: usize> ArrayBuilder<T, N> {
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self {
+            // Safety: This is the exact implementation of MaybeUninit::uninit_array()
+            data: unsafe { MaybeUninit::<[MaybeUninit<T>; N]>::uninit().assume_init() },
+            len: 0,
+        }
+    }
+
+    #[inline(always)]
+    pub fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Push a value to the array. Must not be called when the array is full.
+    #[inline(always)]
+    pub fn push(&mut self, value: T) {
+        debug_assert!(self.len < N);
+        // Safety: self.len < N is required by the caller
+        unsafe {
+            self.data
+                .get_unchecked_mut(self.len)
+                .as_mut_ptr()
+                .write(value)
+        };
+        self.len += 1;
+    }
+
+    /// Take the filled array, leaving the builder empty. Returns `None` if the array isn't full.
+    #[inline(always)]
+    pub fn take_array(&mut self) -> Option<[T; N]> {
+        if !self.is_full() {
+            return None;
+        }
+        // Nothing should be dropped by self after the values have been moved out.
+        self.len = 0;
+        // Safety: all N values are initialized
+        Some(unsafe { (&self.data as *const [MaybeUninit<T>; N] as *const [T; N]).read() })
+    }
+
+    /// The values that have been pushed so far.
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[T] {
+        // Safety: values in the range [0, len) are initialized
+        unsafe {
+            let slice = self.data.get_unchecked(0..self.len);
+            &*(slice as *const [MaybeUninit<T>] as *const [T])
+        }
+    }
+}
+
+#[cfg(feature = "safe")]
+impl<T, const N: usize> ArrayBuilder<T, N> {
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self {
+            data: alloc::vec::Vec::new(),
+        }
+    }
+
+    #[inline(always)]
+    pub fn is_full(&self) -> bool {
+        self.data.len() == N
+    }
+
+    /// Push a value to the array. Must not be called when the array is full.
+    #[inline(always)]
+    pub fn push(&mut self, value: T) {
+        debug_assert!(self.data.len() < N);
+        self.data.push(value);
+    }
+
+    /// Take the filled array, leaving the builder empty. Returns `None` if the array isn't full.
+    #[inline(always)]
+    pub fn take_array(&mut self) -> Option<[T; N]> {
+        if !self.is_full() {
+            return None;
+        }
+        core::convert::TryInto::try_into(core::mem::take(&mut self.data)).ok()
+    }
+
+    /// The values that have been pushed so far.
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+}
+
+impl<T, const N: usize> ArrayBuilder<T, N> {
+    /// Get the filled array, or `None` if the array isn't full.
+    #[inline(always)]
+    pub fn into_array(mut self) -> Option<[T; N]> {
+        self.take_array()
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for ArrayBuilder<T, N> {
+    #[inline]
+    fn clone(&self) -> Self {
+        let mut builder = Self::new();
+        for value in self.as_slice() {
+            builder.push(value.clone());
+        }
+        builder
+    }
+}
+
+#[cfg(not(feature = "safe"))]
+impl<T, const N: usize> Drop for ArrayBuilder<T, N> {
+    #[inline]
+    fn drop(&mut self) {
+        // Safety: values in the range [0, len) are initialized
+        unsafe {
+            let slice = self.data.get_unchecked_mut(0..self.len);
+            core::ptr::drop_in_place(&mut *(slice as *mut [MaybeUninit<T>] as *mut [T]));
+        }
+    }
 }
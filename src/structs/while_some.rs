@@ -0,0 +1,81 @@
+use crate::{FusedGenerator, Generator, GeneratorResult, ValueResult};
+
+/// A generator of the values inside `Some`, completing at the first `None`. See
+/// [`.while_some()`](crate::GeneratorExt::while_some) for details.
+#[derive(Clone)]
+pub struct WhileSome<Src> {
+    source: Src,
+    is_complete: bool,
+}
+
+impl<Src> WhileSome<Src> {
+    #[inline]
+    pub(crate) fn new(source: Src) -> Self {
+        Self {
+            source,
+            is_complete: false,
+        }
+    }
+}
+
+impl<Src, T> Generator for WhileSome<Src>
+where
+    Src: Generator<Output = Option<T>>,
+{
+    type Output = T;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        let is_complete = &mut self.is_complete;
+        if *is_complete {
+            return GeneratorResult::Complete;
+        }
+
+        let result = self.source.run(|x| match x {
+            Some(value) => output(value),
+            None => {
+                *is_complete = true;
+                ValueResult::Stop
+            }
+        });
+
+        if *is_complete {
+            GeneratorResult::Complete
+        } else {
+            result
+        }
+    }
+}
+
+impl<Src, T> FusedGenerator for WhileSome<Src> where Src: FusedGenerator<Output = Option<T>> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::StoppingGen;
+    use crate::{GeneratorExt, GeneratorResult, IntoGenerator};
+
+    #[test]
+    fn while_some() {
+        let data = [Some(1), Some(2), None, Some(4)];
+        let mut gen = data.into_gen().while_some();
+        let mut output = Vec::new();
+        assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Complete);
+        assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Complete);
+        assert_eq!(output, [1, 2]);
+
+        let output: Vec<_> = [Some(1), Some(2)].into_gen().while_some().collect();
+        assert_eq!(output, [1, 2]);
+    }
+
+    #[test]
+    fn spuriously_stopping() {
+        let data = [Some(1), Some(2), None, Some(3)];
+        for x in 0..3 {
+            let mut gen = StoppingGen::new(x, &data).copied().while_some();
+            let mut output = Vec::new();
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Stopped);
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Complete);
+            assert_eq!(output, [1, 2]);
+        }
+    }
+}
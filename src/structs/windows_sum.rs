@@ -0,0 +1,119 @@
+use crate::{structs::utility::set_some, Generator, GeneratorResult, ValueResult};
+use core::ops::{Add, Sub};
+use std::collections::VecDeque;
+
+/// Running sum over a sliding window. See [`.windows_sum()`](crate::GeneratorExt::windows_sum) for details.
+#[derive(Clone)]
+pub struct WindowsSum<Src>
+where
+    Src: Generator,
+{
+    source: Src,
+    window: VecDeque<Src::Output>,
+    size: usize,
+    sum: Option<Src::Output>,
+}
+
+impl<Src> WindowsSum<Src>
+where
+    Src: Generator,
+{
+    #[inline]
+    pub(crate) fn new(source: Src, size: usize) -> Self {
+        if size == 0 {
+            panic!("Window size must not be 0");
+        }
+        Self {
+            source,
+            window: VecDeque::new(),
+            size,
+            sum: None,
+        }
+    }
+}
+
+impl<Src> Generator for WindowsSum<Src>
+where
+    Src: Generator,
+    Src::Output: Add<Output = Src::Output> + Sub<Output = Src::Output> + Clone,
+{
+    type Output = Src::Output;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        let size = self.size;
+        let window = &mut self.window;
+        let sum = &mut self.sum;
+        self.source.run(|x| {
+            let leaving = if window.len() == size {
+                window.pop_front()
+            } else {
+                None
+            };
+            window.push_back(x.clone());
+            // Subtract the leaving value first, so the running sum never exceeds a window sum.
+            let new_sum = match (sum.take(), leaving) {
+                (Some(prev), Some(leaving)) => prev - leaving + x,
+                (Some(prev), None) => prev + x,
+                (None, _) => x,
+            };
+            let new_sum = set_some(sum, new_sum);
+            if window.len() == size {
+                output(new_sum.clone())
+            } else {
+                ValueResult::MoreValues
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::StoppingGen;
+    use crate::{GeneratorExt, GeneratorResult, IntoGenerator};
+
+    #[test]
+    fn windows_sum() {
+        let data = [1, 2, 3, 4, 5];
+        let output: Vec<_> = data.into_gen().windows_sum(3).collect();
+        assert_eq!(output, [6, 9, 12]);
+
+        let output: Vec<_> = data.into_gen().windows_sum(1).collect();
+        assert_eq!(output, data);
+
+        let output: Vec<_> = data.into_gen().windows_sum(6).collect();
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn no_intermediate_overflow() {
+        let data = [200u8, 50, 200];
+        let output: Vec<_> = data.into_gen().windows_sum(2).collect();
+        assert_eq!(output, [250, 250]);
+    }
+
+    #[test]
+    fn huge_window() {
+        let data = [1, 2, 3];
+        assert_eq!(data.into_gen().windows_sum(usize::MAX).count(), 0);
+    }
+
+    #[test]
+    fn spuriously_stopping() {
+        let data = [1, 2, 3, 4, 5];
+        for x in 0..5 {
+            let mut gen = StoppingGen::new(x, &data).copied().windows_sum(3);
+            let mut output = Vec::new();
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Stopped);
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Complete);
+            assert_eq!(output, [6, 9, 12]);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_size() {
+        let data = [1, 2, 3];
+        let _gen = data.into_gen().windows_sum(0);
+    }
+}
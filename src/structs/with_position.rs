@@ -0,0 +1,144 @@
+use crate::{Generator, GeneratorResult, ValueResult};
+
+/// The position of a value in a generator. Generated by
+/// [`.with_position()`](crate::GeneratorExt::with_position).
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Position {
+    /// The first value, when there is more than one.
+    First,
+    /// Neither the first nor the last value.
+    Middle,
+    /// The last value, when there is more than one.
+    Last,
+    /// The only value.
+    Only,
+}
+
+/// Tags values with their position. See [`.with_position()`](crate::GeneratorExt::with_position)
+/// for details.
+#[derive(Clone)]
+pub struct WithPosition<Src>
+where
+    Src: Generator,
+{
+    source: Src,
+    pending: Option<Src::Output>,
+    first_generated: bool,
+}
+
+impl<Src> WithPosition<Src>
+where
+    Src: Generator,
+{
+    #[inline]
+    pub(crate) fn new(source: Src) -> Self {
+        Self {
+            source,
+            pending: None,
+            first_generated: false,
+        }
+    }
+}
+
+impl<Src> Generator for WithPosition<Src>
+where
+    Src: Generator,
+{
+    type Output = (Position, Src::Output);
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        let pending = &mut self.pending;
+        let first_generated = &mut self.first_generated;
+        let result = self.source.run(|x| match pending.replace(x) {
+            Some(prev) => {
+                let position = if *first_generated {
+                    Position::Middle
+                } else {
+                    Position::First
+                };
+                *first_generated = true;
+                output((position, prev))
+            }
+            None => ValueResult::MoreValues,
+        });
+
+        if result == GeneratorResult::Complete {
+            if let Some(last) = self.pending.take() {
+                let position = if self.first_generated {
+                    Position::Last
+                } else {
+                    Position::Only
+                };
+                if output((position, last)) == ValueResult::Stop {
+                    return GeneratorResult::Stopped;
+                }
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Position;
+    use crate::test::{collect_one_at_a_time, StoppingGen};
+    use crate::{GeneratorExt, GeneratorResult, IntoGenerator};
+
+    #[test]
+    fn with_position() {
+        let output: Vec<_> = [1, 2, 3, 4].into_gen().with_position().collect();
+        assert_eq!(
+            output,
+            [
+                (Position::First, 1),
+                (Position::Middle, 2),
+                (Position::Middle, 3),
+                (Position::Last, 4)
+            ]
+        );
+
+        let output: Vec<_> = [1, 2].into_gen().with_position().collect();
+        assert_eq!(output, [(Position::First, 1), (Position::Last, 2)]);
+
+        let output: Vec<_> = [1].into_gen().with_position().collect();
+        assert_eq!(output, [(Position::Only, 1)]);
+
+        let empty: [i32; 0] = [];
+        assert_eq!(empty.into_gen().with_position().count(), 0);
+    }
+
+    #[test]
+    fn consumer_stop() {
+        let mut gen = [1, 2, 3].into_gen().with_position();
+        let output = collect_one_at_a_time(&mut gen);
+        assert_eq!(
+            output,
+            [
+                (Position::First, 1),
+                (Position::Middle, 2),
+                (Position::Last, 3)
+            ]
+        );
+    }
+
+    #[test]
+    fn spuriously_stopping() {
+        let data = [1, 2, 3];
+        for x in 0..3 {
+            let mut gen = StoppingGen::new(x, &data).with_position();
+            let mut output = Vec::new();
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Stopped);
+            assert_eq!(gen.for_each(|x| output.push(x)), GeneratorResult::Complete);
+            assert_eq!(
+                output,
+                [
+                    (Position::First, &1),
+                    (Position::Middle, &2),
+                    (Position::Last, &3)
+                ]
+            );
+        }
+    }
+}
@@ -1,48 +1,105 @@
-use crate::{Generator, GeneratorExt, GeneratorResult, ValueResult};
+use crate::{Generator, GeneratorExt, GeneratorResult, ValueResult, ZipEqError};
+
+/// Builds the tuples generated by [`Zip`].
+type MakePair<L, R> = fn(L, R) -> (L, R);
 
 /// Zip two generators. See [`.zip()`](crate::GeneratorExt::zip) for details.
-#[derive(Clone)]
 pub struct Zip<Left, Right>
+where
+    Left: Generator,
+    Right: Generator,
+{
+    inner: ZipWith<Left, Right, MakePair<Left::Output, Right::Output>>,
+}
+
+impl<Left, Right> Zip<Left, Right>
+where
+    Left: Generator,
+    Right: Generator,
+{
+    #[inline]
+    pub(crate) fn new(left: Left, right: Right) -> Self {
+        Self {
+            inner: ZipWith::new(left, right, |left, right| (left, right)),
+        }
+    }
+}
+
+impl<Left, Right> Clone for Zip<Left, Right>
+where
+    Left: Generator + Clone,
+    Left::Output: Clone,
+    Right: Generator + Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<Left, Right> Generator for Zip<Left, Right>
+where
+    Left: Generator,
+    Right: Generator,
+{
+    type Output = (Left::Output, Right::Output);
+
+    #[inline]
+    fn run(&mut self, output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        self.inner.run(output)
+    }
+}
+
+/// Zip two generators with a closure. See [`.zip_with()`](crate::GeneratorExt::zip_with) for
+/// details.
+#[derive(Clone)]
+pub struct ZipWith<Left, Right, F>
 where
     Left: Generator,
 {
     left: Left,
     right: Right,
+    f: F,
     last_left: Option<Left::Output>,
 }
 
-impl<Left, Right> Zip<Left, Right>
+impl<Left, Right, F> ZipWith<Left, Right, F>
 where
     Left: Generator,
 {
     #[inline]
-    pub(crate) fn new(left: Left, right: Right) -> Self {
+    pub(crate) fn new(left: Left, right: Right, f: F) -> Self {
         Self {
             left,
             right,
+            f,
             last_left: None,
         }
     }
 }
 
-impl<Left, Right> Generator for Zip<Left, Right>
+impl<Left, Right, F, O> Generator for ZipWith<Left, Right, F>
 where
     Left: Generator,
     Right: Generator,
+    F: FnMut(Left::Output, Right::Output) -> O,
 {
-    type Output = (Left::Output, Right::Output);
+    type Output = O;
 
     #[inline]
     fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
         let right = &mut self.right;
         let left = &mut self.left;
+        let f = &mut self.f;
         let last_left = &mut self.last_left;
 
         if last_left.is_some() {
             let mut output_result = ValueResult::Stop;
             match right.run(|rv| {
                 if let Some(lv) = last_left.take() {
-                    output_result = output((lv, rv));
+                    output_result = output(f(lv, rv));
                 }
                 ValueResult::Stop
             }) {
@@ -60,7 +117,7 @@ where
         let mut right_result = GeneratorResult::Stopped;
 
         let left_result = left.run(|left_value| match right.next() {
-            Ok(right_value) => output((left_value, right_value)),
+            Ok(right_value) => output(f(left_value, right_value)),
             Err(x) => {
                 *last_left = Some(left_value);
                 right_result = x;
@@ -75,11 +132,306 @@ where
     }
 }
 
+/// A value from either or both of two zipped generators. Generated by
+/// [`.zip_longest()`](crate::GeneratorExt::zip_longest).
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum EitherOrBoth<L, R> {
+    /// Both generators had a value.
+    Both(L, R),
+    /// Only the left generator had a value.
+    Left(L),
+    /// Only the right generator had a value.
+    Right(R),
+}
+
+impl<L, R> EitherOrBoth<L, R> {
+    /// Get the left value, if there is one.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use pushgen::structs::EitherOrBoth;
+    /// assert_eq!(EitherOrBoth::<_, ()>::Left(1).left(), Some(1));
+    /// assert_eq!(EitherOrBoth::Both(1, 2).left(), Some(1));
+    /// assert_eq!(EitherOrBoth::<(), _>::Right(2).left(), None);
+    /// ```
+    #[inline]
+    pub fn left(self) -> Option<L> {
+        match self {
+            EitherOrBoth::Both(left, _) | EitherOrBoth::Left(left) => Some(left),
+            EitherOrBoth::Right(_) => None,
+        }
+    }
+
+    /// Get the right value, if there is one.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use pushgen::structs::EitherOrBoth;
+    /// assert_eq!(EitherOrBoth::<(), _>::Right(2).right(), Some(2));
+    /// assert_eq!(EitherOrBoth::Both(1, 2).right(), Some(2));
+    /// assert_eq!(EitherOrBoth::<_, ()>::Left(1).right(), None);
+    /// ```
+    #[inline]
+    pub fn right(self) -> Option<R> {
+        match self {
+            EitherOrBoth::Both(_, right) | EitherOrBoth::Right(right) => Some(right),
+            EitherOrBoth::Left(_) => None,
+        }
+    }
+
+    /// Get both values, if both generators had a value.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use pushgen::structs::EitherOrBoth;
+    /// assert_eq!(EitherOrBoth::Both(1, 2).both(), Some((1, 2)));
+    /// assert_eq!(EitherOrBoth::<_, ()>::Left(1).both(), None);
+    /// ```
+    #[inline]
+    pub fn both(self) -> Option<(L, R)> {
+        match self {
+            EitherOrBoth::Both(left, right) => Some((left, right)),
+            _ => None,
+        }
+    }
+}
+
+/// Zip two generators until both are complete. See [`.zip_longest()`](crate::GeneratorExt::zip_longest) for details.
+#[derive(Clone)]
+pub struct ZipLongest<Left, Right>
+where
+    Left: Generator,
+{
+    left: Left,
+    right: Right,
+    // A left value waiting for a value from a stopped right generator.
+    pending_left: Option<Left::Output>,
+    left_complete: bool,
+    right_complete: bool,
+}
+
+impl<Left, Right> ZipLongest<Left, Right>
+where
+    Left: Generator,
+{
+    #[inline]
+    pub(crate) fn new(left: Left, right: Right) -> Self {
+        Self {
+            left,
+            right,
+            pending_left: None,
+            left_complete: false,
+            right_complete: false,
+        }
+    }
+}
+
+impl<Left, Right> Generator for ZipLongest<Left, Right>
+where
+    Left: Generator,
+    Right: Generator,
+{
+    type Output = EitherOrBoth<Left::Output, Right::Output>;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        if !self.left_complete {
+            let right = &mut self.right;
+            let right_complete = &mut self.right_complete;
+
+            if let Some(left_value) = self.pending_left.take() {
+                let value = match right.next() {
+                    Ok(right_value) => EitherOrBoth::Both(left_value, right_value),
+                    Err(GeneratorResult::Stopped) => {
+                        self.pending_left = Some(left_value);
+                        return GeneratorResult::Stopped;
+                    }
+                    Err(GeneratorResult::Complete) => {
+                        *right_complete = true;
+                        EitherOrBoth::Left(left_value)
+                    }
+                };
+                if output(value) == ValueResult::Stop {
+                    return GeneratorResult::Stopped;
+                }
+            }
+
+            let pending_left = &mut self.pending_left;
+            let result = self.left.run(|left_value| {
+                if *right_complete {
+                    return output(EitherOrBoth::Left(left_value));
+                }
+                match right.next() {
+                    Ok(right_value) => output(EitherOrBoth::Both(left_value, right_value)),
+                    Err(GeneratorResult::Stopped) => {
+                        *pending_left = Some(left_value);
+                        ValueResult::Stop
+                    }
+                    Err(GeneratorResult::Complete) => {
+                        *right_complete = true;
+                        output(EitherOrBoth::Left(left_value))
+                    }
+                }
+            });
+            if result == GeneratorResult::Stopped {
+                return GeneratorResult::Stopped;
+            }
+            self.left_complete = true;
+        }
+
+        if self.right_complete {
+            return GeneratorResult::Complete;
+        }
+        let result = self
+            .right
+            .run(|right_value| output(EitherOrBoth::Right(right_value)));
+        self.right_complete = result == GeneratorResult::Complete;
+        result
+    }
+}
+
+/// Zip two generators of equal length. See [`.zip_eq()`](crate::GeneratorExt::zip_eq) for details.
+pub struct ZipEq<Left, Right>
+where
+    Left: Generator,
+{
+    inner: ZipLongest<Left, Right>,
+}
+
+impl<Left, Right> Clone for ZipEq<Left, Right>
+where
+    Left: Generator + Clone,
+    Left::Output: Clone,
+    Right: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<Left, Right> ZipEq<Left, Right>
+where
+    Left: Generator,
+{
+    #[inline]
+    pub(crate) fn new(left: Left, right: Right) -> Self {
+        Self {
+            inner: ZipLongest::new(left, right),
+        }
+    }
+}
+
+impl<Left, Right> Generator for ZipEq<Left, Right>
+where
+    Left: Generator,
+    Right: Generator,
+{
+    type Output = (Left::Output, Right::Output);
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        self.inner.run(|value| match value {
+            EitherOrBoth::Both(left, right) => output((left, right)),
+            EitherOrBoth::Left(_) => {
+                panic!("zip_eq: left generator is longer than right generator")
+            }
+            EitherOrBoth::Right(_) => {
+                panic!("zip_eq: right generator is longer than left generator")
+            }
+        })
+    }
+}
+
+/// Zip two generators of equal length, generating an error on a length mismatch. See
+/// [`.try_zip_eq()`](crate::GeneratorExt::try_zip_eq) for details.
+pub struct TryZipEq<Left, Right>
+where
+    Left: Generator,
+{
+    inner: ZipLongest<Left, Right>,
+    is_complete: bool,
+}
+
+impl<Left, Right> Clone for TryZipEq<Left, Right>
+where
+    Left: Generator + Clone,
+    Left::Output: Clone,
+    Right: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            is_complete: self.is_complete,
+        }
+    }
+}
+
+impl<Left, Right> TryZipEq<Left, Right>
+where
+    Left: Generator,
+{
+    #[inline]
+    pub(crate) fn new(left: Left, right: Right) -> Self {
+        Self {
+            inner: ZipLongest::new(left, right),
+            is_complete: false,
+        }
+    }
+}
+
+impl<Left, Right> Generator for TryZipEq<Left, Right>
+where
+    Left: Generator,
+    Right: Generator,
+{
+    type Output = Result<(Left::Output, Right::Output), ZipEqError>;
+
+    #[inline]
+    fn run(&mut self, mut output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        if self.is_complete {
+            return GeneratorResult::Complete;
+        }
+
+        let mut error = None;
+        let result = self.inner.run(|value| match value {
+            EitherOrBoth::Both(left, right) => output(Ok((left, right))),
+            EitherOrBoth::Left(_) => {
+                error = Some(ZipEqError::LeftLonger);
+                ValueResult::Stop
+            }
+            EitherOrBoth::Right(_) => {
+                error = Some(ZipEqError::RightLonger);
+                ValueResult::Stop
+            }
+        });
+
+        match error {
+            Some(error) => {
+                // Nothing is generated after the error.
+                self.is_complete = true;
+                if output(Err(error)) == ValueResult::Stop {
+                    GeneratorResult::Stopped
+                } else {
+                    GeneratorResult::Complete
+                }
+            }
+            None => result,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::test::StoppingGen;
-    use crate::{GeneratorExt, GeneratorResult, SliceGenerator};
+    use crate::{GeneratorExt, GeneratorResult, SliceGenerator, ZipEqError};
 
     fn do_zip(left: &[i32], right: &[i32]) -> (Vec<(i32, i32)>, GeneratorResult) {
         let mut output: Vec<(i32, i32)> = Vec::new();
@@ -156,4 +508,119 @@ mod tests {
             assert_eq!(output, [(1, 1), (2, 2), (3, 3)]);
         }
     }
+
+    #[test]
+    fn zip_longest() {
+        use EitherOrBoth::{Both, Left, Right};
+        let output: Vec<_> = SliceGenerator::new(&[1, 2, 3])
+            .copied()
+            .zip_longest(SliceGenerator::new(&[4]).copied())
+            .collect();
+        assert_eq!(output, [Both(1, 4), Left(2), Left(3)]);
+
+        let output: Vec<_> = SliceGenerator::new(&[1])
+            .copied()
+            .zip_longest(SliceGenerator::new(&[4, 5, 6]).copied())
+            .collect();
+        assert_eq!(output, [Both(1, 4), Right(5), Right(6)]);
+    }
+
+    #[test]
+    fn zip_longest_spuriously_stopping() {
+        use EitherOrBoth::{Both, Left, Right};
+        let long = [1, 2, 3];
+        let short = [4, 5];
+        for x in 0..3 {
+            for y in 0..2 {
+                let mut gen = StoppingGen::new(x, &long).zip_longest(StoppingGen::new(y, &short));
+                let mut output = Vec::new();
+                while gen.for_each(|x| output.push(x)) == GeneratorResult::Stopped {}
+                assert_eq!(output, [Both(&1, &4), Both(&2, &5), Left(&3)]);
+
+                let mut gen = StoppingGen::new(y, &short).zip_longest(StoppingGen::new(x, &long));
+                let mut output = Vec::new();
+                while gen.for_each(|x| output.push(x)) == GeneratorResult::Stopped {}
+                assert_eq!(output, [Both(&4, &1), Both(&5, &2), Right(&3)]);
+            }
+        }
+    }
+
+    #[test]
+    fn zip_eq() {
+        let output: Vec<_> = SliceGenerator::new(&[1, 2])
+            .zip_eq(SliceGenerator::new(&[3, 4]))
+            .collect();
+        assert_eq!(output, [(&1, &3), (&2, &4)]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn zip_eq_left_longer() {
+        SliceGenerator::new(&[1, 2, 3])
+            .zip_eq(SliceGenerator::new(&[3, 4]))
+            .for_each(|_| ());
+    }
+
+    #[test]
+    #[should_panic]
+    fn zip_eq_right_longer() {
+        SliceGenerator::new(&[1])
+            .zip_eq(SliceGenerator::new(&[3, 4]))
+            .for_each(|_| ());
+    }
+
+    #[test]
+    fn try_zip_eq() {
+        let output: Vec<_> = SliceGenerator::new(&[1, 2])
+            .copied()
+            .try_zip_eq(SliceGenerator::new(&[3, 4]).copied())
+            .collect();
+        assert_eq!(output, [Ok((1, 3)), Ok((2, 4))]);
+
+        let mut gen = SliceGenerator::new(&[1, 2, 3])
+            .copied()
+            .try_zip_eq(SliceGenerator::new(&[3]).copied());
+        assert_eq!(gen.next(), Ok(Ok((1, 3))));
+        assert_eq!(gen.next(), Ok(Err(ZipEqError::LeftLonger)));
+        assert_eq!(gen.next(), Err(GeneratorResult::Complete));
+
+        let output: Vec<_> = SliceGenerator::new(&[1])
+            .copied()
+            .try_zip_eq(SliceGenerator::new(&[3, 4]).copied())
+            .collect();
+        assert_eq!(output, [Ok((1, 3)), Err(ZipEqError::RightLonger)]);
+    }
+
+    #[test]
+    fn try_zip_eq_spuriously_stopping() {
+        let data = [1, 2, 3];
+        for x in 0..3 {
+            let mut gen = StoppingGen::new(x, &data).try_zip_eq(SliceGenerator::new(&data));
+            let mut output = Vec::new();
+            while gen.for_each(|x| output.push(x)) == GeneratorResult::Stopped {}
+            assert_eq!(output, [Ok((&1, &1)), Ok((&2, &2)), Ok((&3, &3))]);
+        }
+    }
+
+    #[test]
+    fn zip_with() {
+        let output: Vec<_> = SliceGenerator::new(&[1, 2, 3])
+            .zip_with(SliceGenerator::new(&[4, 5]), |a, b| a * b)
+            .collect();
+        assert_eq!(output, [4, 10]);
+    }
+
+    #[test]
+    fn zip_with_spuriously_stopping() {
+        let data = [1, 2, 3];
+        for x in 0..3 {
+            for y in 0..3 {
+                let mut gen =
+                    StoppingGen::new(x, &data).zip_with(StoppingGen::new(y, &data), |a, b| a + b);
+                let mut output = Vec::new();
+                while gen.for_each(|x| output.push(x)) == GeneratorResult::Stopped {}
+                assert_eq!(output, [2, 4, 6]);
+            }
+        }
+    }
 }
@@ -67,6 +67,30 @@ impl<'a, T> Generator for StoppingGen<'a, T> {
     }
 }
 
+/// Run a generator until it completes, stopping it after every generated value.
+///
+/// Returns all values generated along the way. This can be used to test that an adaptor resumes
+/// correctly after being stopped by its consumer.
+///
+/// ## Example
+///
+/// ```
+/// use pushgen::test::collect_one_at_a_time;
+/// use pushgen::{GeneratorExt, IntoGenerator};
+/// let data = [1, 2, 3];
+/// let mut gen = data.into_gen().map(|x| x * 2);
+/// assert_eq!(collect_one_at_a_time(&mut gen), [2, 4, 6]);
+/// ```
+pub fn collect_one_at_a_time<G: Generator>(gen: &mut G) -> Vec<G::Output> {
+    let mut output = Vec::new();
+    while gen.run(|x| {
+        output.push(x);
+        ValueResult::Stop
+    }) == GeneratorResult::Stopped
+    {}
+    output
+}
+
 /// A spuriously stopping generator that can stop multiple times.
 ///
 /// The generator takes a slice of `Option<T>`, each `None` will result in the generator stopping,
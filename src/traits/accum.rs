@@ -33,6 +33,22 @@ pub trait Product<A = Self>: Sized {
         G: Generator<Output = A>;
 }
 
+/// Trait to represent types that can be created by summing up a generator, saturating at the
+/// numeric bounds instead of overflowing.
+///
+/// The trait is used to implement the [`saturating_sum()`] method on generators. This trait is
+/// generally interacted with via [`GeneratorExt::saturating_sum`].
+///
+/// [`GeneratorExt::saturating_sum`]: crate::GeneratorExt::saturating_sum
+/// [`saturating_sum()`]: crate::traits::SaturatingSum::saturating_sum
+///
+pub trait SaturatingSum<A = Self>: Sized {
+    /// Calculate the saturating sum from a given generator.
+    fn saturating_sum<G>(gen: G) -> Self
+    where
+        G: Generator<Output = A>;
+}
+
 macro_rules! integer_sum_product {
     (@impls $zero:expr, $one:expr, $($a:ty)*) => ($(
     impl Sum for $a {
@@ -155,8 +171,41 @@ macro_rules! float_sum_product {
     })*)
 }
 
+macro_rules! integer_saturating_sum {
+    ($($a:ty)*) => ($(
+    impl SaturatingSum for $a {
+        #[inline]
+        fn saturating_sum<G: Generator<Output=Self>>(mut gen: G) -> Self {
+            let mut ret: $a = 0;
+            gen.run(
+                |x| {
+                    ret = ret.saturating_add(x);
+                    ValueResult::MoreValues
+                }
+            );
+            ret
+        }
+    }
+
+    impl<'a> SaturatingSum<&'a $a> for $a {
+        #[inline]
+        fn saturating_sum<G: Generator<Output=&'a Self>>(mut gen: G) -> Self {
+            let mut ret: $a = 0;
+            gen.run(
+                |x| {
+                    ret = ret.saturating_add(*x);
+                    ValueResult::MoreValues
+                }
+            );
+            ret
+        }
+    }
+    )*);
+}
+
 integer_sum_product! { i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize }
 float_sum_product! { f32 f64 }
+integer_saturating_sum! { i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize }
 
 #[cfg(test)]
 mod tests {
@@ -173,6 +222,16 @@ mod tests {
         assert_eq!(Wrapping::<u32>::sum(data.into_gen()), Wrapping(0));
     }
 
+    #[test]
+    fn saturating_sum() {
+        let data = [200u8, 100, 1];
+        assert_eq!(u8::saturating_sum(data.into_gen()), 255);
+        assert_eq!(u8::saturating_sum(SliceGenerator::new(&data)), 255);
+
+        let data = [-100i8, -100, 50];
+        assert_eq!(i8::saturating_sum(data.into_gen()), -78);
+    }
+
     #[test]
     fn product() {
         let data = [2, 3, 4];
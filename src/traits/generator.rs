@@ -140,6 +140,15 @@ pub trait ReverseGenerator: Generator {
     }
 }
 
+/// A generator that always reports `Complete` once it has completed.
+///
+/// After a `FusedGenerator` has returned [`GeneratorResult::Complete`](crate::GeneratorResult::Complete)
+/// it never generates any values again, and every later run returns `Complete` as well. Generators
+/// that don't uphold this by themselves can be wrapped with [`.fuse()`](crate::GeneratorExt::fuse).
+///
+/// This is a marker trait, adaptors implement it when their source generator does.
+pub trait FusedGenerator: Generator {}
+
 impl<L, R> Generator for Either<L, R>
 where
     L: Generator,
@@ -186,6 +195,13 @@ where
     }
 }
 
+impl<L, R> FusedGenerator for Either<L, R>
+where
+    L: FusedGenerator,
+    R: FusedGenerator<Output = L::Output>,
+{
+}
+
 impl<T: Generator> Generator for &mut T {
     type Output = T::Output;
 
@@ -199,3 +215,17 @@ impl<T: Generator> Generator for &mut T {
         (**self).try_advance(n)
     }
 }
+
+impl<T: ReverseGenerator> ReverseGenerator for &mut T {
+    #[inline]
+    fn run_back(&mut self, output: impl FnMut(Self::Output) -> ValueResult) -> GeneratorResult {
+        (**self).run_back(output)
+    }
+
+    #[inline]
+    fn try_advance_back(&mut self, n: NonZeroUsize) -> (usize, GeneratorResult) {
+        (**self).try_advance_back(n)
+    }
+}
+
+impl<T: FusedGenerator> FusedGenerator for &mut T {}
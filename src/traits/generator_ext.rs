@@ -1,14 +1,22 @@
-use crate::structs::utility::InplaceUpdatable;
+use crate::structs::utility::{non_zero, unwrap_some, ArrayBuilder, InplaceUpdatable};
 use crate::structs::{
-    Chain, Cloned, Copied, Cycle, Dedup, Enumerate, Filter, FilterMap, Flatten, Inspect,
-    IteratorAdaptor, Map, Reverse, Scan, Skip, SkipWhile, StepBy, Take, TakeWhile, Zip,
+    ArrayChunks, Batching, CartesianProduct, Chain, Cloned, Coalesce, Copied, CumProd, CumSum,
+    Cycle, CycleN, Dedup, DedupBy, DedupByKey, DedupWithCount, Diff, DiffBy, Enumerate, ErrInto,
+    Filter, FilterMap, FilterOk, FlatMap, Flatten, FlattenOk, FlattenOption, FlattenWithSep,
+    FormatWith, Fuse, Gather, GroupBy, HomogeneousTuple, Inspect, Interleave, InterleaveShortest,
+    Intersperse, IntersperseWith, IteratorAdaptor, Map, MapErr, MapInto, MapOk, MapWhile,
+    MapWindows, Merge, MergeBy, MergeByKey, PadUsing, Peekable, Positions, Reverse, Rld, Rle, Scan,
+    Skip, SkipWhile, StepBy, Take, TakeWhile, TakeWhileInclusive, TryZipEq, TupleWindow,
+    TupleWindows, Tuples, UnwrapOr, UnwrapOrElse, Update, WhileSome, WithPosition, Zip, ZipEq,
+    ZipLongest, ZipWith,
 };
-use crate::traits::{FromGenerator, Product, Sum};
+use crate::traits::{FromGenerator, Product, SaturatingSum, Sum};
 use crate::{
-    Generator, GeneratorResult, IntoGenerator, ReverseGenerator, TryReduction, ValueResult,
+    Either, ExactlyOneError, Generator, GeneratorResult, IntoGenerator, ReverseGenerator,
+    TryReduction, TryStep, ValueResult,
 };
 use core::cmp::Ordering;
-use core::num::NonZeroUsize;
+use core::ops::AddAssign;
 
 pub trait Sealed {}
 
@@ -131,6 +139,78 @@ pub trait GeneratorExt: Sealed + Generator + Sized {
         retval
     }
 
+    /// Tests if all generated values are equal.
+    ///
+    /// `all_equal()` is short-circuiting; it will stop processing as soon as it finds a value that
+    /// differs from the first one. The generator can be resumed afterwards, the mismatching value
+    /// has been consumed.
+    ///
+    /// An empty generator returns true.
+    ///
+    /// ## Spuriously stopping generators
+    ///
+    /// A stopped generator is treated like a completed one: `true` is returned if all values
+    /// generated before the stop were equal, including when the generator stops before generating
+    /// any value. Calling `all_equal()` again after resuming only compares the values generated
+    /// after the stop.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// assert!([1, 1, 1].into_gen().all_equal());
+    /// assert!(![1, 2, 1].into_gen().all_equal());
+    ///
+    /// let mut gen = [1, 2, 3].into_gen();
+    /// assert!(!gen.all_equal());
+    /// assert_eq!(gen.next(), Ok(3));
+    /// ```
+    #[inline]
+    fn all_equal(&mut self) -> bool
+    where
+        Self::Output: PartialEq,
+    {
+        match self.next() {
+            Ok(first) => self.all(|x| x == first),
+            Err(_) => true,
+        }
+    }
+
+    /// Tests if all generated values are distinct.
+    ///
+    /// Every value is stored in a [`HashSet`](std::collections::HashSet) until the first repeated
+    /// value is found. `all_unique()` is short-circuiting; it will stop processing as soon as it
+    /// finds a repeated value. The generator can be resumed afterwards, the repeated value has been
+    /// consumed.
+    ///
+    /// An empty generator returns true.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// assert!([1, 2, 3].into_gen().all_unique());
+    /// assert!(![1, 2, 1].into_gen().all_unique());
+    ///
+    /// let mut gen = [1, 2, 1, 4].into_gen();
+    /// assert!(!gen.all_unique());
+    /// assert_eq!(gen.next(), Ok(4));
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    fn all_unique(&mut self) -> bool
+    where
+        Self::Output: Eq + core::hash::Hash,
+    {
+        let mut seen = std::collections::HashSet::new();
+        self.all(|x| seen.insert(x))
+    }
+
     /// Repeats a generator endlessly.
     ///
     /// Instead of stopping when a generator has completed, the generator will start over again
@@ -139,6 +219,9 @@ pub trait GeneratorExt: Sealed + Generator + Sized {
     /// The generator will only start over once the source generator has completed. Spuriously
     /// stopping generators will **not** cause the source to start over again.
     ///
+    /// If a pass over the source generator completes without generating any values the cycle
+    /// completes as well, so cycling an empty generator doesn't run forever.
+    ///
     /// ## Examples
     ///
     /// Basic usage:
@@ -162,6 +245,65 @@ pub trait GeneratorExt: Sealed + Generator + Sized {
     {
         Cycle::new(self)
     }
+
+    /// Repeats a generator `times` times.
+    ///
+    /// Like [`cycle()`](GeneratorExt::cycle), but the generator completes after the source
+    /// generator has completed `times` times. The source is only started over once it has
+    /// completed, so spuriously stopping generators can be resumed in the middle of any pass.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{GeneratorExt, IntoGenerator};
+    /// let data = [1, 2];
+    /// let output: Vec<_> = data.into_gen().cycle_n(3).collect();
+    /// assert_eq!(output, [1, 2, 1, 2, 1, 2]);
+    /// ```
+    #[inline]
+    fn cycle_n(self, times: usize) -> CycleN<Self>
+    where
+        Self: Clone,
+    {
+        CycleN::new(self, times)
+    }
+
+    /// Creates a generator that never generates values after it has completed.
+    ///
+    /// After the source generator has returned [`GeneratorResult::Complete`] once, the fused
+    /// generator always returns `Complete` without running the source again. A spuriously stopped
+    /// generator is not complete, and can still be resumed.
+    ///
+    /// Generators that already behave this way implement the [`FusedGenerator`] marker trait,
+    /// which `Fuse` implements as well.
+    ///
+    /// [`FusedGenerator`]: crate::FusedGenerator
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{GeneratorExt, GeneratorResult};
+    /// let mut count = 0;
+    /// // Generates a single value each time it is run.
+    /// let mut gen = pushgen::from_fn(move || {
+    ///     count += 1;
+    ///     if count % 2 == 1 { Some(count) } else { None }
+    /// })
+    /// .fuse();
+    ///
+    /// assert_eq!(gen.next(), Ok(1));
+    /// assert_eq!(gen.next(), Err(GeneratorResult::Complete));
+    /// assert_eq!(gen.next(), Err(GeneratorResult::Complete));
+    /// ```
+    #[inline]
+    fn fuse(self) -> Fuse<Self> {
+        Fuse::new(self)
+    }
+
     /// Retrieve the next value from the generator
     ///
     /// If the generator is completed or stopped before a value is retrieved an `Err(GeneratorResult)`
@@ -255,6 +397,52 @@ pub trait GeneratorExt: Sealed + Generator + Sized {
         res
     }
 
+    /// Returns the only value of the generator.
+    ///
+    /// If the generator generates exactly one value, that value is returned. Otherwise an
+    /// [`ExactlyOneError`] is returned, telling if the generator was empty or generated more than
+    /// one value. In the latter case the first two values are kept in the error.
+    ///
+    /// `exactly_one()` stops the generator as soon as a second value is generated.
+    ///
+    /// ## Spuriously stopping generators
+    ///
+    /// If the generator stops before it completes, [`ExactlyOneError::Stopped`] is returned,
+    /// holding the value generated before the stop, if any. No value is lost: a second value is
+    /// only pulled from the generator after the first one.
+    ///
+    /// A stop is not reported as [`ExactlyOneError::Empty`] or as `Ok`, since the generator may
+    /// still generate more values. A caller that resumes the generator has to account for the
+    /// value held by the error itself.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{ExactlyOneError, IntoGenerator, GeneratorExt};
+    /// let a = [1, 2, 3];
+    /// assert_eq!(a.into_gen().filter(|x| *x == 2).exactly_one(), Ok(2));
+    /// assert_eq!(a.into_gen().filter(|x| *x > 3).exactly_one(), Err(ExactlyOneError::Empty));
+    /// assert_eq!(
+    ///     a.into_gen().exactly_one(),
+    ///     Err(ExactlyOneError::MoreThanOne(1, 2))
+    /// );
+    /// ```
+    #[inline]
+    fn exactly_one(&mut self) -> Result<Self::Output, ExactlyOneError<Self::Output>> {
+        let first = match self.next() {
+            Ok(first) => first,
+            Err(GeneratorResult::Complete) => return Err(ExactlyOneError::Empty),
+            Err(GeneratorResult::Stopped) => return Err(ExactlyOneError::Stopped(None)),
+        };
+        match self.next() {
+            Ok(second) => Err(ExactlyOneError::MoreThanOne(first, second)),
+            Err(GeneratorResult::Complete) => Ok(first),
+            Err(GeneratorResult::Stopped) => Err(ExactlyOneError::Stopped(Some(first))),
+        }
+    }
+
     /// Creates a generator that clones all of its elements.
     ///
     /// This is useful when you have a generator that generates `&T` but you need a generate
@@ -389,6 +577,28 @@ pub trait GeneratorExt: Sealed + Generator + Sized {
         FilterMap::new(self, f)
     }
 
+    /// Creates a generator of the values inside `Some`, skipping every `None`.
+    ///
+    /// This is the same as `.filter_map(|x| x)`, without the closure.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let a = [Some(1), None, Some(2), None, Some(3)];
+    /// let output: Vec<_> = a.into_gen().flatten_option().collect();
+    /// assert_eq!(output, [1, 2, 3]);
+    /// ```
+    #[inline]
+    fn flatten_option<T>(self) -> FlattenOption<Self>
+    where
+        Self: Generator<Output = Option<T>>,
+    {
+        FlattenOption::new(self)
+    }
+
     /// Takes a closure and creates a generator which  calls the closure on each value.
     ///
     /// ## Example
@@ -407,304 +617,1966 @@ pub trait GeneratorExt: Sealed + Generator + Sized {
         Map::new(self, transform_fn)
     }
 
-    /// Skips over `n` values, consuming and ignoring them.
+    /// Converts each value with [`Into`].
+    ///
+    /// This is the same as `map(Into::into)`, with the target type given as a type parameter
+    /// instead of needing an annotation at every call site.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
     ///
-    /// ## Example
-    ///```
-    /// # use pushgen::{GeneratorExt, SliceGenerator};
-    /// # use pushgen::structs::Skip;
-    /// let input = [1,2,3,4];
-    /// let mut skipped_generator = SliceGenerator::new(&input).skip(2);
-    /// let mut output: Vec<i32> = Vec::new();
-    /// skipped_generator.for_each(|x| output.push(*x));
-    /// assert_eq!(output, [3,4]);
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let a = [1u8, 2, 3];
+    /// let sum: u32 = a.into_gen().map_into::<u32>().map(|x| x * 100).sum();
+    /// assert_eq!(sum, 600);
     /// ```
     #[inline]
-    fn skip(self, n: usize) -> Skip<Self> {
-        Skip::new(self, n)
+    fn map_into<U>(self) -> MapInto<Self, U>
+    where
+        Self::Output: Into<U>,
+    {
+        MapInto::new(self)
     }
 
-    /// Creates a generator that skips values based on a predicate.
+    /// Creates a generator that uses each generated index to look up a value in `source`.
     ///
-    /// `skip_while()` takes a closure as argument. It will call this closure on each value,
-    /// and ignore values until the closure returns `false`.
+    /// This allows selecting or permuting the values of a slice with a generator of indices.
     ///
-    /// After `false` is returned, `skip_while()` will push the rest of the values.
+    /// ## Panics
+    ///
+    /// Panics if an index is out of range for `source`.
     ///
     /// ## Examples
     ///
-    /// Basic usage
+    /// Basic usage:
     ///
-    /// ```rust
+    /// ```
     /// use pushgen::{IntoGenerator, GeneratorExt};
-    /// let a = [-1i32, 0, 1];
-    /// let mut output = Vec::new();
-    /// a.into_gen().skip_while(|x| x.is_negative()).for_each(|x| output.push(x));
-    /// assert_eq!(output, [0, 1]);
+    /// let data = ["zero", "one", "two", "three"];
+    /// let output: Vec<_> = [3, 1, 1].into_gen().gather(&data).collect();
+    /// assert_eq!(output, [&"three", &"one", &"one"]);
     /// ```
     #[inline]
-    fn skip_while<P>(self, predicate: P) -> SkipWhile<Self, P>
+    fn gather<T>(self, source: &[T]) -> Gather<'_, Self, T>
     where
-        P: FnMut(&Self::Output) -> bool,
+        Self: Generator<Output = usize>,
     {
-        SkipWhile::new(self, predicate)
+        Gather::new(self, source)
     }
 
-    /// Takes `n` values and then completes the generator.
+    /// Maps the `Ok` values of a generator of `Result`s, passing `Err` values on unchanged.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
     ///
-    /// ## Example
     /// ```
-    /// use pushgen::{SliceGenerator, GeneratorExt};
-    /// let data = [1, 2, 3, 4];
-    /// let mut output: Vec<i32> = Vec::new();
-    /// SliceGenerator::new(&data).take(2).for_each(|x| output.push(*x));
-    /// assert_eq!(output, [1, 2]);
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let a = [Ok(1), Err("bad"), Ok(3)];
+    /// let output: Vec<_> = a.into_gen().map_ok(|x| x * 2).collect();
+    /// assert_eq!(output, [Ok(2), Err("bad"), Ok(6)]);
     /// ```
     #[inline]
-    fn take(self, n: usize) -> Take<Self> {
-        Take::new(self, n)
+    fn map_ok<T, E, U, F>(self, transform: F) -> MapOk<Self, F>
+    where
+        Self: Generator<Output = Result<T, E>>,
+        F: FnMut(T) -> U,
+    {
+        MapOk::new(self, transform)
     }
 
-    /// Creates a generator that pushes values based on a predicate.
+    /// Filters the `Ok` values of a generator of `Result`s, passing `Err` values on unchanged.
     ///
-    /// `take_while()` takes a closure as an argument. It will call this closure on each value
-    /// received from the source generator, and push values while it returns true. After `false` is
-    /// returned, `take_while()`'s job is over and it will always report `Complete`.
+    /// `Ok` values for which `predicate` returns `false` are removed.
     ///
     /// ## Examples
     ///
     /// Basic usage:
     ///
-    /// ```rust
+    /// ```
     /// use pushgen::{IntoGenerator, GeneratorExt};
-    /// let a = [-1i32, 0, 1];
-    ///
-    /// let mut gen_as_iter = a.into_gen().take_while(|x| x.is_negative()).iter();
-    ///
-    /// assert_eq!(gen_as_iter.next(), Some(-1));
-    /// assert_eq!(gen_as_iter.next(), None);
+    /// let a = [Ok(1), Err("bad"), Ok(2), Ok(3)];
+    /// let output: Vec<_> = a.into_gen().filter_ok(|x| x % 2 == 1).collect();
+    /// assert_eq!(output, [Ok(1), Err("bad"), Ok(3)]);
     /// ```
     #[inline]
-    fn take_while<P>(self, predicate: P) -> TakeWhile<Self, P>
+    fn filter_ok<T, E, P>(self, predicate: P) -> FilterOk<Self, P>
     where
-        P: FnMut(&Self::Output) -> bool,
+        Self: Generator<Output = Result<T, E>>,
+        P: FnMut(&T) -> bool,
     {
-        TakeWhile::new(self, predicate)
+        FilterOk::new(self, predicate)
     }
 
-    /// Creates a generator that works like map, but flattens nested structure.
-    ///
-    /// The [`map`] adapter is very useful, but only when the closure
-    /// argument produces values. If it produces a generator instead, there's
-    /// an extra layer of indirection. `flat_map()` will remove this extra layer
-    /// on its own.
-    ///
-    /// You can think of `flat_map(f)` as the semantic equivalent
-    /// of [`map`]ping, and then [`flatten`]ing as in `map(f).flatten()`.
-    ///
-    /// Another way of thinking about `flat_map()`: [`map`]'s closure returns
-    /// one item for each element, and `flat_map()`'s closure returns an
-    /// iterator for each element.
-    ///
-    /// [`map`]: GeneratorExt::map
-    /// [`flatten`]: GeneratorExt::flatten
+    /// Maps the `Err` values of a generator of `Result`s, passing `Ok` values on unchanged.
     ///
-    /// # Examples
+    /// ## Examples
     ///
     /// Basic usage:
     ///
     /// ```
-    /// use pushgen::IntoGenerator;
-    /// use crate::pushgen::GeneratorExt;
-    ///
-    /// let words = ["alpha", "beta", "gamma"];
-    ///
-    /// let mut merged = String::new();
-    /// words.into_gen()
-    ///      .flat_map(|s| pushgen::from_iter(s.chars()))
-    ///      .for_each(|x| merged.push(x));
-    /// assert_eq!(merged, "alphabetagamma");
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let a = [Ok(1), Err(2), Ok(3)];
+    /// let output: Vec<_> = a.into_gen().map_err(|e| format!("error {}", e)).collect();
+    /// assert_eq!(output, [Ok(1), Err(String::from("error 2")), Ok(3)]);
     /// ```
     #[inline]
-    fn flat_map<U, F>(self, f: F) -> Flatten<Map<Self, F>>
+    fn map_err<T, E, U, F>(self, transform: F) -> MapErr<Self, F>
     where
-        U: crate::IntoGenerator,
-        F: FnMut(Self::Output) -> U,
+        Self: Generator<Output = Result<T, E>>,
+        F: FnMut(E) -> U,
     {
-        self.map(f).flatten()
+        MapErr::new(self, transform)
     }
 
-    /// Creates a generator that flattens nested structure.
+    /// Converts the `Err` values of a generator of `Result`s with [`Into`], passing `Ok` values on
+    /// unchanged.
     ///
-    /// This is useful when you have a generator of generators or a generator of
-    /// things that can be turned into generators and you want to remove one
-    /// level of indirection.
+    /// The error type must implement `Into<U>` for the returned generator to be usable.
     ///
-    /// # Examples
+    /// ## Examples
     ///
     /// Basic usage:
     ///
     /// ```
-    /// use pushgen::IntoGenerator;
-    /// use crate::pushgen::GeneratorExt;
-    ///
-    /// let data = vec![vec![1, 2, 3, 4], vec![5, 6]];
-    /// let mut output: Vec<i32> = Vec::new();
-    /// let flattened = data.into_gen().flatten().for_each(|x| output.push(x));
-    /// assert_eq!(output, [1, 2, 3, 4, 5, 6]);
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let a: [Result<i32, u8>; 2] = [Ok(1), Err(2)];
+    /// let output: Vec<_> = a.into_gen().err_into::<u32>().collect();
+    /// assert_eq!(output, [Ok(1), Err(2u32)]);
     /// ```
+    #[inline]
+    fn err_into<U>(self) -> ErrInto<Self, U> {
+        ErrInto::new(self)
+    }
+
+    /// Flattens the `Ok` values of a generator of `Result`s, passing `Err` values on unchanged.
     ///
-    /// Mapping and then flattening:
+    /// Each `Ok` value is converted to a generator with [`IntoGenerator`], and its values are
+    /// generated wrapped in `Ok`.
     ///
-    /// ```
-    /// use pushgen::IntoGenerator;
-    /// use crate::pushgen::GeneratorExt;
+    /// ## Examples
     ///
-    /// let words = &["alpha", "beta", "gamma"];
+    /// Basic usage:
     ///
-    /// let mut merged = String::new();
-    /// words.into_gen()
-    ///      .map(|s| pushgen::from_iter(s.chars()))
-    ///      .flatten()
-    ///      .for_each(|x| merged.push(x));
-    /// assert_eq!(merged, "alphabetagamma");
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let a = vec![Ok(vec![1, 2]), Err("bad"), Ok(vec![3])];
+    /// let output: Vec<_> = a.into_gen().flatten_ok().collect();
+    /// assert_eq!(output, [Ok(1), Ok(2), Err("bad"), Ok(3)]);
     /// ```
     #[inline]
-    fn flatten(self) -> Flatten<Self>
+    fn flatten_ok<T, E>(self) -> FlattenOk<Self, T>
     where
-        Self::Output: crate::IntoGenerator,
+        Self: Generator<Output = Result<T, E>>,
+        T: IntoGenerator,
     {
-        Flatten::new(self)
+        FlattenOk::new(self)
     }
 
-    /// Run a generator to completion, or until it is stopped, and call a closure for each value
-    /// produced by the generator.
+    /// Creates a generator of the values inside `Some`, that completes at the first `None`.
     ///
-    /// The closure will be called for as long as the generator produces values, it is not possible
-    /// to abort processing early. If early abort is needed, use [`Generator::run`](crate::Generator::run)
-    /// ## Example
-    /// ```
-    /// # use pushgen::{GeneratorExt, GeneratorResult, SliceGenerator};
-    /// let mut sum = 0i32;
-    /// let data = [1,2,3];
-    /// let result = SliceGenerator::new(&data).for_each(|x| sum += x);
-    /// assert_eq!(sum, 6);
-    /// assert_eq!(result, GeneratorResult::Complete);
+    /// Once a `None` has been seen the generator stays complete, even if the source has more
+    /// values. This is useful when the source signals the end of the data in-band.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let a = [Some(1), Some(2), None, Some(4)];
+    /// let output: Vec<_> = a.into_gen().while_some().collect();
+    /// assert_eq!(output, [1, 2]);
     /// ```
     #[inline]
-    fn for_each<Func>(&mut self, mut func: Func) -> GeneratorResult
+    fn while_some<T>(self) -> WhileSome<Self>
     where
-        Func: FnMut(Self::Output),
+        Self: Generator<Output = Option<T>>,
     {
-        self.run(move |value| {
-            func(value);
-            ValueResult::MoreValues
-        })
+        WhileSome::new(self)
     }
 
-    /// A generator method that applies a fallible function to each item
-    /// produced, stopping at the first error and returning that error.
+    /// Creates a generator that replaces each `Err` value with `default`.
     ///
-    /// This can also be thought of as the fallible form of [`for_each()`]
-    /// or as the stateless version of [`try_fold()`].
+    /// This is useful to turn a generator of `Result<T, E>` into a generator of `T` without dropping
+    /// any values.
     ///
-    /// [`for_each()`]: GeneratorExt::for_each
-    /// [`try_fold()`]: GeneratorExt::try_fold
+    /// ## Examples
     ///
-    /// # Examples
+    /// Basic usage:
     ///
     /// ```
-    /// use std::fs::rename;
-    /// use std::io::{stdout, Write};
-    /// use std::path::Path;
-    /// use pushgen::{SliceGenerator, GeneratorExt};
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let a = [Ok(1), Err("x"), Ok(3)];
+    /// let output: Vec<_> = a.into_gen().unwrap_or(0).collect();
+    /// assert_eq!(output, [1, 0, 3]);
+    /// ```
+    #[inline]
+    fn unwrap_or<T, E>(self, default: T) -> UnwrapOr<Self, T>
+    where
+        Self: Generator<Output = Result<T, E>>,
+        T: Clone,
+    {
+        UnwrapOr::new(self, default)
+    }
+
+    /// Creates a generator that replaces each `Err` value with the value computed by a closure.
     ///
-    /// let data = ["no_tea.txt", "stale_bread.json", "torrential_rain.png"];
+    /// The closure is called with the error value and should return the replacement value.
     ///
-    /// let res = SliceGenerator::new(&data).try_for_each(|x| writeln!(stdout(), "{}", x));
-    /// assert!(res.is_ok());
+    /// ## Examples
     ///
-    /// let mut gen = SliceGenerator::new(&data);
-    /// let res = gen.try_for_each(|x| rename(x, Path::new(x).with_extension("old")));
-    /// assert!(res.is_err());
-    /// // It short-circuited, so the remaining items are still in the generator:
-    /// let mut output: Vec<&'static str> = Vec::new();
-    /// gen.for_each(|x| output.push(*x));
-    /// assert_eq!(output, ["stale_bread.json", "torrential_rain.png"]);
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let a = [Ok(1), Err("two"), Ok(3)];
+    /// let output: Vec<_> = a.into_gen().unwrap_or_else(|e| e.len()).collect();
+    /// assert_eq!(output, [1, 3, 3]);
     /// ```
     #[inline]
-    fn try_for_each<F, E>(&mut self, mut f: F) -> Result<(), E>
+    fn unwrap_or_else<T, E, F>(self, f: F) -> UnwrapOrElse<Self, F>
     where
-        F: FnMut(Self::Output) -> Result<(), E>,
+        Self: Generator<Output = Result<T, E>>,
+        F: FnMut(E) -> T,
     {
-        let mut res = Ok(());
-        let res_mut = &mut res;
-        self.run(move |value| match f(value) {
-            Ok(()) => ValueResult::MoreValues,
-            Err(e) => {
-                *res_mut = Err(e);
-                ValueResult::Stop
-            }
-        });
-        res
+        UnwrapOrElse::new(self, f)
     }
 
-    /// Zips the output of two generators into a single generator of pairs.
+    /// Skips over `n` values, consuming and ignoring them.
     ///
-    /// `zip()` returns a new generator that will use values from two generators, outputting
-    /// a tuple where the first element comes from the first generator, and the second element comes
-    /// from the second generator.
+    /// ## Example
+    ///```
+    /// # use pushgen::{GeneratorExt, SliceGenerator};
+    /// # use pushgen::structs::Skip;
+    /// let input = [1,2,3,4];
+    /// let mut skipped_generator = SliceGenerator::new(&input).skip(2);
+    /// let mut output: Vec<i32> = Vec::new();
+    /// skipped_generator.for_each(|x| output.push(*x));
+    /// assert_eq!(output, [3,4]);
+    /// ```
+    #[inline]
+    fn skip(self, n: usize) -> Skip<Self> {
+        Skip::new(self, n)
+    }
+
+    /// Creates a generator that skips the last `n` values of the source.
     ///
-    /// The zip generator will complete when either generator completes.
+    /// Up to `n` values are held back in a buffer, and each new value pushes the oldest buffered
+    /// value out. When the source completes, the buffered values are dropped.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let a = [1, 2, 3, 4, 5];
+    /// let output: Vec<_> = a.into_gen().dropping_back(2).collect();
+    /// assert_eq!(output, [1, 2, 3]);
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    fn dropping_back(self, n: usize) -> crate::structs::DroppingBack<Self> {
+        crate::structs::DroppingBack::new(self, n)
+    }
+
+    /// Creates a generator that skips values based on a predicate.
+    ///
+    /// `skip_while()` takes a closure as argument. It will call this closure on each value,
+    /// and ignore values until the closure returns `false`.
+    ///
+    /// After `false` is returned, `skip_while()` will push the rest of the values.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage
+    ///
+    /// ```rust
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let a = [-1i32, 0, 1];
+    /// let mut output = Vec::new();
+    /// a.into_gen().skip_while(|x| x.is_negative()).for_each(|x| output.push(x));
+    /// assert_eq!(output, [0, 1]);
+    /// ```
+    #[inline]
+    fn skip_while<P>(self, predicate: P) -> SkipWhile<Self, P>
+    where
+        P: FnMut(&Self::Output) -> bool,
+    {
+        SkipWhile::new(self, predicate)
+    }
+
+    /// Takes `n` values and then completes the generator.
     ///
     /// ## Example
     /// ```
     /// use pushgen::{SliceGenerator, GeneratorExt};
-    /// let left = [1, 2, 3];
-    /// let right = [4, 5, 6];
-    /// let mut output: Vec<(i32, i32)> = Vec::new();
-    /// SliceGenerator::new(&left).zip(SliceGenerator::new(&right)).for_each(|(a, b)| output.push((*a, *b)));
-    /// assert_eq!(output, [(1,4), (2, 5), (3, 6)]);
+    /// let data = [1, 2, 3, 4];
+    /// let mut output: Vec<i32> = Vec::new();
+    /// SliceGenerator::new(&data).take(2).for_each(|x| output.push(*x));
+    /// assert_eq!(output, [1, 2]);
     /// ```
     #[inline]
-    fn zip<Right>(self, right: Right) -> Zip<Self, Right>
+    fn take(self, n: usize) -> Take<Self> {
+        Take::new(self, n)
+    }
+
+    /// Takes `n` values from the back of the generator and then completes, generating the values
+    /// in reverse order.
+    ///
+    /// This is the reverse sibling of [`take()`](GeneratorExt::take), and is equivalent to
+    /// `gen.rev().take(n)`. Values from the front of the generator are left untouched, so using
+    /// [`by_ref()`](GeneratorExt::by_ref) the remaining values can still be generated afterwards.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{SliceGenerator, GeneratorExt};
+    /// let data = [1, 2, 3, 4];
+    /// let mut gen = SliceGenerator::new(&data);
+    ///
+    /// let back: Vec<_> = gen.by_ref().rev_take(2).collect();
+    /// assert_eq!(back, [&4, &3]);
+    ///
+    /// let front: Vec<_> = gen.collect();
+    /// assert_eq!(front, [&1, &2]);
+    /// ```
+    #[inline]
+    fn rev_take(self, n: usize) -> Take<Reverse<Self>>
     where
-        Right: Generator,
+        Self: ReverseGenerator,
     {
-        Zip::new(self, right)
+        self.rev().take(n)
     }
 
-    /// Create a de-duplicating generator, removing consecutive duplicate values.
+    /// Creates a generator of the last `n` values of the source.
     ///
-    /// Values will be made available when a non-duplicate is detected. If the up-stream generator generates
-    /// the following sequence: `[1, 2, 3, 3, 4]` then the value `1` will be generated from the
-    /// `Dedup` generator once the value `2` has been generated by the upstream generator and so
-    /// on.
+    /// All other values are discarded as they arrive, so at most `n` values are buffered. Nothing
+    /// is generated until the source completes.
     ///
-    /// | Upstream value | Dedup-generated value |
-    /// |----------------|-----------------------|
-    /// | 1              | *None*                |
-    /// | 2              | 1                     |
-    /// | 3              | 2                     |
-    /// | 3              | *Ignored*             |
-    /// | 4              | 3                     |
-    /// | *Complete*     | 4                     |
-    /// | *Complete*     | *Complete*            |
+    /// Unlike [`rev_take()`](GeneratorExt::rev_take) the source doesn't have to be a
+    /// [`ReverseGenerator`], and the values are generated in their original order.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
     ///
-    /// ## Example
     /// ```
-    /// # use pushgen::{SliceGenerator, GeneratorExt};
-    /// let data = [1, 2, 3, 3, 3, 3, 4, 3];
-    /// let mut output: Vec<i32> = Vec::new();
-    /// SliceGenerator::new(&data).dedup().for_each(|x| output.push(*x));
-    /// assert_eq!(output, [1, 2, 3, 4, 3]);
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let a = [1, 2, 3, 4, 5];
+    /// let output: Vec<_> = a.into_gen().tail(2).collect();
+    /// assert_eq!(output, [4, 5]);
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    fn tail(self, n: usize) -> crate::structs::Tail<Self> {
+        crate::structs::Tail::new(self, n)
+    }
+
+    /// Creates a generator that pushes values based on a predicate.
+    ///
+    /// `take_while()` takes a closure as an argument. It will call this closure on each value
+    /// received from the source generator, and push values while it returns true. After `false` is
+    /// returned, `take_while()`'s job is over and it will always report `Complete`.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let a = [-1i32, 0, 1];
+    ///
+    /// let mut gen_as_iter = a.into_gen().take_while(|x| x.is_negative()).iter();
+    ///
+    /// assert_eq!(gen_as_iter.next(), Some(-1));
+    /// assert_eq!(gen_as_iter.next(), None);
+    /// ```
+    #[inline]
+    fn take_while<P>(self, predicate: P) -> TakeWhile<Self, P>
+    where
+        P: FnMut(&Self::Output) -> bool,
+    {
+        TakeWhile::new(self, predicate)
+    }
+
+    /// Creates a generator that pushes values while `predicate` returns `true`, and then pushes
+    /// the first value for which it returns `false`.
+    ///
+    /// Like [`take_while()`](GeneratorExt::take_while) the generator completes once `predicate`
+    /// returns `false`, but the failing value is generated as well. This is useful when reading up
+    /// to and including a terminator.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let a = [b'a', b'b', b'\n', b'c'];
+    /// let line: Vec<_> = a.into_gen().take_while_inclusive(|x| *x != b'\n').collect();
+    /// assert_eq!(line, b"ab\n");
+    /// ```
+    #[inline]
+    fn take_while_inclusive<P>(self, predicate: P) -> TakeWhileInclusive<Self, P>
+    where
+        P: FnMut(&Self::Output) -> bool,
+    {
+        TakeWhileInclusive::new(self, predicate)
+    }
+
+    /// Creates a generator that both maps and pushes values while the closure returns `Some`.
+    ///
+    /// `map_while()` takes a closure as an argument. It will call this closure on each value
+    /// received from the source generator, and push the mapped values while it returns `Some`.
+    /// After `None` is returned, `map_while()`'s job is over and it will always report `Complete`.
+    ///
+    /// This differs from [`filter_map()`](GeneratorExt::filter_map), which skips values the
+    /// closure returns `None` for and keeps going.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use pushgen::{IntoGenerator, GeneratorExt, GeneratorResult};
+    /// let a = ["1", "2", "three", "4"];
+    ///
+    /// let mut gen = a.into_gen().map_while(|s| s.parse::<i32>().ok());
+    ///
+    /// assert_eq!(gen.next(), Ok(1));
+    /// assert_eq!(gen.next(), Ok(2));
+    /// assert_eq!(gen.next(), Err(GeneratorResult::Complete));
+    /// ```
+    #[inline]
+    fn map_while<B, F>(self, func: F) -> MapWhile<Self, F>
+    where
+        F: FnMut(Self::Output) -> Option<B>,
+    {
+        MapWhile::new(self, func)
+    }
+
+    /// Creates a generator that works like map, but flattens nested structure.
+    ///
+    /// The [`map`] adapter is very useful, but only when the closure
+    /// argument produces values. If it produces a generator instead, there's
+    /// an extra layer of indirection. `flat_map()` will remove this extra layer
+    /// on its own.
+    ///
+    /// You can think of `flat_map(f)` as the semantic equivalent
+    /// of [`map`]ping, and then [`flatten`]ing as in `map(f).flatten()`.
+    ///
+    /// Another way of thinking about `flat_map()`: [`map`]'s closure returns
+    /// one item for each element, and `flat_map()`'s closure returns an
+    /// iterator for each element.
+    ///
+    /// [`map`]: GeneratorExt::map
+    /// [`flatten`]: GeneratorExt::flatten
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::IntoGenerator;
+    /// use crate::pushgen::GeneratorExt;
+    ///
+    /// let words = ["alpha", "beta", "gamma"];
+    ///
+    /// let mut merged = String::new();
+    /// words.into_gen()
+    ///      .flat_map(|s| pushgen::from_iter(s.chars()))
+    ///      .for_each(|x| merged.push(x));
+    /// assert_eq!(merged, "alphabetagamma");
+    /// ```
+    #[inline]
+    fn flat_map<U, F>(self, f: F) -> FlatMap<Self, F, U>
+    where
+        U: crate::IntoGenerator,
+        F: FnMut(Self::Output) -> U,
+    {
+        FlatMap::new(self, f)
+    }
+
+    /// Creates a generator that flattens nested structure.
+    ///
+    /// This is useful when you have a generator of generators or a generator of
+    /// things that can be turned into generators and you want to remove one
+    /// level of indirection.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::IntoGenerator;
+    /// use crate::pushgen::GeneratorExt;
+    ///
+    /// let data = vec![vec![1, 2, 3, 4], vec![5, 6]];
+    /// let mut output: Vec<i32> = Vec::new();
+    /// let flattened = data.into_gen().flatten().for_each(|x| output.push(x));
+    /// assert_eq!(output, [1, 2, 3, 4, 5, 6]);
+    /// ```
+    ///
+    /// Mapping and then flattening:
+    ///
+    /// ```
+    /// use pushgen::IntoGenerator;
+    /// use crate::pushgen::GeneratorExt;
+    ///
+    /// let words = &["alpha", "beta", "gamma"];
+    ///
+    /// let mut merged = String::new();
+    /// words.into_gen()
+    ///      .map(|s| pushgen::from_iter(s.chars()))
+    ///      .flatten()
+    ///      .for_each(|x| merged.push(x));
+    /// assert_eq!(merged, "alphabetagamma");
+    /// ```
+    #[inline]
+    fn flatten(self) -> Flatten<Self>
+    where
+        Self::Output: crate::IntoGenerator,
+    {
+        Flatten::new(self)
+    }
+
+    /// Creates a generator that flattens nested structure, placing a separator between the values
+    /// of adjacent sub-generators.
+    ///
+    /// The separator is generated when the next sub-generator is started, so no separator is
+    /// generated before the first or after the last sub-generator.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    ///
+    /// let data = vec![vec![1, 2], vec![3], vec![4, 5]];
+    /// let output: Vec<_> = data.into_gen().flatten_with_sep(0).collect();
+    /// assert_eq!(output, [1, 2, 0, 3, 0, 4, 5]);
+    /// ```
+    #[inline]
+    fn flatten_with_sep(
+        self,
+        separator: <Self::Output as IntoGenerator>::Output,
+    ) -> FlattenWithSep<Self>
+    where
+        Self::Output: IntoGenerator,
+        <Self::Output as IntoGenerator>::Output: Clone,
+    {
+        FlattenWithSep::new(self, separator)
+    }
+
+    /// Run a generator to completion, or until it is stopped, and call a closure for each value
+    /// produced by the generator.
+    ///
+    /// The closure will be called for as long as the generator produces values, it is not possible
+    /// to abort processing early. If early abort is needed, use [`Generator::run`](crate::Generator::run)
+    /// ## Example
+    /// ```
+    /// # use pushgen::{GeneratorExt, GeneratorResult, SliceGenerator};
+    /// let mut sum = 0i32;
+    /// let data = [1,2,3];
+    /// let result = SliceGenerator::new(&data).for_each(|x| sum += x);
+    /// assert_eq!(sum, 6);
+    /// assert_eq!(result, GeneratorResult::Complete);
+    /// ```
+    #[inline]
+    fn for_each<Func>(&mut self, mut func: Func) -> GeneratorResult
+    where
+        Func: FnMut(Self::Output),
+    {
+        self.run(move |value| {
+            func(value);
+            ValueResult::MoreValues
+        })
+    }
+
+    /// Run a generator to completion, or until it is stopped, calling a closure for each value and
+    /// counting the number of values processed.
+    ///
+    /// This works like [`for_each()`](GeneratorExt::for_each) but also returns the number of values
+    /// that were passed to the closure during this run.
+    ///
+    /// ## Panics
+    ///
+    /// The count might overflow and panic if more than `usize::MAX` values are processed.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{GeneratorExt, GeneratorResult, SliceGenerator};
+    /// let mut sum = 0i32;
+    /// let data = [1, 2, 3];
+    /// let result = SliceGenerator::new(&data).for_each_counted(|x| sum += x);
+    /// assert_eq!(sum, 6);
+    /// assert_eq!(result, (3, GeneratorResult::Complete));
+    /// ```
+    #[inline]
+    fn for_each_counted<Func>(&mut self, mut func: Func) -> (usize, GeneratorResult)
+    where
+        Func: FnMut(Self::Output),
+    {
+        let mut count = 0;
+        let result = self.for_each(|value| {
+            func(value);
+            count += 1;
+        });
+        (count, result)
+    }
+
+    /// A generator method that applies a fallible function to each item
+    /// produced, stopping at the first error and returning that error.
+    ///
+    /// This can also be thought of as the fallible form of [`for_each()`]
+    /// or as the stateless version of [`try_fold()`].
+    ///
+    /// [`for_each()`]: GeneratorExt::for_each
+    /// [`try_fold()`]: GeneratorExt::try_fold
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::fs::rename;
+    /// use std::io::{stdout, Write};
+    /// use std::path::Path;
+    /// use pushgen::{SliceGenerator, GeneratorExt};
+    ///
+    /// let data = ["no_tea.txt", "stale_bread.json", "torrential_rain.png"];
+    ///
+    /// let res = SliceGenerator::new(&data).try_for_each(|x| writeln!(stdout(), "{}", x));
+    /// assert!(res.is_ok());
+    ///
+    /// let mut gen = SliceGenerator::new(&data);
+    /// let res = gen.try_for_each(|x| rename(x, Path::new(x).with_extension("old")));
+    /// assert!(res.is_err());
+    /// // It short-circuited, so the remaining items are still in the generator:
+    /// let mut output: Vec<&'static str> = Vec::new();
+    /// gen.for_each(|x| output.push(*x));
+    /// assert_eq!(output, ["stale_bread.json", "torrential_rain.png"]);
+    /// ```
+    #[inline]
+    fn try_for_each<F, E>(&mut self, mut f: F) -> Result<(), E>
+    where
+        F: FnMut(Self::Output) -> Result<(), E>,
+    {
+        let mut res = Ok(());
+        let res_mut = &mut res;
+        self.run(move |value| match f(value) {
+            Ok(()) => ValueResult::MoreValues,
+            Err(e) => {
+                *res_mut = Err(e);
+                ValueResult::Stop
+            }
+        });
+        res
+    }
+
+    /// Zips the output of two generators into a single generator of pairs.
+    ///
+    /// `zip()` returns a new generator that will use values from two generators, outputting
+    /// a tuple where the first element comes from the first generator, and the second element comes
+    /// from the second generator.
+    ///
+    /// The zip generator will complete when either generator completes.
+    ///
+    /// ## Example
+    /// ```
+    /// use pushgen::{SliceGenerator, GeneratorExt};
+    /// let left = [1, 2, 3];
+    /// let right = [4, 5, 6];
+    /// let mut output: Vec<(i32, i32)> = Vec::new();
+    /// SliceGenerator::new(&left).zip(SliceGenerator::new(&right)).for_each(|(a, b)| output.push((*a, *b)));
+    /// assert_eq!(output, [(1,4), (2, 5), (3, 6)]);
+    /// ```
+    #[inline]
+    fn zip<Right>(self, right: Right) -> Zip<Self, Right>
+    where
+        Right: Generator,
+    {
+        Zip::new(self, right)
+    }
+
+    /// Zips the output of two generators, combining each pair of values with a closure.
+    ///
+    /// `a.zip_with(b, f)` generates the same values as `a.zip(b).map(|(l, r)| f(l, r))`, without
+    /// going through an intermediate tuple and an extra [`Map`] layer.
+    ///
+    /// The generator will complete when either generator completes.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let left = [1, 2, 3];
+    /// let right = [4, 5, 6];
+    /// let output: Vec<_> = left.into_gen().zip_with(right.into_gen(), |a, b| a * b).collect();
+    /// assert_eq!(output, [4, 10, 18]);
+    /// ```
+    #[inline]
+    fn zip_with<Right, F, O>(self, right: Right, f: F) -> ZipWith<Self, Right, F>
+    where
+        Right: Generator,
+        F: FnMut(Self::Output, Right::Output) -> O,
+    {
+        ZipWith::new(self, right, f)
+    }
+
+    /// Creates a generator of all pairs of values from two generators.
+    ///
+    /// For every value of `self` all values of `right` are generated, paired with the value from
+    /// `self`. `right` is cloned for every value of `self` so it can be restarted, which makes this
+    /// useful for grid and parameter sweeps.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let output: Vec<_> = [1, 2].into_gen().cartesian_product(['a', 'b'].into_gen()).collect();
+    /// assert_eq!(output, [(1, 'a'), (1, 'b'), (2, 'a'), (2, 'b')]);
+    /// ```
+    #[inline]
+    fn cartesian_product<Right>(self, right: Right) -> CartesianProduct<Self, Right>
+    where
+        Self::Output: Clone,
+        Right: Generator + Clone,
+    {
+        CartesianProduct::new(self, right)
+    }
+
+    /// Creates a generator of all `k`-combinations of the values, in lexicographic order of their
+    /// positions.
+    ///
+    /// Each combination is generated as a `Vec` of `k` values, taken in the order of the source.
+    /// All values of the source are collected first, so the source must be finite. `k == 0`
+    /// generates a single empty combination, and a `k` larger than the number of values generates
+    /// nothing.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let a = [1, 2, 3];
+    /// let output: Vec<_> = a.into_gen().combinations(2).collect();
+    /// assert_eq!(output, [vec![1, 2], vec![1, 3], vec![2, 3]]);
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    fn combinations(self, k: usize) -> crate::structs::Combinations<Self>
+    where
+        Self::Output: Clone,
+    {
+        crate::structs::Combinations::new(self, k)
+    }
+
+    /// Creates a generator of all `k`-permutations of the values, in lexicographic order of their
+    /// positions.
+    ///
+    /// Each permutation is generated as a `Vec` of `k` distinct values of the source. All values
+    /// of the source are collected first, so the source must be finite. `k == 0` generates a
+    /// single empty permutation, and a `k` larger than the number of values generates nothing.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let a = [1, 2, 3];
+    /// let output: Vec<_> = a.into_gen().permutations(2).collect();
+    /// assert_eq!(
+    ///     output,
+    ///     [vec![1, 2], vec![1, 3], vec![2, 1], vec![2, 3], vec![3, 1], vec![3, 2]]
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    fn permutations(self, k: usize) -> crate::structs::Permutations<Self>
+    where
+        Self::Output: Clone,
+    {
+        crate::structs::Permutations::new(self, k)
+    }
+
+    /// Creates a generator of all subsets of the values, from the smallest to the largest.
+    ///
+    /// Each subset is generated as a `Vec`, starting with the empty subset. Subsets of the same
+    /// size are generated in the same order as [`combinations()`](GeneratorExt::combinations).
+    /// All values of the source are collected first, so the source must be finite.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let a = [1, 2, 3];
+    /// let output: Vec<_> = a.into_gen().powerset().collect();
+    /// assert_eq!(
+    ///     output,
+    ///     [vec![], vec![1], vec![2], vec![3], vec![1, 2], vec![1, 3], vec![2, 3], vec![1, 2, 3]]
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    fn powerset(self) -> crate::structs::Powerset<Self>
+    where
+        Self::Output: Clone,
+    {
+        crate::structs::Powerset::new(self)
+    }
+
+    /// Merges two generators sorted in ascending order into one sorted generator.
+    ///
+    /// When both generators have equal values the value from `self` is generated first. At most
+    /// one value from each generator is buffered between runs.
+    ///
+    /// The output is only sorted if both inputs are sorted.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let output: Vec<_> = [1, 4, 5].into_gen().merge([2, 3, 6].into_gen()).collect();
+    /// assert_eq!(output, [1, 2, 3, 4, 5, 6]);
+    /// ```
+    #[inline]
+    fn merge<Right>(self, right: Right) -> Merge<Self, Right>
+    where
+        Self::Output: PartialOrd,
+        Right: Generator<Output = Self::Output>,
+    {
+        Merge::new(self, right)
+    }
+
+    /// Merges two sorted generators into one sorted generator, using a predicate to decide the
+    /// order.
+    ///
+    /// `take_left(left, right)` is called with the next value of each generator and should return
+    /// `true` if the value from `self` should be generated first. [`merge()`](GeneratorExt::merge)
+    /// is the same as `merge_by(right, |a, b| a <= b)`.
+    ///
+    /// ## Examples
+    ///
+    /// Merging generators sorted in descending order:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let output: Vec<_> = [5, 4, 1]
+    ///     .into_gen()
+    ///     .merge_by([6, 3, 2].into_gen(), |a, b| a >= b)
+    ///     .collect();
+    /// assert_eq!(output, [6, 5, 4, 3, 2, 1]);
+    /// ```
+    #[inline]
+    fn merge_by<Right, F>(self, right: Right, take_left: F) -> MergeBy<Self, Right, F>
+    where
+        Right: Generator<Output = Self::Output>,
+        F: FnMut(&Self::Output, &Self::Output) -> bool,
+    {
+        MergeBy::new(self, right, take_left)
+    }
+
+    /// Merges two generators sorted in ascending order by a key into one sorted generator.
+    ///
+    /// When both generators have values with equal keys the value from `self` is generated
+    /// first.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let left = [(1, "one"), (3, "three")];
+    /// let right = [(2, "two"), (4, "four")];
+    /// let output: Vec<_> = left
+    ///     .into_gen()
+    ///     .merge_by_key(right.into_gen(), |x| x.0)
+    ///     .map(|x| x.1)
+    ///     .collect();
+    /// assert_eq!(output, ["one", "two", "three", "four"]);
+    /// ```
+    #[inline]
+    fn merge_by_key<Right, F, K>(self, right: Right, key: F) -> MergeByKey<Self, Right, F>
+    where
+        Right: Generator<Output = Self::Output>,
+        F: FnMut(&Self::Output) -> K,
+        K: PartialOrd,
+    {
+        MergeByKey::new(self, right, key)
+    }
+
+    /// Zips two generators, continuing until both of them have completed.
+    ///
+    /// Values are generated as [`EitherOrBoth`](crate::structs::EitherOrBoth): `Both` while both
+    /// generators have values, then `Left` or `Right` with the remaining values of the longer
+    /// generator.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt, structs::EitherOrBoth};
+    /// let a = [1, 2, 3];
+    /// let b = ['a'];
+    /// let output: Vec<_> = a.into_gen().zip_longest(b.into_gen()).collect();
+    /// assert_eq!(
+    ///     output,
+    ///     [EitherOrBoth::Both(1, 'a'), EitherOrBoth::Left(2), EitherOrBoth::Left(3)]
+    /// );
+    /// ```
+    #[inline]
+    fn zip_longest<Right>(self, right: Right) -> ZipLongest<Self, Right>
+    where
+        Right: Generator,
+    {
+        ZipLongest::new(self, right)
+    }
+
+    /// Zips two generators that are expected to have the same length.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if one of the generators completes before the other one. Use
+    /// [`try_zip_eq()`](GeneratorExt::try_zip_eq) to get an error instead.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let a = [1, 2, 3];
+    /// let b = ['a', 'b', 'c'];
+    /// let output: Vec<_> = a.into_gen().zip_eq(b.into_gen()).collect();
+    /// assert_eq!(output, [(1, 'a'), (2, 'b'), (3, 'c')]);
+    /// ```
+    #[inline]
+    fn zip_eq<Right>(self, right: Right) -> ZipEq<Self, Right>
+    where
+        Right: Generator,
+    {
+        ZipEq::new(self, right)
+    }
+
+    /// Zips two generators that are expected to have the same length, generating an error if they
+    /// don't.
+    ///
+    /// Pairs are generated as `Ok` values. If one generator completes before the other one a
+    /// single [`ZipEqError`] is generated, telling which generator was longer, and the generator
+    /// completes.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt, ZipEqError};
+    /// let a = [1, 2, 3];
+    /// let b = ['a', 'b'];
+    /// let output: Vec<_> = a.into_gen().try_zip_eq(b.into_gen()).collect();
+    /// assert_eq!(output, [Ok((1, 'a')), Ok((2, 'b')), Err(ZipEqError::LeftLonger)]);
+    /// ```
+    ///
+    /// [`ZipEqError`]: crate::ZipEqError
+    #[inline]
+    fn try_zip_eq<Right>(self, right: Right) -> TryZipEq<Self, Right>
+    where
+        Right: Generator,
+    {
+        TryZipEq::new(self, right)
+    }
+
+    /// Creates a generator that alternates values from this generator and `other`.
+    ///
+    /// The first value is taken from this generator, then one from `other` and so on. Once either
+    /// generator completes, the remaining values of the other one are generated.
+    ///
+    /// ## Spuriously stopping generators
+    ///
+    /// If the generator whose turn it is stops, the interleaving generator stops as well. It
+    /// continues with the same generator when it is resumed.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let a = [1, 3, 5, 6];
+    /// let b = [2, 4];
+    /// let output: Vec<_> = a.into_gen().interleave(b.into_gen()).collect();
+    /// assert_eq!(output, [1, 2, 3, 4, 5, 6]);
+    /// ```
+    #[inline]
+    fn interleave<Other>(self, other: Other) -> Interleave<Self, Other>
+    where
+        Other: Generator<Output = Self::Output>,
+    {
+        Interleave::new(self, other)
+    }
+
+    /// Creates a generator that alternates values from this generator and `other` until either
+    /// of them completes.
+    ///
+    /// Like [`interleave()`](GeneratorExt::interleave), but the generator completes as soon as the
+    /// generator whose turn it is completes, without generating the rest of the other one.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let a = [1, 3, 5, 7];
+    /// let b = [2, 4];
+    /// let output: Vec<_> = a.into_gen().interleave_shortest(b.into_gen()).collect();
+    /// assert_eq!(output, [1, 2, 3, 4, 5]);
+    /// ```
+    #[inline]
+    fn interleave_shortest<Other>(self, other: Other) -> InterleaveShortest<Self, Other>
+    where
+        Other: Generator<Output = Self::Output>,
+    {
+        InterleaveShortest::new(self, other)
+    }
+
+    /// Creates a generator that lets a closure pull values from the source generator.
+    ///
+    /// `f` is called with the source generator, from which it can take any number of values with
+    /// [`next()`](GeneratorExt::next), and returns either the next value to generate or the
+    /// result that ends the run. Since `next()` reports the end of the source as an error, `?` can
+    /// be used to forward it. This allows custom chunking and look-ahead without implementing
+    /// [`Generator`] manually.
+    ///
+    /// ## Spuriously stopping generators
+    ///
+    /// Returning `Err(GeneratorResult::Stopped)` stops the generator, and `f` is called again on the
+    /// next run. Values that `f` took from the source before the stop must be kept by `f` itself if
+    /// they shouldn't be lost.
+    ///
+    /// ## Examples
+    ///
+    /// Summing pairs of values:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt, GeneratorResult};
+    /// let a = [1, 2, 3, 4, 5];
+    /// let output: Vec<_> = a
+    ///     .into_gen()
+    ///     .batching(|gen| {
+    ///         let first = gen.next()?;
+    ///         match gen.next() {
+    ///             Ok(second) => Ok(first + second),
+    ///             Err(GeneratorResult::Complete) => Ok(first),
+    ///             Err(err) => Err(err),
+    ///         }
+    ///     })
+    ///     .collect();
+    /// assert_eq!(output, [3, 7, 5]);
+    /// ```
+    #[inline]
+    fn batching<F, B>(self, f: F) -> Batching<Self, F>
+    where
+        F: FnMut(&mut Self) -> Result<B, GeneratorResult>,
+    {
+        Batching::new(self, f)
+    }
+
+    /// Creates a generator that can look at the next value without consuming it.
+    ///
+    /// [`Peekable::peek()`] retrieves the next value from the source and keeps it until it is
+    /// generated.
+    ///
+    /// ## Spuriously stopping generators
+    ///
+    /// `peek()` returns `None` if the source stopped before generating a value. Peeking again
+    /// resumes the source.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let mut gen = [1, 1, 2].into_gen().peekable();
+    /// let first = gen.next().unwrap();
+    /// let mut count = 1;
+    /// while gen.peek() == Some(&first) {
+    ///     gen.next().unwrap();
+    ///     count += 1;
+    /// }
+    /// assert_eq!(count, 2);
+    /// assert_eq!(gen.next(), Ok(2));
+    /// ```
+    #[inline]
+    fn peekable(self) -> Peekable<Self> {
+        Peekable::new(self)
+    }
+
+    /// Creates a generator that can look at any number of upcoming values without consuming them.
+    ///
+    /// Each call to [`MultiPeek::peek()`] returns the value after the previously peeked one,
+    /// buffering values from the source as needed. [`MultiPeek::reset_peek()`] moves the peek
+    /// cursor back to the start. Buffered values are generated before any new values from the
+    /// source.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let mut gen = ['a', 'b', 'c'].into_gen().multipeek();
+    /// assert_eq!(gen.peek(), Some(&'a'));
+    /// assert_eq!(gen.peek(), Some(&'b'));
+    /// gen.reset_peek();
+    /// assert_eq!(gen.peek(), Some(&'a'));
+    ///
+    /// let output: String = gen.collect();
+    /// assert_eq!(output, "abc");
+    /// ```
+    ///
+    /// [`MultiPeek::peek()`]: crate::structs::MultiPeek::peek
+    /// [`MultiPeek::reset_peek()`]: crate::structs::MultiPeek::reset_peek
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    fn multipeek(self) -> crate::structs::MultiPeek<Self> {
+        crate::structs::MultiPeek::new(self)
+    }
+
+    /// Creates a generator that pads the source generator to at least `min_len` values.
+    ///
+    /// Once the source generator completes, `f(index)` is generated for every index from the number
+    /// of values generated so far up to, but not including, `min_len`.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let a = [1, 2];
+    /// let output: Vec<_> = a.into_gen().pad_using(4, |i| i * 10).collect();
+    /// assert_eq!(output, [1, 2, 20, 30]);
+    /// ```
+    #[inline]
+    fn pad_using<F>(self, min_len: usize, f: F) -> PadUsing<Self, F>
+    where
+        F: FnMut(usize) -> Self::Output,
+    {
+        PadUsing::new(self, min_len, f)
+    }
+
+    /// Creates a generator that tags each value with its [`Position`](crate::structs::Position).
+    ///
+    /// Values are generated as `(position, value)` pairs. Whether a value is the last one is only
+    /// known once the next value has been seen, so each value is held back until the next value
+    /// is generated or the source completes.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt, structs::Position};
+    /// let output: Vec<_> = ['a', 'b', 'c'].into_gen().with_position().collect();
+    /// assert_eq!(
+    ///     output,
+    ///     [(Position::First, 'a'), (Position::Middle, 'b'), (Position::Last, 'c')]
+    /// );
+    ///
+    /// let output: Vec<_> = ['a'].into_gen().with_position().collect();
+    /// assert_eq!(output, [(Position::Only, 'a')]);
+    /// ```
+    #[inline]
+    fn with_position(self) -> WithPosition<Self> {
+        WithPosition::new(self)
+    }
+
+    /// Create a generator that merges adjacent values.
+    ///
+    /// `f(prev, next)` is called with the currently held value and the next value from the source.
+    /// If it returns `Ok(merged)` then `merged` replaces the held value. If it returns
+    /// `Err((prev, next))` then `prev` is generated and `next` becomes the held value. The last held
+    /// value is generated once the source completes.
+    ///
+    /// ## Examples
+    ///
+    /// Merging adjacent intervals:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let intervals = [(0, 2), (2, 5), (7, 8), (8, 9), (10, 11)];
+    /// let output: Vec<_> = intervals
+    ///     .into_gen()
+    ///     .coalesce(|a, b| if a.1 == b.0 { Ok((a.0, b.1)) } else { Err((a, b)) })
+    ///     .collect();
+    /// assert_eq!(output, [(0, 5), (7, 9), (10, 11)]);
+    /// ```
+    ///
+    /// Collapsing adjacent whitespace:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let text: String = "a  b   c"
+    ///     .chars()
+    ///     .collect::<Vec<_>>()
+    ///     .into_gen()
+    ///     .coalesce(|a, b| if a == ' ' && b == ' ' { Ok(' ') } else { Err((a, b)) })
+    ///     .collect();
+    /// assert_eq!(text, "a b c");
+    /// ```
+    #[inline]
+    fn coalesce<F>(self, f: F) -> Coalesce<Self, F>
+    where
+        F: FnMut(Self::Output, Self::Output) -> Result<Self::Output, (Self::Output, Self::Output)>,
+    {
+        Coalesce::new(self, f)
+    }
+
+    /// Create a de-duplicating generator, removing consecutive duplicate values.
+    ///
+    /// Values will be made available when a non-duplicate is detected. If the up-stream generator generates
+    /// the following sequence: `[1, 2, 3, 3, 4]` then the value `1` will be generated from the
+    /// `Dedup` generator once the value `2` has been generated by the upstream generator and so
+    /// on.
+    ///
+    /// | Upstream value | Dedup-generated value |
+    /// |----------------|-----------------------|
+    /// | 1              | *None*                |
+    /// | 2              | 1                     |
+    /// | 3              | 2                     |
+    /// | 3              | *Ignored*             |
+    /// | 4              | 3                     |
+    /// | *Complete*     | 4                     |
+    /// | *Complete*     | *Complete*            |
+    ///
+    /// ## Example
+    /// ```
+    /// # use pushgen::{SliceGenerator, GeneratorExt};
+    /// let data = [1, 2, 3, 3, 3, 3, 4, 3];
+    /// let mut output: Vec<i32> = Vec::new();
+    /// SliceGenerator::new(&data).dedup().for_each(|x| output.push(*x));
+    /// assert_eq!(output, [1, 2, 3, 4, 3]);
+    /// ```
+    ///
+    /// ## Reverse generation
+    ///
+    /// If the source generator is a [`ReverseGenerator`] then so is `Dedup`, and values can be
+    /// deduplicated from the back. The forward and reverse directions each hold their own previous
+    /// value, so a `Dedup` should only be used in one direction. Mixing forward and reverse generation
+    /// on the same `Dedup` results in unspecified, but safe, output.
+    ///
+    /// ```
+    /// use pushgen::{SliceGenerator, GeneratorExt};
+    /// let data = [1, 1, 2, 3, 3];
+    /// let output: Vec<_> = SliceGenerator::new(&data).dedup().rev().collect();
+    /// assert_eq!(output, [&3, &2, &1]);
+    /// ```
+    #[inline]
+    fn dedup(self) -> Dedup<Self>
+    where
+        Self::Output: PartialEq,
+    {
+        Dedup::new(self)
+    }
+
+    /// Create a de-duplicating generator, removing consecutive values that `same` considers equal.
+    ///
+    /// `same` is called with the previously held value and the next value from the source
+    /// generator. If it returns `true` the next value is dropped. The first value of every run of
+    /// equal values is generated, once a value that isn't equal to it is seen or the source
+    /// generator completes.
+    ///
+    /// ## Example
+    /// ```
+    /// # use pushgen::{IntoGenerator, GeneratorExt};
+    /// let data = ["a", "A", "b", "c", "C", "a"];
+    /// let output: Vec<_> = data.into_gen().dedup_by(|a, b| a.eq_ignore_ascii_case(b)).collect();
+    /// assert_eq!(output, ["a", "b", "c", "a"]);
+    /// ```
+    #[inline]
+    fn dedup_by<F>(self, same: F) -> DedupBy<Self, F>
+    where
+        F: FnMut(&Self::Output, &Self::Output) -> bool,
+    {
+        DedupBy::new(self, same)
+    }
+
+    /// Create a de-duplicating generator, removing consecutive values with equal keys.
+    ///
+    /// The key of every value is computed once with `key_fn`, and only the key of the held value
+    /// is compared, so the values themselves don't need to implement `PartialEq`. The first value
+    /// of every run of equal keys is generated.
+    ///
+    /// ## Example
+    /// ```
+    /// # use pushgen::{IntoGenerator, GeneratorExt};
+    /// let data = [(1, 'a'), (1, 'b'), (2, 'c'), (1, 'd')];
+    /// let output: Vec<_> = data.into_gen().dedup_by_key(|x| x.0).collect();
+    /// assert_eq!(output, [(1, 'a'), (2, 'c'), (1, 'd')]);
+    /// ```
+    #[inline]
+    fn dedup_by_key<K, F>(self, key_fn: F) -> DedupByKey<Self, F, K>
+    where
+        F: FnMut(&Self::Output) -> K,
+        K: PartialEq,
+    {
+        DedupByKey::new(self, key_fn)
+    }
+
+    /// Create a generator that collapses runs of equal consecutive values into `(count, value)`
+    /// pairs.
+    ///
+    /// Like [`dedup()`](GeneratorExt::dedup), a pair is generated once a non-duplicate is detected
+    /// or the source generator completes. The count and the held value are kept if the source
+    /// generator is stopped, so a run is never split when the generator is resumed.
+    ///
+    /// ## Example
+    /// ```
+    /// # use pushgen::{IntoGenerator, GeneratorExt};
+    /// let data = ['a', 'a', 'b', 'c', 'c', 'c'];
+    /// let output: Vec<_> = data.into_gen().dedup_with_count().collect();
+    /// assert_eq!(output, [(2, 'a'), (1, 'b'), (3, 'c')]);
+    /// ```
+    #[inline]
+    fn dedup_with_count(self) -> DedupWithCount<Self>
+    where
+        Self::Output: PartialEq,
+    {
+        DedupWithCount::new(self)
+    }
+
+    /// Creates a generator that run-length encodes the values into `(value, run_length)` pairs.
+    ///
+    /// This is [`dedup_with_count()`](GeneratorExt::dedup_with_count) with the pairs in
+    /// `(value, run_length)` order, and the same handling of stopped generators. Use
+    /// [`rld()`](GeneratorExt::rld) to decode the pairs again.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let data = ['a', 'a', 'b', 'c', 'c', 'c'];
+    /// let output: Vec<_> = data.into_gen().rle().collect();
+    /// assert_eq!(output, [('a', 2), ('b', 1), ('c', 3)]);
+    /// ```
+    #[inline]
+    fn rle(self) -> Rle<Self>
+    where
+        Self::Output: PartialEq,
+    {
+        Rle::new(self)
+    }
+
+    /// Creates a generator that run-length decodes `(value, count)` pairs, generating each value
+    /// `count` times.
+    ///
+    /// This is the inverse of [`rle()`](GeneratorExt::rle). Pairs with a count of 0 generate
+    /// nothing.
+    ///
+    /// ## Spuriously stopping generators
+    ///
+    /// If the consumer stops in the middle of a run, the rest of the run is generated when the
+    /// generator is resumed.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let data = [('a', 2), ('b', 1), ('c', 3)];
+    /// let output: String = data.into_gen().rld().collect();
+    /// assert_eq!(output, "aabccc");
+    /// ```
+    #[inline]
+    fn rld<T>(self) -> Rld<Self, T>
+    where
+        Self: Generator<Output = (T, usize)>,
+        T: Clone,
+    {
+        Rld::new(self)
+    }
+
+    /// Creates a generator that removes values that have already been generated.
+    ///
+    /// Unlike [`dedup()`](GeneratorExt::dedup), which only removes consecutive duplicates, this
+    /// removes every value that is equal to an earlier one. A clone of every generated value is
+    /// kept in a `HashSet`.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let a = [3, 1, 3, 2, 1];
+    /// let output: Vec<_> = a.into_gen().unique().collect();
+    /// assert_eq!(output, [3, 1, 2]);
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    fn unique(self) -> crate::structs::Unique<Self>
+    where
+        Self::Output: Eq + core::hash::Hash + Clone,
+    {
+        crate::structs::Unique::new(self)
+    }
+
+    /// Creates a generator that removes values whose key has already been seen.
+    ///
+    /// Only the keys extracted by `key` are stored in a `HashSet`, so large values don't have to be
+    /// cloned to be deduplicated. The first value with a given key is generated.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let a = ["a", "bb", "c", "dd", "eee"];
+    /// let output: Vec<_> = a.into_gen().unique_by(|s| s.len()).collect();
+    /// assert_eq!(output, ["a", "bb", "eee"]);
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    fn unique_by<F, K>(self, key: F) -> crate::structs::UniqueBy<Self, F, K>
+    where
+        F: FnMut(&Self::Output) -> K,
+        K: Eq + core::hash::Hash,
+    {
+        crate::structs::UniqueBy::new(self, key)
+    }
+
+    /// Creates a generator that only generates values that appear more than once.
+    ///
+    /// A value is generated the first time a duplicate of it is seen, that is when it appears for
+    /// the second time. Later duplicates are ignored. Every distinct value is kept in a `HashMap`.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let ids = [4, 1, 4, 2, 1, 4];
+    /// let output: Vec<_> = ids.into_gen().duplicates().collect();
+    /// assert_eq!(output, [4, 1]);
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    fn duplicates(self) -> crate::structs::Duplicates<Self>
+    where
+        Self::Output: Eq + core::hash::Hash,
+    {
+        crate::structs::Duplicates::new(self)
+    }
+
+    /// Creates a generator that groups values into chunks of `size` values.
+    ///
+    /// Each chunk is generated as a `Vec` once it is full. When the source generator completes, the
+    /// last chunk is generated even if it holds fewer than `size` values.
+    ///
+    /// ## Spuriously stopping generators
+    ///
+    /// The chunk that is being filled is kept if the source generator is stopped, so a stopped
+    /// generator can be resumed without splitting a chunk.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `size` is 0.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let a = [1, 2, 3, 4, 5];
+    /// let output: Vec<_> = a.into_gen().chunks(2).collect();
+    /// assert_eq!(output, [vec![1, 2], vec![3, 4], vec![5]]);
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    fn chunks(self, size: usize) -> crate::structs::Chunks<Self> {
+        crate::structs::Chunks::new(self, size)
+    }
+
+    /// Creates a generator of windows of `size` values, where each window starts `stride` values
+    /// after the previous one.
+    ///
+    /// Each window is generated as a `Vec`. A `stride` smaller than `size` generates overlapping
+    /// windows, a `stride` equal to `size` works like [`chunks()`](GeneratorExt::chunks), and a
+    /// larger `stride` skips the values between windows. Values at the end that don't fill a whole
+    /// window are not generated.
+    ///
+    /// For fixed-size windows with a stride of 1, [`map_windows()`](GeneratorExt::map_windows)
+    /// avoids allocating.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `size` or `stride` is 0.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let a = [1, 2, 3, 4, 5, 6, 7];
+    /// let output: Vec<_> = a.into_gen().sliding_windows(3, 2).collect();
+    /// assert_eq!(output, [vec![1, 2, 3], vec![3, 4, 5], vec![5, 6, 7]]);
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    fn sliding_windows(self, size: usize, stride: usize) -> crate::structs::SlidingWindows<Self>
+    where
+        Self::Output: Clone,
+    {
+        crate::structs::SlidingWindows::new(self, size, stride)
+    }
+
+    /// Creates a generator that groups values into arrays of `N` values.
+    ///
+    /// Unlike [`chunks()`](GeneratorExt::chunks) this doesn't allocate. Values are collected in an
+    /// internal buffer and an array is generated once `N` values have been collected. Values that
+    /// are left over when the source generator completes are not generated, but can be retrieved
+    /// with [`ArrayChunks::remainder()`].
+    ///
+    /// ## Spuriously stopping generators
+    ///
+    /// The values of the incomplete chunk are kept if the source generator is stopped, so a
+    /// stopped generator can be resumed without splitting a chunk.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `N` is 0.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let a = [1, 2, 3, 4, 5];
+    /// let output: Vec<_> = a.into_gen().array_chunks::<2>().collect();
+    /// assert_eq!(output, [[1, 2], [3, 4]]);
+    /// ```
+    #[inline]
+    fn array_chunks<const N: usize>(self) -> ArrayChunks<Self, N> {
+        ArrayChunks::new(self)
+    }
+
+    /// Creates a generator of overlapping tuples of consecutive values.
+    ///
+    /// The tuple type decides the window size, tuples of 2, 3 and 4 values are supported. The first
+    /// tuple is generated once enough values have been seen, after that one tuple is generated for
+    /// each value. Values are cloned into every window they are part of.
+    ///
+    /// If the source generates fewer values than the window size, nothing is generated.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let a = [1, 4, 9, 16];
+    /// let deltas: Vec<_> = a.into_gen().tuple_windows().map(|(a, b)| b - a).collect();
+    /// assert_eq!(deltas, [3, 5, 7]);
+    ///
+    /// let triples: Vec<(_, _, _)> = a.into_gen().tuple_windows().collect();
+    /// assert_eq!(triples, [(1, 4, 9), (4, 9, 16)]);
+    /// ```
+    #[inline]
+    fn tuple_windows<Tup>(self) -> TupleWindows<Self, Tup>
+    where
+        Tup: TupleWindow<Item = Self::Output>,
+    {
+        TupleWindows::new(self)
+    }
+
+    /// Creates a generator of the differences between each value and its predecessor.
+    ///
+    /// The first value has no predecessor, so one value less than the source is generated.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let a = [1, 4, 9, 16];
+    /// let output: Vec<_> = a.into_gen().diff().collect();
+    /// assert_eq!(output, [3, 5, 7]);
+    /// ```
+    #[inline]
+    fn diff(self) -> Diff<Self>
+    where
+        Self::Output: core::ops::Sub<Output = Self::Output> + Clone,
+    {
+        Diff::new(self)
+    }
+
+    /// Creates a generator that combines each value with its predecessor using `combine`.
+    ///
+    /// `combine` is called with the previous value and the current value. The first value has no
+    /// predecessor, so one value less than the source is generated.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let a = [1.0, 2.0, 3.0, 1.5];
+    /// let output: Vec<_> = a.into_gen().diff_by(|prev, x| x / prev).collect();
+    /// assert_eq!(output, [2.0, 1.5, 0.5]);
+    /// ```
+    #[inline]
+    fn diff_by<F, U>(self, combine: F) -> DiffBy<Self, F>
+    where
+        F: FnMut(&Self::Output, &Self::Output) -> U,
+    {
+        DiffBy::new(self, combine)
+    }
+
+    /// Creates a generator that groups values into non-overlapping tuples.
+    ///
+    /// The tuple type decides how many values are grouped, tuples of 2, 3 and 4 values are
+    /// supported. Values that are left over when the source generator completes are not generated,
+    /// but can be retrieved with [`Tuples::into_remainder()`].
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let a = [1, 2, 3, 4, 5, 6, 7];
+    /// let output: Vec<_> = a.into_gen().tuples::<(_, _, _)>().collect();
+    /// assert_eq!(output, [(1, 2, 3), (4, 5, 6)]);
+    ///
+    /// let points: Vec<(_, _)> = a.into_gen().tuples().collect();
+    /// assert_eq!(points, [(1, 2), (3, 4), (5, 6)]);
+    /// ```
+    #[inline]
+    fn tuples<Tup>(self) -> Tuples<Self, Tup>
+    where
+        Tup: HomogeneousTuple<Item = Self::Output>,
+    {
+        Tuples::new(self)
+    }
+
+    /// Creates a generator that calls `func` with every sliding window of `N` values.
+    ///
+    /// The windows overlap, and `func` is first called once `N` values have been seen. After that
+    /// it is called once for each value, with the window shifted by one. The values are moved into
+    /// the window, so they don't need to implement `Clone`.
+    ///
+    /// If the source generates fewer than `N` values, nothing is generated.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `N` is 0.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let a = ['a', 'b', 'c', 'd'];
+    /// let output: Vec<String> = a.into_gen().map_windows(|w: &[_; 2]| w.iter().collect()).collect();
+    /// assert_eq!(output, ["ab", "bc", "cd"]);
+    /// ```
+    #[inline]
+    fn map_windows<U, F, const N: usize>(self, func: F) -> MapWindows<Self, F, N>
+    where
+        F: FnMut(&[Self::Output; N]) -> U,
+    {
+        MapWindows::new(self, func)
+    }
+
+    /// Splits the generated values into segments separated by values matching `is_delimiter`.
+    ///
+    /// Each segment is generated as a `Vec` of the values between two delimiters; the delimiters
+    /// themselves are not generated. This works like [`slice::split`]: leading, trailing and
+    /// adjacent delimiters produce empty segments, and an empty source generates a single empty
+    /// segment.
+    ///
+    /// ## Spuriously stopping generators
+    ///
+    /// The segment that is being collected is kept if the source generator is stopped, so a
+    /// stopped generator can be resumed without splitting a segment.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let a = [1, 2, 0, 3, 0, 0, 4];
+    /// let output: Vec<_> = a.into_gen().split_on(|x| *x == 0).collect();
+    /// assert_eq!(output, [vec![1, 2], vec![3], vec![], vec![4]]);
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    fn split_on<F>(self, is_delimiter: F) -> crate::structs::SplitOn<Self, F>
+    where
+        F: FnMut(&Self::Output) -> bool,
+    {
+        crate::structs::SplitOn::new(self, is_delimiter)
+    }
+
+    /// Splits the generated values into segments, starting a new segment at each value matching
+    /// `starts_segment`.
+    ///
+    /// Each segment is generated as a `Vec`. Unlike [`split_on()`](GeneratorExt::split_on) the
+    /// matching values are kept as the first value of their segment, which suits data where a
+    /// header marks the start of each record. Segments are never empty: values before the first
+    /// match form a segment of their own, and an empty source generates nothing.
+    ///
+    /// ## Spuriously stopping generators
+    ///
+    /// The segment that is being collected is kept if the source generator is stopped, so a
+    /// stopped generator can be resumed without splitting a segment.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let lines = ["# a", "1", "2", "# b", "3"];
+    /// let output: Vec<_> = lines.into_gen().split_when(|l| l.starts_with('#')).collect();
+    /// assert_eq!(output, [vec!["# a", "1", "2"], vec!["# b", "3"]]);
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    fn split_when<F>(self, starts_segment: F) -> crate::structs::SplitWhen<Self, F>
+    where
+        F: FnMut(&Self::Output) -> bool,
+    {
+        crate::structs::SplitWhen::new(self, starts_segment)
+    }
+
+    /// Creates a generator that generates the sum of each sliding window of `size` values.
+    ///
+    /// The first sum is generated once `size` values have been seen, after that one sum is
+    /// generated for each value. Every step only does a constant amount of work: the value entering
+    /// the window is added to the running sum and the value leaving the window is subtracted from it.
+    ///
+    /// If the source generates fewer than `size` values, nothing is generated.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `size` is 0.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let a = [1, 2, 3, 4, 5];
+    /// let output: Vec<_> = a.into_gen().windows_sum(3).collect();
+    /// assert_eq!(output, [6, 9, 12]);
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    fn windows_sum(self, size: usize) -> crate::structs::WindowsSum<Self>
+    where
+        Self::Output:
+            core::ops::Add<Output = Self::Output> + core::ops::Sub<Output = Self::Output> + Clone,
+    {
+        crate::structs::WindowsSum::new(self, size)
+    }
+
+    /// Creates a generator of statistics over a sliding window of `size` values.
+    ///
+    /// Each window is generated as a [`RollingStats`](crate::structs::RollingStats) with the sum,
+    /// minimum, maximum and mean of the values in the window. Only the values of the current window
+    /// are kept, and every step takes amortized constant time. The sum is kept as a running sum that
+    /// subtracts the value leaving the window before adding the new one, so it only overflows if a
+    /// window sum does. For floating point values, rounding errors can accumulate in the running
+    /// sum.
+    ///
+    /// The first window is generated once `size` values have been seen, after that one window is
+    /// generated for each value. If the source generates fewer than `size` values, nothing is
+    /// generated.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `size` is 0.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let a = [1.0, 3.0, 2.0, 6.0];
+    /// let output: Vec<_> = a
+    ///     .into_gen()
+    ///     .rolling(2)
+    ///     .map(|w| (w.mean(), *w.min(), *w.max()))
+    ///     .collect();
+    /// assert_eq!(output, [(2.0, 1.0, 3.0), (2.5, 2.0, 3.0), (4.0, 2.0, 6.0)]);
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    fn rolling(self, size: usize) -> crate::structs::Rolling<Self>
+    where
+        Self::Output: core::ops::Add<Output = Self::Output>
+            + core::ops::Sub<Output = Self::Output>
+            + PartialOrd
+            + Clone,
+    {
+        crate::structs::Rolling::new(self, size)
+    }
+
+    /// Groups consecutive values with equal keys, handing each group to a closure as a generator.
+    ///
+    /// The key of each value is computed with `key_fn`. Groups are run with
+    /// [`GroupBy::for_each_group()`] or [`GroupBy::run_groups()`], which call a closure with the
+    /// key and a [`Group`](crate::structs::Group) generator for each run of equal keys. The group
+    /// generator borrows the source, so groups can be reduced without being collected. Unlike
+    /// [`chunk_by_with_key()`](GeneratorExt::chunk_by_with_key) nothing is allocated.
+    ///
+    /// Values of a group that the closure doesn't generate are skipped.
+    ///
+    /// ## Spuriously stopping generators
+    ///
+    /// If the source generator stops while a group is being generated, the group generator and
+    /// `run_groups()` return [`GeneratorResult::Stopped`]. The next call to `run_groups()` hands
+    /// the rest of the group to the closure again, with the same key.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let a = [1, 1, 2, 3, 3, 3];
+    /// let mut sums = Vec::new();
+    /// a.into_gen()
+    ///     .group_by(|x| *x)
+    ///     .for_each_group(|key, group| sums.push((key, group.sum::<i32>())));
+    /// assert_eq!(sums, [(1, 2), (2, 2), (3, 9)]);
+    /// ```
+    #[inline]
+    fn group_by<K, F>(self, key_fn: F) -> GroupBy<Self, F, K>
+    where
+        F: FnMut(&Self::Output) -> K,
+        K: PartialEq + Clone,
+    {
+        GroupBy::new(self, key_fn)
+    }
+
+    /// Groups consecutive values with equal keys, generating each group as a `Vec`.
+    ///
+    /// The key of each value is computed with `key_fn`, and consecutive values with equal keys are
+    /// collected into a `Vec`. This is [`chunk_by_with_key()`](GeneratorExt::chunk_by_with_key)
+    /// without the keys.
+    ///
+    /// ## Spuriously stopping generators
+    ///
+    /// The group that is being collected is kept if the source generator is stopped, so a
+    /// stopped generator can be resumed without splitting a group.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let a = [1, 3, 2, 4, 6, 5];
+    /// let output: Vec<_> = a.into_gen().chunk_by(|x| x % 2).collect();
+    /// assert_eq!(output, [vec![1, 3], vec![2, 4, 6], vec![5]]);
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    fn chunk_by<K, F>(self, key_fn: F) -> crate::structs::ChunkBy<Self, F, K>
+    where
+        F: FnMut(&Self::Output) -> K,
+        K: PartialEq,
+    {
+        crate::structs::ChunkBy::new(self, key_fn)
+    }
+
+    /// Groups consecutive values with equal keys, generating `(key, group)` pairs.
+    ///
+    /// The key of each value is computed with `key_fn`. Consecutive values with equal keys are
+    /// collected into a `Vec`, which is generated together with the key once a value with a different
+    /// key is seen, or the source generator completes.
+    ///
+    /// Use [`group_by()`](GeneratorExt::group_by) to reduce each group without collecting it.
+    ///
+    /// ## Spuriously stopping generators
+    ///
+    /// The group that is being collected is kept if the source generator is stopped, so a
+    /// stopped generator can be resumed without splitting a group.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let a = [1, 1, 2, 3, 3, 3];
+    /// let output: Vec<_> = a.into_gen().chunk_by_with_key(|x| *x).collect();
+    /// assert_eq!(output, [(1, vec![1, 1]), (2, vec![2]), (3, vec![3, 3, 3])]);
     /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
     #[inline]
-    fn dedup(self) -> Dedup<Self>
+    fn chunk_by_with_key<K, F>(self, key_fn: F) -> crate::structs::ChunkByWithKey<Self, F, K>
     where
-        Self::Output: PartialEq,
+        F: FnMut(&Self::Output) -> K,
+        K: PartialEq,
     {
-        Dedup::new(self)
+        crate::structs::ChunkByWithKey::new(self, key_fn)
     }
 
     /// Create an iterator from a generator.
@@ -753,6 +2625,151 @@ pub trait GeneratorExt: Sealed + Generator + Sized {
         StepBy::new(self, step_size)
     }
 
+    /// Create a generator that skips the first `offset` values and then steps by the given amount.
+    ///
+    /// This is the same as `.skip(offset).step_by(step_size)`, but the initial skip and the
+    /// strides share a single adaptor and are both done with [`try_advance`](Generator::try_advance).
+    ///
+    /// ## Panics
+    ///
+    /// The method will panic if given a step size of `0`
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt, GeneratorResult};
+    /// let a = [0, 1, 2, 3, 4, 5];
+    /// let mut gen = a.into_gen().step_by_from(1, 2);
+    ///
+    /// assert_eq!(gen.next(), Ok(1));
+    /// assert_eq!(gen.next(), Ok(3));
+    /// assert_eq!(gen.next(), Ok(5));
+    /// assert_eq!(gen.next(), Err(GeneratorResult::Complete));
+    /// ```
+    #[inline]
+    fn step_by_from(self, offset: usize, step_size: usize) -> StepBy<Self> {
+        StepBy::with_offset(self, offset, step_size)
+    }
+
+    /// Creates a generator that forwards each value with probability `p`, using `rng` as the
+    /// source of randomness.
+    ///
+    /// Every value is kept or dropped independently of the others, which makes this a cheap way
+    /// to downsample a stream. `rng` can be any [`rand::Rng`], including a `&mut` reference to one.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `p` is not in the range `[0, 1]`.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// use rand::{rngs::StdRng, SeedableRng};
+    /// let a = [1, 2, 3, 4, 5];
+    /// let rng = StdRng::seed_from_u64(0);
+    /// let output: Vec<_> = a.into_gen().sample(0.5, rng).collect();
+    /// assert!(output.iter().all(|x| a.contains(x)));
+    /// ```
+    #[cfg(feature = "rand")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    #[inline]
+    fn sample<R>(self, p: f64, rng: R) -> crate::structs::Sample<Self, R>
+    where
+        R: rand::Rng,
+    {
+        crate::structs::Sample::new(self, p, rng)
+    }
+
+    /// Creates a generator that places a copy of `separator` between adjacent values.
+    ///
+    /// A separator is only generated once the value following it is available, so no trailing
+    /// separator is ever generated, not even if the source generator spuriously stops.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let a = [1, 2, 3];
+    /// let output: Vec<_> = a.into_gen().intersperse(0).collect();
+    /// assert_eq!(output, [1, 0, 2, 0, 3]);
+    /// ```
+    #[inline]
+    fn intersperse(self, separator: Self::Output) -> Intersperse<Self>
+    where
+        Self::Output: Clone,
+    {
+        Intersperse::new(self, separator)
+    }
+
+    /// Creates a generator that places a separator produced by `separator` between adjacent
+    /// values.
+    ///
+    /// Like [`intersperse()`](GeneratorExt::intersperse), but the separators are created lazily by
+    /// calling the closure, so the values don't need to implement `Clone` and each separator can
+    /// be different. The closure is only called when a separator is about to be generated.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let a = ["a", "b", "c"];
+    /// let output: Vec<_> = a.into_gen().map(String::from).intersperse_with(String::new).collect();
+    /// assert_eq!(output, ["a", "", "b", "", "c"]);
+    /// ```
+    #[inline]
+    fn intersperse_with<F>(self, separator: F) -> IntersperseWith<Self, F>
+    where
+        F: FnMut() -> Self::Output,
+    {
+        IntersperseWith::new(self, separator)
+    }
+
+    /// Creates a value that formats the generated values with [`Display`](core::fmt::Display),
+    /// separated by `sep`.
+    ///
+    /// `format` is called with each value and a callback that writes anything implementing
+    /// `Display`. Nothing is allocated: the values are written directly to the formatter, so the
+    /// result can be embedded in `format!` or `write!`.
+    ///
+    /// ## Spuriously stopping generators
+    ///
+    /// The values are formatted until the generator stops, so a spuriously stopping generator only
+    /// has the values before the stop formatted.
+    ///
+    /// ## Panics
+    ///
+    /// The returned value panics if it is formatted more than once.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let a = [1.5, 2.25, 3.0];
+    /// let s = format!("values: {}", a.into_gen().format_with(" | ", |x, f| f(&format_args!("{:.1}", x))));
+    /// assert_eq!(s, "values: 1.5 | 2.2 | 3.0");
+    /// ```
+    #[inline]
+    fn format_with<F>(self, sep: &str, format: F) -> FormatWith<'_, Self, F>
+    where
+        F: FnMut(
+            Self::Output,
+            &mut dyn FnMut(&dyn core::fmt::Display) -> core::fmt::Result,
+        ) -> core::fmt::Result,
+    {
+        FormatWith::new(self, sep, format)
+    }
+
     /// Box a generator, making it possible to use as return value in for instance traits.
     ///
     /// ## Performance
@@ -815,6 +2832,107 @@ pub trait GeneratorExt: Sealed + Generator + Sized {
         S::sum(self)
     }
 
+    /// Sums the values of a generator, saturating at the numeric bounds instead of overflowing.
+    ///
+    /// An empty generator returns the zero value of the type.
+    ///
+    /// ## Spuriously stopping generators
+    ///
+    /// `saturating_sum()` only sums the values up until the source generator is first stopped.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let a = [100u8, 100, 100];
+    /// let sum: u8 = a.into_gen().saturating_sum();
+    ///
+    /// assert_eq!(sum, u8::MAX);
+    /// ```
+    #[inline]
+    fn saturating_sum<S>(self) -> S
+    where
+        S: SaturatingSum<Self::Output>,
+    {
+        S::saturating_sum(self)
+    }
+
+    /// Sums the values of a generator and counts them in a single pass.
+    ///
+    /// This is useful to calculate a mean value without running the generator twice.
+    ///
+    /// ## Spuriously stopping generators
+    ///
+    /// `sum_and_count()` only sums and counts the values up until the source generator is first stopped.
+    ///
+    /// ## Panics
+    ///
+    /// When calling `sum_and_count()` and a primitive integer type is being returned,
+    /// this method will panic if the computation overflows and debug assertions are enabled.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let a = [2, 4, 6];
+    /// let (sum, count): (i32, usize) = a.into_gen().sum_and_count();
+    ///
+    /// assert_eq!(sum, 12);
+    /// assert_eq!(count, 3);
+    /// assert_eq!(sum / count as i32, 4);
+    /// ```
+    #[inline]
+    fn sum_and_count<S>(self) -> (S, usize)
+    where
+        S: Sum<Self::Output>,
+    {
+        let mut count = 0;
+        let sum = S::sum(self.inspect(|_| count += 1));
+        (sum, count)
+    }
+
+    /// Adds every value of a generator into an existing accumulator.
+    ///
+    /// Unlike [`sum()`](GeneratorExt::sum), `sum_into()` does not create a new value but adds each
+    /// value to `acc` in place. This makes it possible to keep summing into the same total across
+    /// several runs of a generator.
+    ///
+    /// ## Spuriously stopping generators
+    ///
+    /// `sum_into()` returns the result of the generator run. If the generator was stopped, calling
+    /// `sum_into()` again with the same accumulator continues the summation where it left off.
+    ///
+    /// ## Panics
+    ///
+    /// When summing into a primitive integer type, this method will panic if the computation
+    /// overflows and debug assertions are enabled.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt, GeneratorResult};
+    /// let a = [1, 2, 3];
+    /// let b = [4, 5];
+    /// let mut sum = 0;
+    ///
+    /// assert_eq!(a.into_gen().sum_into(&mut sum), GeneratorResult::Complete);
+    /// assert_eq!(b.into_gen().sum_into(&mut sum), GeneratorResult::Complete);
+    /// assert_eq!(sum, 15);
+    /// ```
+    #[inline]
+    fn sum_into<S>(&mut self, acc: &mut S) -> GeneratorResult
+    where
+        S: AddAssign<Self::Output>,
+    {
+        self.for_each(|x| *acc += x)
+    }
+
     /// Multiplies the values of a generator. Takes each value and adds them together and returns
     /// the result.
     ///
@@ -855,6 +2973,54 @@ pub trait GeneratorExt: Sealed + Generator + Sized {
         P::product(self)
     }
 
+    /// Creates a generator of the running sums of the values.
+    ///
+    /// The first value is generated as is, and each following value is added to the previous sum.
+    /// Unlike [`sum()`](GeneratorExt::sum), no zero value is needed, so any type implementing
+    /// [`Add`](core::ops::Add) can be used.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let a = [1, 2, 3, 4];
+    /// let output: Vec<_> = a.into_gen().cumsum().collect();
+    /// assert_eq!(output, [1, 3, 6, 10]);
+    /// ```
+    #[inline]
+    fn cumsum(self) -> CumSum<Self>
+    where
+        Self::Output: core::ops::Add<Output = Self::Output> + Clone,
+    {
+        CumSum::new(self)
+    }
+
+    /// Creates a generator of the running products of the values.
+    ///
+    /// The first value is generated as is, and each following value is multiplied with the previous
+    /// product. Unlike [`product()`](GeneratorExt::product), no one value is needed, so any type
+    /// implementing [`Mul`](core::ops::Mul) can be used.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let a = [1, 2, 3, 4];
+    /// let output: Vec<_> = a.into_gen().cumprod().collect();
+    /// assert_eq!(output, [1, 2, 6, 24]);
+    /// ```
+    #[inline]
+    fn cumprod(self) -> CumProd<Self>
+    where
+        Self::Output: core::ops::Mul<Output = Self::Output> + Clone,
+    {
+        CumProd::new(self)
+    }
+
     /// Returns the minimum value of a generator.
     ///
     /// If several elements are equally minimum, the first element is
@@ -1199,7 +3365,44 @@ pub trait GeneratorExt: Sealed + Generator + Sized {
     /// assert_eq!(sum, 6);
     /// ```
     #[inline]
-    fn fold<B, F>(mut self, init: B, mut folder: F) -> B
+    fn fold<B, F>(mut self, init: B, folder: F) -> B
+    where
+        F: FnMut(B, Self::Output) -> B,
+    {
+        self.fold_ref(init, folder)
+    }
+
+    /// Folds every element into an accumulator without consuming the generator.
+    ///
+    /// This works like [`fold()`] but borrows the generator instead of taking ownership of it,
+    /// so the generator can still be used afterwards.
+    ///
+    /// [`fold()`]: GeneratorExt::fold
+    ///
+    /// ## Spuriously stopping generators
+    ///
+    /// `fold_ref()` will stop and return the result after the first stop of the generator. It
+    /// doesn't matter if the generator stopped or completed.
+    ///
+    /// Use [`try_fold()`] to correctly handle spuriously stopping generators.
+    ///
+    /// [`try_fold()`]: GeneratorExt::try_fold
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt, GeneratorResult};
+    /// let a = [1, 2, 3];
+    /// let mut gen = a.into_gen();
+    ///
+    /// let sum = gen.fold_ref(0, |acc, x| acc + x);
+    /// assert_eq!(sum, 6);
+    /// assert_eq!(gen.next(), Err(GeneratorResult::Complete));
+    /// ```
+    #[inline]
+    fn fold_ref<B, F>(&mut self, init: B, mut folder: F) -> B
     where
         F: FnMut(B, Self::Output) -> B,
     {
@@ -1216,14 +3419,15 @@ pub trait GeneratorExt: Sealed + Generator + Sized {
     /// an ‘accumulator’, and a value.
     /// The closure either returns successfully, with the value that the accumulator should have for
     /// the next iteration, or it returns failure, with an error value that is propagated back to
-    /// the caller immediately (short-circuiting).
+    /// the caller immediately (short-circuiting). The closure may return a [`Result`], where `Err`
+    /// is a failure, or a [`ControlFlow`](core::ops::ControlFlow), where `Break` is a failure.
     ///
     /// The return value from `try_fold()` can distinguish between 3 different return-conditions:
     ///
     ///   * `Ok(Reduction::Complete(B))` -> the generator has completed and produced a final value.
     ///   * `Ok(Reduction::Partial(B))` -> the generator spuriously stopped early.
     ///   Later `try_fold` calls should use the partial value as `init`.
-    ///   * `Err(E)` -> The provided closure returned an error.
+    ///   * `Err(E)` -> The provided closure returned an error, or broke with `E`.
     ///
     /// ## Examples
     ///
@@ -1257,15 +3461,34 @@ pub trait GeneratorExt: Sealed + Generator + Sized {
     /// assert_eq!(gen.next(), Err(GeneratorResult::Complete));
     /// ```
     ///
+    /// Breaking with [`ControlFlow`](core::ops::ControlFlow):
+    ///
+    /// ```
+    /// use core::ops::ControlFlow;
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let a = [1, 2, 3, 4, 5];
+    ///
+    /// // Sum the values until the sum exceeds 5, breaking with the value that crossed it.
+    /// let result = a.into_gen().try_fold(0, |acc, x| {
+    ///     if acc + x > 5 {
+    ///         ControlFlow::Break(x)
+    ///     } else {
+    ///         ControlFlow::Continue(acc + x)
+    ///     }
+    /// });
+    /// assert_eq!(result, Err(3));
+    /// ```
+    ///
     #[inline]
-    fn try_fold<B, F, E>(&mut self, init: B, mut folder: F) -> Result<TryReduction<B>, E>
+    fn try_fold<B, F, R>(&mut self, init: B, mut folder: F) -> Result<TryReduction<B>, R::Break>
     where
-        F: FnMut(B, Self::Output) -> Result<B, E>,
+        F: FnMut(B, Self::Output) -> R,
+        R: TryStep<B>,
     {
         let mut acc = InplaceUpdatable::new(Ok(init));
         let run_result = self.run(|x| {
             acc.update_with_result(|prev_acc| match prev_acc {
-                Ok(prev_acc) => match folder(prev_acc, x) {
+                Ok(prev_acc) => match folder(prev_acc, x).into_result() {
                     Ok(x) => (Ok(x), ValueResult::MoreValues),
                     err => (err, ValueResult::Stop),
                 },
@@ -1395,6 +3618,46 @@ pub trait GeneratorExt: Sealed + Generator + Sized {
         Some(left_value.get_inner())
     }
 
+    /// Reduces the elements to a single one by repeatedly applying a reducing operation, starting
+    /// from the back.
+    ///
+    /// The reduction is seeded with the last value, and `reducer` is then called with the
+    /// accumulated value and each preceding value, working towards the front. This is the same as
+    /// [`.rev().reduce()`](GeneratorExt::reduce).
+    ///
+    /// ## Returns
+    ///
+    /// `None` if the generator is empty, otherwise the result of the reduction.
+    ///
+    /// ## Spuriously stopping generators
+    ///
+    /// Like [`reduce()`](GeneratorExt::reduce), the result is returned after the source generator
+    /// has stopped. It doesn't matter if the source generator is stopped or completed.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use pushgen::{GeneratorExt, IntoGenerator};
+    /// let a = [1, 2, 3];
+    /// assert_eq!(a.into_gen().reduce_back(|acc, x| acc - x), Some(0));
+    /// assert_eq!(a.into_gen().reduce_back(|acc, x| x - acc), Some(2));
+    /// ```
+    #[inline]
+    fn reduce_back<F>(mut self, mut reducer: F) -> Option<Self::Output>
+    where
+        Self: ReverseGenerator,
+        F: FnMut(Self::Output, Self::Output) -> Self::Output,
+    {
+        let mut right_value = InplaceUpdatable::new(self.next_back().ok()?);
+
+        self.run_back(|x| {
+            right_value.inplace_reduce(x, &mut reducer);
+            ValueResult::MoreValues
+        });
+
+        Some(right_value.get_inner())
+    }
+
     /// Reduces the values to a single value by repeatedly applying a reducing operation.
     ///
     /// Use this reduction if the generator is known to spuriously stop mid-stream. Otherwise
@@ -1528,6 +3791,41 @@ pub trait GeneratorExt: Sealed + Generator + Sized {
         B::from_gen(self)
     }
 
+    /// Collects exactly `N` values into an array.
+    ///
+    /// Values are pulled from the generator until the array is full, any further values are left
+    /// in the generator. This does not require any allocations and is available without `std`.
+    ///
+    /// ## Spuriously stopping generators
+    ///
+    /// If the generator stops or completes before `N` values have been generated, `None` is returned
+    /// and the values pulled so far are dropped. A stopped generator can still be resumed afterwards.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let a = [1, 2, 3, 4, 5];
+    /// let mut gen = a.into_gen();
+    ///
+    /// assert_eq!(gen.collect_array::<2>(), Some([1, 2]));
+    /// assert_eq!(gen.collect_array::<2>(), Some([3, 4]));
+    /// assert_eq!(gen.collect_array::<2>(), None);
+    /// ```
+    #[inline]
+    fn collect_array<const N: usize>(&mut self) -> Option<[Self::Output; N]> {
+        let mut array = ArrayBuilder::new();
+        if !array.is_full() {
+            self.run(|x| {
+                array.push(x);
+                (!array.is_full()).into()
+            });
+        }
+        array.into_array()
+    }
+
     /// Creates a generator which gives the current generation count as well as the value.
     ///
     /// The generator generates `(i, val)` values, where `i` is the current index of the value and
@@ -1558,7 +3856,61 @@ pub trait GeneratorExt: Sealed + Generator + Sized {
     /// ```
     #[inline]
     fn enumerate(self) -> Enumerate<Self> {
-        Enumerate::new(self)
+        Enumerate::new(self, 0)
+    }
+
+    /// Creates a generator which gives the current generation count, starting at `start`, as well
+    /// as the value.
+    ///
+    /// This works like [`enumerate()`](GeneratorExt::enumerate) but the first value gets the index
+    /// `start` instead of `0`. This is useful when a generator is logically a continuation of an
+    /// earlier one.
+    ///
+    /// ## Panics
+    ///
+    /// The generator might panic if the index overflows a `usize`.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{SliceGenerator, GeneratorExt, GeneratorResult};
+    /// let data = ['a', 'b', 'c'];
+    ///
+    /// let mut gen = SliceGenerator::new(&data).enumerate_from(100);
+    /// assert_eq!(gen.next(), Ok((100, &'a')));
+    /// assert_eq!(gen.next(), Ok((101, &'b')));
+    /// assert_eq!(gen.next(), Ok((102, &'c')));
+    /// assert_eq!(gen.next(), Err(GeneratorResult::Complete));
+    /// ```
+    #[inline]
+    fn enumerate_from(self, start: usize) -> Enumerate<Self> {
+        Enumerate::new(self, start)
+    }
+
+    /// Creates a generator of the indices of the values for which `predicate` returns `true`.
+    ///
+    /// Indices count every value of the source, starting from 0, and are counted correctly across
+    /// stopped and resumed runs. Unlike [`position()`](GeneratorExt::position), all matching
+    /// indices are generated.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let a = [1, 2, 3, 4, 6, 7];
+    /// let output: Vec<_> = a.into_gen().positions(|x| x % 2 == 0).collect();
+    /// assert_eq!(output, [1, 3, 4]);
+    /// ```
+    #[inline]
+    fn positions<P>(self, predicate: P) -> Positions<Self, P>
+    where
+        P: FnMut(Self::Output) -> bool,
+    {
+        Positions::new(self, predicate)
     }
 
     /// Does something with each value from the generator, passing the value on.
@@ -1611,6 +3963,29 @@ pub trait GeneratorExt: Sealed + Generator + Sized {
         Inspect::new(self, inspector)
     }
 
+    /// Mutates each value in place with `updater` and then passes it on.
+    ///
+    /// This is the same as `map(|mut x| { updater(&mut x); x })`, without the closure having to
+    /// return the value.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let words = vec![String::from("a"), String::from("b")];
+    /// let output: Vec<_> = words.into_gen().update(|s| s.push('!')).collect();
+    /// assert_eq!(output, ["a!", "b!"]);
+    /// ```
+    #[inline]
+    fn update<F>(self, updater: F) -> Update<Self, F>
+    where
+        F: FnMut(&mut Self::Output),
+    {
+        Update::new(self, updater)
+    }
+
     /// Reverses a generators direction.
     ///
     /// ## Examples
@@ -1904,6 +4279,51 @@ pub trait GeneratorExt: Sealed + Generator + Sized {
         self.iter().partition(partitioner)
     }
 
+    /// Consumes a generator, mapping each value to an [`Either`] and collecting the left and right
+    /// values into two separate collections.
+    ///
+    /// `partition_map()` returns a pair: `(<left values>, <right values>)`.
+    ///
+    /// ## Spuriously stopping generators
+    ///
+    /// Like [`partition()`](GeneratorExt::partition), this will immediately stop once the
+    /// generator has stopped. It doesn't matter if the generator completes or spuriously stops.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{Either, IntoGenerator, GeneratorExt};
+    /// let a = [Ok(1), Err("bad"), Ok(3)];
+    ///
+    /// let (values, errors): (Vec<i32>, Vec<&str>) = a.into_gen().partition_map(|r| match r {
+    ///     Ok(v) => Either::Left(v),
+    ///     Err(e) => Either::Right(e),
+    /// });
+    ///
+    /// assert_eq!(values, [1, 3]);
+    /// assert_eq!(errors, ["bad"]);
+    /// ```
+    #[inline]
+    fn partition_map<A, B, L, R, F>(self, mut f: F) -> (A, B)
+    where
+        A: Default + Extend<L>,
+        B: Default + Extend<R>,
+        F: FnMut(Self::Output) -> Either<L, R>,
+    {
+        self.fold(
+            (A::default(), B::default()),
+            |(mut left, mut right), value| {
+                match f(value) {
+                    Either::Left(l) => left.extend(Some(l)),
+                    Either::Right(r) => right.extend(Some(r)),
+                }
+                (left, right)
+            },
+        )
+    }
+
     /// Converts an iterator of pairs into a pair of containers.
     ///
     /// `unzip()` consumes a generator of pairs, producing two collections: one from the
@@ -1965,6 +4385,39 @@ pub trait GeneratorExt: Sealed + Generator + Sized {
         self.iter().unzip()
     }
 
+    /// Collects `(key, value)` pairs into a `HashMap`, grouping the values of each key in a `Vec`.
+    ///
+    /// Values are kept in the order they were generated.
+    ///
+    /// ## Spuriously stopping generators
+    ///
+    /// Only the values generated before the generator stops are collected.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let a = [("a", 1), ("b", 2), ("a", 3)];
+    /// let groups = a.into_gen().into_group_map();
+    ///
+    /// assert_eq!(groups["a"], [1, 3]);
+    /// assert_eq!(groups["b"], [2]);
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    fn into_group_map<K, V>(mut self) -> std::collections::HashMap<K, Vec<V>>
+    where
+        Self: Generator<Output = (K, V)>,
+        K: Eq + core::hash::Hash,
+    {
+        let mut groups = std::collections::HashMap::new();
+        self.for_each(|(key, value)| groups.entry(key).or_insert_with(Vec::new).push(value));
+        groups
+    }
+
     /// [Lexicographically](https://doc.rust-lang.org/std/cmp/trait.Ord.html#lexicographical-comparison)
     /// compares the elements of this generator with those of another.
     ///
@@ -2135,11 +4588,13 @@ pub trait GeneratorExt: Sealed + Generator + Sized {
         Rhs: IntoGenerator<Output = Self::Output>,
         Cmp: FnMut(Self::Output, Self::Output) -> Ordering,
     {
-        unsafe {
-            crate::structs::utility::unwrap_unchecked(
-                self.partial_cmp_by(rhs, move |x, y| Some(cmp(x, y))),
-            )
-        }
+        let ordering = self.partial_cmp_by(rhs, move |x, y| Some(cmp(x, y)));
+        // Safety: partial_cmp_by only returns None if the comparison does, which it never does
+        #[cfg(not(feature = "safe"))]
+        let ordering = unsafe { unwrap_some(ordering) };
+        #[cfg(feature = "safe")]
+        let ordering = unwrap_some(ordering);
+        ordering
     }
 
     /// Determines if the values of this generator are lexicographically less than to those of another.
@@ -2315,6 +4770,56 @@ pub trait GeneratorExt: Sealed + Generator + Sized {
         !self.eq(rhs)
     }
 
+    /// Advances the generator exactly `n` values, ignoring them.
+    ///
+    /// This is built on [`try_advance()`](crate::Generator::try_advance) but keeps advancing until
+    /// either `n` values have been skipped or the generator completes.
+    ///
+    /// ## Returns
+    ///
+    /// `Ok(())` if the generator was advanced `n` values, otherwise `Err(advanced)` where `advanced`
+    /// is the number of values that were skipped before the generator completed.
+    ///
+    /// ## Spuriously stopping generators
+    ///
+    /// `try_advance_exact()` keeps advancing a stopped generator until it has advanced `n` values
+    /// or completes. A generator that stops indefinitely without advancing will cause this method to
+    /// never return.
+    ///
+    /// ## Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use pushgen::{IntoGenerator, GeneratorExt};
+    /// let a = [1, 2, 3, 4, 5];
+    /// let mut gen = a.into_gen();
+    /// assert_eq!(gen.try_advance_exact(2), Ok(()));
+    /// assert_eq!(gen.next(), Ok(3));
+    /// assert_eq!(gen.try_advance_exact(3), Err(2));
+    /// ```
+    #[inline]
+    fn try_advance_exact(&mut self, n: usize) -> Result<(), usize> {
+        let mut advanced = 0;
+        while advanced < n {
+            // Safety: advanced < n so n - advanced is never 0
+            #[cfg(not(feature = "safe"))]
+            let amount = unsafe { non_zero(n - advanced) };
+            #[cfg(feature = "safe")]
+            let amount = non_zero(n - advanced);
+            match self.try_advance(amount) {
+                (x, GeneratorResult::Stopped) => advanced += x,
+                (x, GeneratorResult::Complete) => {
+                    advanced += x;
+                    if advanced < n {
+                        return Err(advanced);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Returns the `nth` value from the generator.
     ///
     /// Like [`Iterator::nth`], the count starts from zero, so `nth(0)` returns the first value,
@@ -2339,7 +4844,12 @@ pub trait GeneratorExt: Sealed + Generator + Sized {
         if n == 0 {
             self.next().ok()
         } else {
-            match self.try_advance(unsafe { NonZeroUsize::new_unchecked(n) }) {
+            // Safety: checked by if clause
+            #[cfg(not(feature = "safe"))]
+            let amount = unsafe { non_zero(n) };
+            #[cfg(feature = "safe")]
+            let amount = non_zero(n);
+            match self.try_advance(amount) {
                 (x, _) if n == x => self.next().ok(),
                 _ => None,
             }
@@ -2353,8 +4863,8 @@ impl<T: Generator> GeneratorExt for T {}
 mod tests {
     use crate::test::StoppingGen;
     use crate::{
-        Generator, GeneratorExt, GeneratorResult, IntoGenerator, SliceGenerator, TryReduction,
-        ValueResult,
+        Either, ExactlyOneError, Generator, GeneratorExt, GeneratorResult, IntoGenerator,
+        SliceGenerator, TryReduction, ValueResult,
     };
     use std::cmp::Ordering;
 
@@ -2477,6 +4987,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn reduce_order() {
+        // The reduction folds from the left, with the first value as the initial accumulator.
+        let x = [10, 1, 2, 3];
+        assert_eq!(x.into_gen().reduce(|a, b| a - b), Some(4));
+
+        let words = ["a", "b", "c"];
+        let joined = words
+            .into_gen()
+            .map(String::from)
+            .reduce(|a, b| a + "-" + &b);
+        assert_eq!(joined.as_deref(), Some("a-b-c"));
+
+        // Reducing ends at the first stop of the generator.
+        assert_eq!(
+            StoppingGen::new(2, &x).copied().reduce(|a, b| a - b),
+            Some(9)
+        );
+    }
+
     #[test]
     fn empty_try_reduce() {
         let x: [i32; 0] = [];
@@ -2610,6 +5140,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn try_fold_control_flow() {
+        use core::ops::ControlFlow;
+
+        let data = [1, 2, 3, 4, 5];
+        let mut gen = data.into_gen();
+        let result = gen.try_fold(0, |acc, x| {
+            if x == 3 {
+                ControlFlow::Break(acc)
+            } else {
+                ControlFlow::Continue(acc + x)
+            }
+        });
+        assert_eq!(result, Err(3));
+        assert_eq!(gen.next(), Ok(4));
+
+        let result = gen.try_fold(0, |acc, x| ControlFlow::<(), _>::Continue(acc + x));
+        assert_eq!(result, Ok(TryReduction::Complete(5)));
+    }
+
     #[test]
     fn collect_vec() {
         let data = [0, 1, 2, 3, 4];
@@ -2628,6 +5178,280 @@ mod tests {
         assert_eq!(out, "GH");
     }
 
+    #[test]
+    fn spuriously_stopping_sum_into() {
+        let data = [1, 2, 3, 4, 5];
+        for x in 0..5 {
+            let mut gen = StoppingGen::new(x, &data).copied();
+            let mut sum = 0;
+            assert_eq!(gen.sum_into(&mut sum), GeneratorResult::Stopped);
+            assert_eq!(gen.sum_into(&mut sum), GeneratorResult::Complete);
+            assert_eq!(sum, data.iter().sum());
+        }
+    }
+
+    #[test]
+    fn fold() {
+        let data = ["a", "b", "c"];
+        let joined = data.into_gen().fold(String::new(), |mut acc, x| {
+            acc.push_str(x);
+            acc
+        });
+        assert_eq!(joined, "abc");
+
+        let empty: [i32; 0] = [];
+        assert_eq!(empty.into_gen().fold(7, |acc, x| acc + x), 7);
+
+        // Folding ends at the first stop of the generator.
+        let data = [1, 2, 3, 4];
+        assert_eq!(StoppingGen::new(2, &data).fold(0, |acc, x| acc + x), 3);
+    }
+
+    #[test]
+    fn fold_ref() {
+        let data = [1, 2, 3, 4];
+        let mut gen = SliceGenerator::new(&data);
+        assert_eq!(gen.fold_ref(0, |acc, x| acc + x), 10);
+
+        let mut output = Vec::new();
+        let result = gen.run(|x| {
+            output.push(*x);
+            ValueResult::MoreValues
+        });
+        assert_eq!(result, GeneratorResult::Complete);
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn collect_array() {
+        let data = [1, 2, 3];
+        assert_eq!(data.into_gen().collect_array::<3>(), Some([1, 2, 3]));
+        assert_eq!(data.into_gen().collect_array::<0>(), Some([]));
+
+        let mut gen = data.into_gen();
+        assert_eq!(gen.collect_array::<2>(), Some([1, 2]));
+        assert_eq!(gen.next(), Ok(3));
+
+        assert_eq!(data.into_gen().collect_array::<4>(), None);
+    }
+
+    #[test]
+    fn collect_array_drops_partial() {
+        let value = std::rc::Rc::new(0);
+        let data = [value.clone(), value.clone()];
+        assert_eq!(std::rc::Rc::strong_count(&value), 3);
+        assert!((&data).into_gen().cloned().collect_array::<3>().is_none());
+        assert_eq!(std::rc::Rc::strong_count(&value), 3);
+        let array = (&data).into_gen().cloned().collect_array::<2>();
+        assert_eq!(std::rc::Rc::strong_count(&value), 5);
+        drop(array);
+        assert_eq!(std::rc::Rc::strong_count(&value), 3);
+    }
+
+    #[test]
+    fn spuriously_stopping_collect_array() {
+        let data = [1, 2, 3, 4];
+        let mut gen = StoppingGen::new(1, &data);
+        assert_eq!(gen.collect_array::<2>(), None);
+        assert_eq!(gen.collect_array::<2>(), Some([&2, &3]));
+    }
+
+    #[test]
+    fn spuriously_stopping_for_each_counted() {
+        let data = [1, 2, 3, 4, 5];
+        for x in 0..5 {
+            let mut gen = StoppingGen::new(x, &data);
+            let mut output = Vec::new();
+            assert_eq!(
+                gen.for_each_counted(|x| output.push(*x)),
+                (x as usize, GeneratorResult::Stopped)
+            );
+            assert_eq!(output.len(), x as usize);
+            assert_eq!(
+                gen.for_each_counted(|x| output.push(*x)),
+                (5 - x as usize, GeneratorResult::Complete)
+            );
+            assert_eq!(output, data);
+        }
+    }
+
+    #[test]
+    fn try_advance_exact() {
+        let header = [0; 10];
+        let mut gen = (&header).into_gen();
+        assert_eq!(gen.try_advance_exact(4), Ok(()));
+        assert_eq!(gen.count(), 6);
+
+        let header = [0; 2];
+        assert_eq!((&header).into_gen().try_advance_exact(4), Err(2));
+        assert_eq!((&header).into_gen().try_advance_exact(0), Ok(()));
+    }
+
+    #[test]
+    fn spuriously_stopping_try_advance_exact() {
+        let data = [Some(1), None, Some(2), None, None, Some(3), Some(4)];
+        let mut gen = crate::test::MultiStoppingGen::new(&data);
+        assert_eq!(gen.try_advance_exact(3), Ok(()));
+        assert_eq!(gen.next(), Ok(&4));
+        let mut gen = crate::test::MultiStoppingGen::new(&data);
+        assert_eq!(gen.try_advance_exact(5), Err(4));
+    }
+
+    #[test]
+    fn sum_and_count() {
+        let data = [2, 4, 6];
+        assert_eq!(data.into_gen().sum_and_count::<i32>(), (12, 3));
+        let empty: [i32; 0] = [];
+        assert_eq!(empty.into_gen().sum_and_count::<i32>(), (0, 0));
+    }
+
+    #[test]
+    fn rev_take() {
+        let data = [1, 2, 3, 4];
+        let mut gen = SliceGenerator::new(&data);
+        let mut back = Vec::new();
+        let result = gen.by_ref().rev_take(2).run(|x| {
+            back.push(*x);
+            ValueResult::MoreValues
+        });
+        assert_eq!(result, GeneratorResult::Complete);
+        assert_eq!(back, [4, 3]);
+        assert_eq!(gen.next(), Ok(&1));
+        assert_eq!(gen.next(), Ok(&2));
+        assert_eq!(gen.next(), Err(GeneratorResult::Complete));
+    }
+
+    #[test]
+    fn reduce_back() {
+        let x = [1i32, 2, 3];
+        fn reducer(a: i32, b: i32) -> i32 {
+            a - b
+        }
+
+        assert_eq!(
+            x.iter().copied().rev().reduce(reducer),
+            (&x).into_gen().copied().reduce_back(reducer)
+        );
+        assert_eq!((&x).into_gen().copied().reduce_back(reducer), Some(0));
+
+        let empty: [i32; 0] = [];
+        assert_eq!(empty.into_gen().reduce_back(reducer), None);
+    }
+
+    #[test]
+    fn all_equal() {
+        assert!([3, 3, 3].into_gen().all_equal());
+        assert!([3].into_gen().all_equal());
+        assert!(![3, 3, 4].into_gen().all_equal());
+
+        let empty: [i32; 0] = [];
+        assert!(empty.into_gen().all_equal());
+
+        let data = [1, 1, 2, 3];
+        let mut gen = (&data).into_gen();
+        assert!(!gen.all_equal());
+        assert_eq!(gen.next(), Ok(&3));
+    }
+
+    #[test]
+    fn all_equal_spuriously_stopping() {
+        let data = [1, 2, 2];
+        let mut gen = StoppingGen::new(0, &data);
+        assert!(gen.all_equal());
+        assert!(!gen.all_equal());
+
+        let mut gen = StoppingGen::new(1, &data);
+        assert!(gen.all_equal());
+        assert!(gen.all_equal());
+    }
+
+    #[test]
+    fn all_unique() {
+        assert!([1, 2, 3].into_gen().all_unique());
+        assert!(![1, 2, 3, 2].into_gen().all_unique());
+
+        let empty: [i32; 0] = [];
+        assert!(empty.into_gen().all_unique());
+
+        let data = [1, 2, 1, 4];
+        let mut gen = (&data).into_gen();
+        assert!(!gen.all_unique());
+        assert_eq!(gen.next(), Ok(&4));
+    }
+
+    #[test]
+    fn exactly_one() {
+        let empty: [i32; 0] = [];
+        assert_eq!(empty.into_gen().exactly_one(), Err(ExactlyOneError::Empty));
+        assert_eq!([1].into_gen().exactly_one(), Ok(1));
+        assert_eq!(
+            [1, 2].into_gen().exactly_one(),
+            Err(ExactlyOneError::MoreThanOne(1, 2))
+        );
+
+        let data = [1, 2, 3];
+        let mut gen = (&data).into_gen();
+        assert_eq!(gen.exactly_one(), Err(ExactlyOneError::MoreThanOne(&1, &2)));
+        assert_eq!(gen.next(), Ok(&3));
+    }
+
+    #[test]
+    fn spuriously_stopping_exactly_one() {
+        let data = [1];
+        let mut gen = StoppingGen::new(0, &data);
+        assert_eq!(gen.exactly_one(), Err(ExactlyOneError::Stopped(None)));
+        assert_eq!(gen.exactly_one(), Ok(&1));
+
+        let mut gen = StoppingGen::new(1, &data);
+        assert_eq!(gen.exactly_one(), Err(ExactlyOneError::Stopped(Some(&1))));
+        assert_eq!(gen.exactly_one(), Err(ExactlyOneError::Empty));
+
+        let data = [1, 2];
+        let mut gen = StoppingGen::new(1, &data);
+        assert_eq!(gen.exactly_one(), Err(ExactlyOneError::Stopped(Some(&1))));
+        assert_eq!(gen.next(), Ok(&2));
+        assert_eq!(gen.next(), Err(GeneratorResult::Complete));
+    }
+
+    #[test]
+    fn into_group_map() {
+        let data = [(1, 'a'), (2, 'b'), (1, 'c'), (3, 'd'), (1, 'e')];
+        let groups = data.into_gen().into_group_map();
+        assert_eq!(groups.len(), 3);
+        assert_eq!(groups[&1], ['a', 'c', 'e']);
+        assert_eq!(groups[&2], ['b']);
+        assert_eq!(groups[&3], ['d']);
+
+        let empty: [(i32, i32); 0] = [];
+        assert!(empty.into_gen().into_group_map().is_empty());
+    }
+
+    #[test]
+    fn partition_map() {
+        let data = [1, 2, 3, 4, 5];
+        let (even, odd): (Vec<i32>, Vec<String>) = data.into_gen().partition_map(|x| {
+            if x % 2 == 0 {
+                Either::Left(x)
+            } else {
+                Either::Right(x.to_string())
+            }
+        });
+        assert_eq!(even, [2, 4]);
+        assert_eq!(odd, ["1", "3", "5"]);
+
+        for x in 0..data.len() {
+            let (left, right): (Vec<i32>, Vec<i32>) = StoppingGen::new(x as i32, &data)
+                .partition_map(|&v| {
+                    if v < 3 {
+                        Either::Left(v)
+                    } else {
+                        Either::Right(v)
+                    }
+                });
+            assert_eq!(left.len() + right.len(), x);
+        }
+    }
+
     #[test]
     fn count() {
         let data: [i32; 0] = [];
@@ -1,9 +1,11 @@
 //! Module containing the various traits used by `pushgen`.
 
 pub use accum::Product;
+pub use accum::SaturatingSum;
 pub use accum::Sum;
 pub use dyn_generator::DynGenerator;
 pub use from_gen::FromGenerator;
+pub use generator::FusedGenerator;
 pub use generator::Generator;
 pub use generator::ReverseGenerator;
 pub use generator_ext::GeneratorExt;